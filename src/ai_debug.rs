@@ -0,0 +1,167 @@
+// ai_debug.rs
+use crate::dev::{DevToggle, toggle_dev_tool};
+use crate::enemy::{
+    Attack, AttackInRange, Chase, Dash, Enemy, EnemySenses, HasTarget, Patrol, Returning,
+};
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use big_brain::prelude::{ActionState as BrainState, Actor, Score};
+use std::collections::{HashMap, HashSet};
+
+const AI_DEBUG_TOGGLE: KeyCode = KeyCode::F2;
+const AI_DEBUG_REFRESH_SECS: f32 = 0.2;
+const AI_DEBUG_LABEL_Y: f32 = 40.0;
+
+/// Per-enemy readout of the running big_brain action, scorer values and
+/// distance to the player. F2 toggles it while the dev tools are on.
+#[derive(Resource)]
+pub struct AiDebug {
+    pub enabled: bool,
+    refresh: Timer,
+}
+
+impl Default for AiDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            refresh: Timer::from_seconds(AI_DEBUG_REFRESH_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+#[derive(Component)]
+struct AiDebugLabel;
+
+#[derive(Default)]
+struct Readout {
+    action: Option<&'static str>,
+    has_target: f32,
+    attack_in_range: f32,
+}
+
+impl DevToggle for AiDebug {
+    const KEY: KeyCode = AI_DEBUG_TOGGLE;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, on: bool) {
+        self.enabled = on;
+    }
+}
+
+fn sync_ai_debug_labels(
+    mut commands: Commands,
+    debug: Res<AiDebug>,
+    enemies: Query<Entity, With<Enemy>>,
+    labels: Query<(Entity, &ChildOf), With<AiDebugLabel>>,
+) {
+    if !debug.enabled {
+        for (label, _) in &labels {
+            commands.entity(label).despawn();
+        }
+        return;
+    }
+    let labelled: HashSet<Entity> = labels.iter().map(|(_, c)| c.parent()).collect();
+    for e in &enemies {
+        if labelled.contains(&e) {
+            continue;
+        }
+        commands.entity(e).with_child((
+            AiDebugLabel,
+            Text2d::new(""),
+            TextFont {
+                font_size: 7.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.6)),
+            Transform::from_xyz(0.0, AI_DEBUG_LABEL_Y, 5.0),
+            Name::new("AiDebugLabel"),
+        ));
+    }
+}
+
+fn update_ai_debug_labels(
+    time: Res<Time>,
+    mut debug: ResMut<AiDebug>,
+    actions: Query<(
+        &Actor,
+        &BrainState,
+        Has<Patrol>,
+        Has<Chase>,
+        Has<Attack>,
+        Has<Dash>,
+        Has<Returning>,
+    )>,
+    scorers: Query<(&Actor, &Score, Has<HasTarget>, Has<AttackInRange>)>,
+    senses: Query<&EnemySenses>,
+    mut labels: Query<(&ChildOf, &mut Text2d), With<AiDebugLabel>>,
+) {
+    if !debug.enabled || !debug.refresh.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let mut readouts: HashMap<Entity, Readout> = HashMap::new();
+    for (Actor(actor), state, patrol, chase, attack, dash, returning) in &actions {
+        if !matches!(state, BrainState::Requested | BrainState::Executing) {
+            continue;
+        }
+        let name = if attack {
+            "Attack"
+        } else if dash {
+            "Dash"
+        } else if chase {
+            "Chase"
+        } else if returning {
+            "Return"
+        } else if patrol {
+            "Patrol"
+        } else {
+            continue;
+        };
+        readouts.entry(*actor).or_default().action = Some(name);
+    }
+    for (Actor(actor), score, has_target, in_range) in &scorers {
+        let r = readouts.entry(*actor).or_default();
+        if has_target {
+            r.has_target = score.get();
+        }
+        if in_range {
+            r.attack_in_range = score.get();
+        }
+    }
+
+    for (child_of, mut text) in &mut labels {
+        let enemy = child_of.parent();
+        let r = readouts.remove(&enemy).unwrap_or_default();
+        let dist = senses
+            .get(enemy)
+            .map(|s| format!("{:.0}", s.dist))
+            .unwrap_or_else(|_| "-".into());
+        text.0 = format!(
+            "{}\ntgt {:.1} atk {:.1}\nd {}",
+            r.action.unwrap_or("-"),
+            r.has_target,
+            r.attack_in_range,
+            dist
+        );
+    }
+}
+
+pub struct AiDebugPlugin;
+
+impl Plugin for AiDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiDebug>().add_systems(
+            Update,
+            (
+                toggle_dev_tool::<AiDebug>,
+                sync_ai_debug_labels,
+                update_ai_debug_labels,
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// ambient.rs
+use crate::camera::MainCamera;
+use crate::gameflow::{GameState, GameplayRoot};
+use crate::prelude::*;
+use crate::rng::GameRng;
+use rand::Rng;
+
+// Density is particles per this many square world units of visible area
+const AMBIENT_DENSITY_AREA: f32 = 10_000.0;
+const AMBIENT_MAX_PARTICLES: usize = 300;
+// Particles wrap within the view grown by this much, so edges never pop
+const AMBIENT_VIEW_MARGIN: f32 = 32.0;
+const AMBIENT_Z: f32 = 20.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum AmbientKind {
+    Dust,
+    Leaves,
+    Embers,
+}
+
+impl AmbientKind {
+    /// Tiled map property value (`ambient`), case-insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "dust" => Some(Self::Dust),
+            "leaves" => Some(Self::Leaves),
+            "embers" => Some(Self::Embers),
+            _ => None,
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Dust => Color::srgba(1.0, 0.95, 0.85, 0.35),
+            Self::Leaves => Color::srgba(0.75, 0.42, 0.18, 0.85),
+            Self::Embers => Color::srgba(1.0, 0.55, 0.15, 0.9),
+        }
+    }
+
+    fn size(self) -> Vec2 {
+        match self {
+            Self::Dust | Self::Embers => Vec2::splat(2.0),
+            Self::Leaves => Vec2::new(4.0, 3.0),
+        }
+    }
+
+    /// Random drift velocity: dust floats, leaves fall, embers rise.
+    fn velocity(self, rng: &mut GameRng) -> Vec2 {
+        match self {
+            Self::Dust => Vec2::new(rng.random_range(-6.0..6.0), rng.random_range(-4.0..4.0)),
+            Self::Leaves => Vec2::new(
+                rng.random_range(-20.0..-5.0),
+                rng.random_range(-30.0..-15.0),
+            ),
+            Self::Embers => Vec2::new(rng.random_range(-5.0..5.0), rng.random_range(15.0..35.0)),
+        }
+    }
+
+    /// Side-to-side sway amplitude (units per second).
+    fn sway(self) -> f32 {
+        match self {
+            Self::Dust => 4.0,
+            Self::Leaves => 12.0,
+            Self::Embers => 6.0,
+        }
+    }
+}
+
+/// Ambient particles kept around the camera. Maps override it through the
+/// `ambient` (dust/leaves/embers/none) and `ambient_density` properties.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct AmbientParticles {
+    pub kind: AmbientKind,
+    /// Particles per `AMBIENT_DENSITY_AREA` of visible area; 0 disables.
+    pub density: f32,
+}
+
+impl Default for AmbientParticles {
+    fn default() -> Self {
+        Self {
+            kind: AmbientKind::Dust,
+            density: 0.5,
+        }
+    }
+}
+
+#[derive(Component)]
+struct AmbientParticle {
+    vel: Vec2,
+    phase: f32,
+}
+
+/// Visible world rect of the main camera, grown by the wrap margin.
+fn ambient_view_rect(camera: &Camera, cam_tf: &GlobalTransform) -> Option<Rect> {
+    let size = camera.logical_viewport_size()?;
+    let a = camera
+        .viewport_to_world_2d(cam_tf, Vec2::new(0.0, size.y))
+        .ok()?;
+    let b = camera
+        .viewport_to_world_2d(cam_tf, Vec2::new(size.x, 0.0))
+        .ok()?;
+    Some(Rect::from_corners(a, b).inflate(AMBIENT_VIEW_MARGIN))
+}
+
+/// Top up (or trim) the pool to the configured density; a kind change
+/// rebuilds it.
+fn maintain_ambient_particles(
+    mut commands: Commands,
+    config: Res<AmbientParticles>,
+    mut rng: ResMut<GameRng>,
+    cam: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    particles: Query<Entity, With<AmbientParticle>>,
+) {
+    if config.is_changed() {
+        for e in &particles {
+            commands.entity(e).despawn();
+        }
+    }
+    let Ok((camera, cam_tf)) = cam.single() else {
+        return;
+    };
+    let Some(view) = ambient_view_rect(camera, cam_tf) else {
+        return;
+    };
+    let area = view.width() * view.height();
+    let target = ((config.density.max(0.0) * area / AMBIENT_DENSITY_AREA) as usize)
+        .min(AMBIENT_MAX_PARTICLES);
+    let current = if config.is_changed() {
+        0
+    } else {
+        particles.iter().len()
+    };
+
+    if current > target {
+        for e in particles.iter().take(current - target) {
+            commands.entity(e).despawn();
+        }
+        return;
+    }
+    let kind = config.kind;
+    for _ in current..target {
+        let pos = Vec2::new(
+            rng.random_range(view.min.x..view.max.x),
+            rng.random_range(view.min.y..view.max.y),
+        );
+        commands.spawn((
+            Sprite::from_color(kind.color(), kind.size()),
+            Transform::from_translation(pos.extend(AMBIENT_Z)),
+            AmbientParticle {
+                vel: kind.velocity(&mut rng),
+                phase: rng.random_range(0.0..std::f32::consts::TAU),
+            },
+            GameplayRoot,
+            Name::new("AmbientParticle"),
+        ));
+    }
+}
+
+/// Drift, sway, and wrap anything that leaves the view to the other side.
+fn drift_ambient_particles(
+    time: Res<Time>,
+    config: Res<AmbientParticles>,
+    cam: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut q: Query<(&AmbientParticle, &mut Transform)>,
+) {
+    let Ok((camera, cam_tf)) = cam.single() else {
+        return;
+    };
+    let Some(view) = ambient_view_rect(camera, cam_tf) else {
+        return;
+    };
+    let t = time.elapsed_secs();
+    let dt = time.delta_secs();
+    let sway = config.kind.sway();
+    for (p, mut tf) in &mut q {
+        let sway_x = (t * 1.3 + p.phase).sin() * sway;
+        let mut pos = tf.translation.truncate() + (p.vel + Vec2::new(sway_x, 0.0)) * dt;
+        if !view.contains(pos) {
+            pos.x = view.min.x + (pos.x - view.min.x).rem_euclid(view.width());
+            pos.y = view.min.y + (pos.y - view.min.y).rem_euclid(view.height());
+        }
+        tf.translation = pos.extend(AMBIENT_Z);
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct AmbientPlugin;
+
+impl Plugin for AmbientPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AmbientParticles>()
+            .register_type::<AmbientParticles>()
+            .add_systems(
+                Update,
+                (maintain_ambient_particles, drift_ambient_particles)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
@@ -1,9 +1,13 @@
 // animations.rs
+use crate::gameflow::GameState;
 use crate::prelude::*;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
 
 pub const DEFAULT_FRAME_MS: u32 = 100;
+/// One-frame stand-in (atlas cell 0) used when a clip can't be resolved.
+pub const PLACEHOLDER_ANIM: &str = "placeholder";
 
 /* ------------------------- Plugins ------------------------- */
 
@@ -14,7 +18,12 @@ impl Plugin for PlayerAnimationsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayerSpritesheet>().add_systems(
             Startup,
-            (load_player_spritesheet, register_player_animations).chain(),
+            (
+                load_player_spritesheet,
+                register_player_animations,
+                register_placeholder_animation,
+            )
+                .chain(),
         );
     }
 }
@@ -26,7 +35,12 @@ impl Plugin for EnemyAnimationsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EnemySpritesheet>().add_systems(
             Startup,
-            (load_enemy_spritesheet, register_enemy_animations).chain(),
+            (
+                load_enemy_spritesheet,
+                register_enemy_animations,
+                register_placeholder_animation,
+            )
+                .chain(),
         );
     }
 }
@@ -36,10 +50,219 @@ pub struct AnimationsPlugin;
 
 impl Plugin for AnimationsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((PlayerAnimationsPlugin, EnemyAnimationsPlugin));
+        app.add_plugins((PlayerAnimationsPlugin, EnemyAnimationsPlugin))
+            .init_resource::<AnimHold>()
+            .register_type::<AnimHold>()
+            .add_systems(OnEnter(GameState::Paused), freeze_animations)
+            .add_systems(
+                PostUpdate,
+                hold_animations_frozen.run_if(in_state(GameState::Paused)),
+            )
+            .add_systems(OnExit(GameState::Paused), thaw_animations);
     }
 }
 
+/* --------------------- Pause freeze --------------------- */
+
+/// `playing` state captured when the pause menu opened.
+#[derive(Component)]
+struct PausedAnim(bool);
+
+fn freeze_animations(
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut SpritesheetAnimation), Without<PausedAnim>>,
+) {
+    for (e, mut anim) in &mut q {
+        commands.entity(e).insert(PausedAnim(anim.playing));
+        anim.playing = false;
+    }
+}
+
+/// Clip switches while paused (e.g. a state change) must not restart playback.
+fn hold_animations_frozen(mut q: Query<&mut SpritesheetAnimation, With<PausedAnim>>) {
+    for mut anim in &mut q {
+        if anim.playing {
+            anim.playing = false;
+        }
+    }
+}
+
+fn thaw_animations(
+    mut commands: Commands,
+    mut q: Query<(Entity, &PausedAnim, &mut SpritesheetAnimation)>,
+) {
+    for (e, paused, mut anim) in &mut q {
+        anim.playing = paused.0;
+        commands.entity(e).remove::<PausedAnim>();
+    }
+}
+
+/* --------------------- State → clip mapping --------------------- */
+
+/// Animation-relevant character state. Each driver keeps a priority list of
+/// these (first active one wins) and an `AnimMap` from state to clip, so a new
+/// state only needs a variant, a priority entry and a clip-table row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AnimState {
+    Dead,
+    Stunned,
+    /// Short flinch from a hit that didn't stun.
+    Hurt,
+    Hanging,
+    ChargedAttack,
+    IdleAttack,
+    WalkingAttack,
+    RunningAttack,
+    JumpingAttack,
+    FallingAttack,
+    Jumping,
+    Falling,
+    Running,
+    Walking,
+    Idle,
+}
+
+/// Clip table row: a state and its candidate clip names, best first.
+pub type AnimClipRow = (AnimState, &'static [&'static str]);
+
+/// Resolved clip per state. States without a clip play idle.
+#[derive(Component, Clone, Debug)]
+pub struct AnimMap {
+    clips: HashMap<AnimState, AnimationId>,
+    idle: AnimationId,
+}
+
+impl AnimMap {
+    /// Resolve every row to the first candidate present in the library.
+    /// A missing idle is logged and replaced by the first clip the table does
+    /// resolve (or the placeholder), so a bad sheet never takes the game down.
+    pub fn build(library: &AnimationLibrary, table: &[AnimClipRow]) -> Self {
+        let clips: HashMap<AnimState, AnimationId> = table
+            .iter()
+            .filter_map(|(state, names)| {
+                names
+                    .iter()
+                    .find_map(|n| library.animation_with_name(*n))
+                    .map(|id| (*state, id))
+            })
+            .collect();
+        let idle = clips.get(&AnimState::Idle).copied().unwrap_or_else(|| {
+            let wanted = table
+                .iter()
+                .find(|(s, _)| *s == AnimState::Idle)
+                .map(|(_, n)| n.join(", "))
+                .unwrap_or_default();
+            let fallback = table
+                .iter()
+                .flat_map(|(_, names)| names.iter().copied())
+                .find(|n| library.animation_with_name(*n).is_some())
+                .unwrap_or(PLACEHOLDER_ANIM);
+            require_anim_or_fallback(library, &wanted, fallback)
+        });
+        Self { clips, idle }
+    }
+
+    pub fn clip(&self, state: AnimState) -> AnimationId {
+        self.clips.get(&state).copied().unwrap_or(self.idle)
+    }
+
+    /// Whether `state` resolved to its own clip (rather than idle).
+    pub fn has(&self, state: AnimState) -> bool {
+        self.clips.contains_key(&state)
+    }
+}
+
+/// Look up `name`, falling back to `fallback` and then `PLACEHOLDER_ANIM`,
+/// logging an error for each miss instead of panicking.
+pub fn require_anim_or_fallback(
+    library: &AnimationLibrary,
+    name: &str,
+    fallback: &str,
+) -> AnimationId {
+    if let Some(id) = library.animation_with_name(name) {
+        return id;
+    }
+    error!("missing animation: {name}; falling back to {fallback}");
+    if let Some(id) = library.animation_with_name(fallback) {
+        return id;
+    }
+    error!("missing fallback animation: {fallback}; using {PLACEHOLDER_ANIM}");
+    library
+        .animation_with_name(PLACEHOLDER_ANIM)
+        .expect("placeholder animation is registered at startup")
+}
+
+/// First state in `priority` for which `active` holds; idle if none do.
+pub fn select_anim_state(priority: &[AnimState], active: impl Fn(AnimState) -> bool) -> AnimState {
+    priority
+        .iter()
+        .copied()
+        .find(|s| active(*s))
+        .unwrap_or(AnimState::Idle)
+}
+
+/* --------------------- Switch hysteresis --------------------- */
+
+/// Shortest time a state's clip plays before a driver may drop to a
+/// lower-priority state, so brief velocity dips (attack windups zeroing
+/// speed) don't flicker between clips. Moving up the priority list always
+/// switches at once.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct AnimHold {
+    pub min_secs: f32,
+}
+
+impl Default for AnimHold {
+    fn default() -> Self {
+        Self { min_secs: 0.1 }
+    }
+}
+
+/// State the driver last switched to and how long it has been showing.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AnimHeld {
+    pub state: AnimState,
+    pub secs: f32,
+}
+
+impl Default for AnimHeld {
+    fn default() -> Self {
+        Self {
+            state: AnimState::Idle,
+            secs: 0.0,
+        }
+    }
+}
+
+/// `wanted`, unless it ranks below the held state in `priority` and the held
+/// one hasn't played `min_secs` yet. Advances `held` by `dt`.
+pub fn hold_anim_state(
+    priority: &[AnimState],
+    held: &mut AnimHeld,
+    wanted: AnimState,
+    dt: f32,
+    min_secs: f32,
+) -> AnimState {
+    held.secs += dt;
+    if wanted == held.state {
+        return wanted;
+    }
+    let rank = |s: AnimState| {
+        priority
+            .iter()
+            .position(|p| *p == s)
+            .unwrap_or(priority.len())
+    };
+    if rank(wanted) < rank(held.state) || held.secs >= min_secs {
+        *held = AnimHeld {
+            state: wanted,
+            secs: 0.0,
+        };
+    }
+    held.state
+}
+
 /* --------------------- Manifest structures --------------------- */
 
 #[derive(Debug, Deserialize, Clone)]
@@ -61,6 +284,33 @@ struct SheetManifest {
     animations: Vec<AnimationEntry>,
 }
 
+impl Default for SheetManifest {
+    /// Built-in stand-in for a missing/malformed sheet JSON: a single cell and
+    /// no clips, so everything plays `PLACEHOLDER_ANIM`.
+    fn default() -> Self {
+        Self {
+            sheet_image: String::new(),
+            columns: 1,
+            rows: 1,
+            frame_w: 64,
+            frame_h: 64,
+            animations: Vec::new(),
+        }
+    }
+}
+
+fn read_sheet_manifest(json_path: &str) -> SheetManifest {
+    fs::read_to_string(json_path)
+        .map_err(|e| format!("failed to read {json_path}: {e}"))
+        .and_then(|text| {
+            serde_json::from_str(&text).map_err(|e| format!("{json_path} malformed: {e}"))
+        })
+        .unwrap_or_else(|e| {
+            error!("{e}; using the built-in placeholder sheet");
+            SheetManifest::default()
+        })
+}
+
 /* --------------------- Spritesheet resources --------------------- */
 
 #[derive(Resource, Default)]
@@ -77,6 +327,41 @@ pub struct EnemySpritesheet {
     manifest: Option<SheetManifest>,
 }
 
+impl PlayerSpritesheet {
+    /// Clip lengths in seconds keyed by pretty name (`player...:...`).
+    pub fn anim_seconds(&self) -> HashMap<String, f32> {
+        anim_seconds_from_manifest(self.manifest.as_ref(), to_anim_name)
+    }
+}
+
+impl EnemySpritesheet {
+    /// Clip lengths in seconds keyed by pretty name (`enemy...:...`).
+    pub fn anim_seconds(&self) -> HashMap<String, f32> {
+        anim_seconds_from_manifest(self.manifest.as_ref(), to_enemy_anim_name)
+    }
+}
+
+/// Durations from the manifest already loaded for registration, so gameplay
+/// timers and clips share one source. Measured up to the last frame's start
+/// (`last_col` frames), which lets a swing end before the clip loops.
+fn anim_seconds_from_manifest(
+    manifest: Option<&SheetManifest>,
+    pretty_name: fn(&str) -> String,
+) -> HashMap<String, f32> {
+    let Some(manifest) = manifest else {
+        warn!("spritesheet manifest not loaded; using fallback durations");
+        return HashMap::new();
+    };
+    manifest
+        .animations
+        .iter()
+        .map(|a| {
+            let secs = (a.last_col as u32 * DEFAULT_FRAME_MS) as f32 / 1000.0;
+            (pretty_name(&a.name), secs)
+        })
+        .collect()
+}
+
 /* --------------------- Loaders --------------------- */
 
 fn load_player_spritesheet(
@@ -84,13 +369,10 @@ fn load_player_spritesheet(
     assets: Res<AssetServer>,
     mut sheet: ResMut<PlayerSpritesheet>,
 ) {
-    let json_path = "assets/PlayerSheet2.json";
-    let json_text =
-        fs::read_to_string(json_path).unwrap_or_else(|e| panic!("Failed to read {json_path}: {e}"));
-
-    let manifest: SheetManifest =
-        serde_json::from_str(&json_text).expect("PlayerSheet2.json malformed");
-    sheet.image = assets.load(&manifest.sheet_image);
+    let manifest = read_sheet_manifest("assets/PlayerSheet2.json");
+    if !manifest.sheet_image.is_empty() {
+        sheet.image = assets.load(&manifest.sheet_image);
+    }
     let spritesheet = Spritesheet::new(manifest.columns, manifest.rows);
     sheet.layout = atlas_layouts.add(spritesheet.atlas_layout(manifest.frame_w, manifest.frame_h));
     sheet.manifest = Some(manifest);
@@ -101,13 +383,10 @@ fn load_enemy_spritesheet(
     assets: Res<AssetServer>,
     mut sheet: ResMut<EnemySpritesheet>,
 ) {
-    let json_path = "assets/EnemySheet.json";
-    let json_text =
-        fs::read_to_string(json_path).unwrap_or_else(|e| panic!("Failed to read {json_path}: {e}"));
-
-    let manifest: SheetManifest =
-        serde_json::from_str(&json_text).expect("EnemySheet.json malformed");
-    sheet.image = assets.load(&manifest.sheet_image);
+    let manifest = read_sheet_manifest("assets/EnemySheet.json");
+    if !manifest.sheet_image.is_empty() {
+        sheet.image = assets.load(&manifest.sheet_image);
+    }
     let spritesheet = Spritesheet::new(manifest.columns, manifest.rows);
     sheet.layout = atlas_layouts.add(spritesheet.atlas_layout(manifest.frame_w, manifest.frame_h));
     sheet.manifest = Some(manifest);
@@ -168,6 +447,17 @@ pub fn to_enemy_anim_name(raw: &str) -> String {
 
 /* --------------------- Registration systems --------------------- */
 
+/// Shared by both sheet plugins; whichever runs first registers it.
+fn register_placeholder_animation(mut library: ResMut<AnimationLibrary>) {
+    if library.animation_with_name(PLACEHOLDER_ANIM).is_some() {
+        return;
+    }
+    let clip = Clip::from_frames([0]).with_duration(AnimationDuration::PerFrame(DEFAULT_FRAME_MS));
+    let clip_id = library.register_clip(clip);
+    let anim_id = library.register_animation(Animation::from_clip(clip_id));
+    let _ = library.name_animation(anim_id, PLACEHOLDER_ANIM);
+}
+
 fn register_player_animations(
     mut library: ResMut<AnimationLibrary>,
     sheet: Res<PlayerSpritesheet>,
@@ -252,3 +542,55 @@ fn register_enemy_animations(mut library: ResMut<AnimationLibrary>, sheet: Res<E
         manifest.frame_h
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AnimHeld, AnimState, hold_anim_state, to_anim_name, to_anim_name_with_prefix,
+        to_enemy_anim_name,
+    };
+
+    #[test]
+    fn folders_become_prefix_parts() {
+        assert_eq!(
+            to_anim_name("combat/swordRunSlash"),
+            "player_combat:swordrunslash"
+        );
+        assert_eq!(to_enemy_anim_name("combat/slash"), "enemy_combat:slash");
+        assert_eq!(to_anim_name("idle"), "player:idle");
+    }
+
+    #[test]
+    fn empty_input_is_unnamed() {
+        assert_eq!(to_anim_name(""), "player:unnamed");
+        assert_eq!(to_anim_name("//"), "player:unnamed");
+        assert_eq!(to_anim_name("___/--"), "player:unnamed");
+    }
+
+    #[test]
+    fn separators_and_underscores_are_normalized() {
+        assert_eq!(to_anim_name("combat\\sword/run"), "player_combat_sword:run");
+        assert_eq!(to_anim_name("__Run  Fast__"), "player:run_fast");
+        assert_eq!(to_anim_name_with_prefix("Jump-Mid", "npc"), "npc:jump_mid");
+    }
+
+    #[test]
+    fn lower_priority_states_wait_out_the_hold() {
+        let priority = [AnimState::IdleAttack, AnimState::Walking, AnimState::Idle];
+        let mut held = AnimHeld {
+            state: AnimState::Walking,
+            secs: 0.0,
+        };
+        // A one-frame dip to idle keeps walking
+        let s = hold_anim_state(&priority, &mut held, AnimState::Idle, 0.016, 0.1);
+        assert_eq!(s, AnimState::Walking);
+        // Attacks win immediately
+        let s = hold_anim_state(&priority, &mut held, AnimState::IdleAttack, 0.016, 0.1);
+        assert_eq!(s, AnimState::IdleAttack);
+        // Leaving only once the attack clip has had its hold
+        let s = hold_anim_state(&priority, &mut held, AnimState::Idle, 0.05, 0.1);
+        assert_eq!(s, AnimState::IdleAttack);
+        let s = hold_anim_state(&priority, &mut held, AnimState::Idle, 0.06, 0.1);
+        assert_eq!(s, AnimState::Idle);
+    }
+}
@@ -0,0 +1,323 @@
+// assists.rs
+use crate::character::{
+    Action, Dead, Falling, GameLayer, Hanging, Idle, IdleAttack, InputGrace, Jumping, Player,
+    Running, RunningAttack, SprintJumping, Stunned, Walking, WalkingAttack,
+};
+use crate::gameflow::GameState;
+use crate::level::OneWayPlatform;
+use crate::prelude::*;
+use avian2d::collision::collider::LayerMask;
+use avian2d::spatial_query::{ShapeCastConfig, SpatialQueryFilter};
+
+// Player capsule (radius 8, length 26) measured from its center
+const PLAYER_RADIUS: f32 = 8.0;
+const PLAYER_HALF_HEIGHT: f32 = 21.0;
+
+// Ledge probe heights relative to the player's center
+const LEDGE_WALL_PROBE_Y: f32 = 10.0;
+const LEDGE_CLEAR_PROBE_Y: f32 = PLAYER_HALF_HEIGHT + 4.0;
+// Extra horizontal reach beyond the capsule for spotting a wall
+const LEDGE_REACH: f32 = 4.0;
+// Player center sits this far below the ledge top while hanging
+const LEDGE_HANG_DROP: f32 = 14.0;
+// No re-grab right after letting go
+const LEDGE_REGRAB_SECS: f32 = 0.3;
+
+// ───────── Config ─────────
+
+/// Optional platforming helpers; each one toggles independently for tuning.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PlatformingAssists {
+    /// Grace after walking off an edge during which Jump still works (0 = off).
+    pub coyote_secs: f32,
+    /// Slide around ceiling corners that would otherwise stop a jump.
+    pub corner_correction: bool,
+    /// Widest sideways nudge corner correction may apply, in world units.
+    pub corner_max_nudge: f32,
+    /// Snap into a hang when falling past a ledge while holding toward it.
+    pub ledge_hang: bool,
+}
+
+impl Default for PlatformingAssists {
+    fn default() -> Self {
+        Self {
+            coyote_secs: 0.1,
+            corner_correction: true,
+            corner_max_nudge: 6.0,
+            ledge_hang: true,
+        }
+    }
+}
+
+// ───────── Components ─────────
+
+/// Time (elapsed seconds) the player last stood on the ground.
+#[derive(Component, Clone, Copy)]
+pub struct LastGrounded(pub f64);
+
+/// Ledge found this frame; the state machine turns it into `Hanging`.
+#[derive(Component, Clone, Copy)]
+pub struct LedgeGrab {
+    pub pos: Vec2,
+}
+
+#[derive(Component)]
+struct LedgeRegrabCooldown(Timer);
+
+fn terrain_filter(one_way: &Query<Entity, With<OneWayPlatform>>) -> SpatialQueryFilter {
+    // One-way platforms can be jumped through and aren't walls
+    SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default))
+        .with_excluded_entities(one_way.iter())
+}
+
+// ───────── Triggers ─────────
+
+/// Jump pressed while falling, shortly after leaving the ground.
+pub fn coyote_jump(
+    In(e): In<Entity>,
+    assists: Res<PlatformingAssists>,
+    grace: Res<InputGrace>,
+    time: Res<Time>,
+    q: Query<(&ActionState<Action>, Option<&LastGrounded>)>,
+) -> bool {
+    if assists.coyote_secs <= 0.0 || grace.active() {
+        return false;
+    }
+    let Ok((actions, last)) = q.get(e) else {
+        return false;
+    };
+    let Some(last) = last else {
+        return false;
+    };
+    actions.just_pressed(&Action::Jump)
+        && time.elapsed_secs_f64() - last.0 <= assists.coyote_secs as f64
+}
+
+pub fn ledge_grabbed(In(e): In<Entity>, q: Query<(), With<LedgeGrab>>) -> bool {
+    q.contains(e)
+}
+
+// ───────── Systems ─────────
+
+fn track_last_grounded(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<
+        (Entity, Option<&mut LastGrounded>),
+        (
+            With<Player>,
+            Or<(
+                With<Idle>,
+                With<Walking>,
+                With<Running>,
+                With<IdleAttack>,
+                With<WalkingAttack>,
+                With<RunningAttack>,
+            )>,
+        ),
+    >,
+) {
+    let now = time.elapsed_secs_f64();
+    for (e, last) in &mut q {
+        match last {
+            Some(mut last) => last.0 = now,
+            None => {
+                commands.entity(e).insert(LastGrounded(now));
+            }
+        }
+    }
+}
+
+/// When a rising jump is about to bonk a ceiling edge, shift sideways onto
+/// the nearest clear column (up to `corner_max_nudge`) so the jump carries on.
+fn correct_jump_corners(
+    time: Res<Time>,
+    assists: Res<PlatformingAssists>,
+    spatial: SpatialQuery,
+    one_way: Query<Entity, With<OneWayPlatform>>,
+    mut q: Query<
+        (&mut Position, &LinearVelocity, &Collider),
+        (With<Player>, Or<(With<Jumping>, With<SprintJumping>)>),
+    >,
+) {
+    if !assists.corner_correction {
+        return;
+    }
+    let filter = terrain_filter(&one_way);
+    for (mut pos, vel, collider) in &mut q {
+        if vel.y <= 0.0 {
+            continue;
+        }
+        let config = ShapeCastConfig::from_max_distance(vel.y * time.delta_secs() + 1.0);
+        let blocked_from = |origin: Vec2| {
+            spatial
+                .cast_shape(collider, origin, 0.0, Dir2::Y, &config, &filter)
+                .is_some()
+        };
+        if !blocked_from(pos.0) {
+            continue;
+        }
+
+        let steps = assists.corner_max_nudge.floor() as i32;
+        let nudge = (1..=steps)
+            .flat_map(|n| [n as f32, -(n as f32)])
+            .find(|dx| {
+                let origin = pos.0 + Vec2::new(*dx, 0.0);
+                spatial
+                    .shape_intersections(collider, origin, 0.0, &filter)
+                    .is_empty()
+                    && !blocked_from(origin)
+            });
+        if let Some(dx) = nudge {
+            pos.0.x += dx;
+        }
+    }
+}
+
+/// Falling past a wall top while holding toward it: remember where to hang.
+fn detect_ledge_grab(
+    mut commands: Commands,
+    assists: Res<PlatformingAssists>,
+    spatial: SpatialQuery,
+    one_way: Query<Entity, With<OneWayPlatform>>,
+    q: Query<
+        (Entity, &Position, &LinearVelocity, &ActionState<Action>),
+        (
+            With<Player>,
+            With<Falling>,
+            Without<Stunned>,
+            Without<Dead>,
+            Without<LedgeGrab>,
+            Without<LedgeRegrabCooldown>,
+        ),
+    >,
+) {
+    if !assists.ledge_hang {
+        return;
+    }
+    let filter = terrain_filter(&one_way);
+    for (e, pos, vel, actions) in &q {
+        let axis = actions.value(&Action::Move);
+        if vel.y > 0.0 || axis.abs() < 0.5 {
+            continue;
+        }
+        let (side, dir) = if axis > 0.0 {
+            (1.0, Dir2::X)
+        } else {
+            (-1.0, Dir2::NEG_X)
+        };
+        let reach = PLAYER_RADIUS + LEDGE_REACH;
+
+        // A wall at chest height...
+        let chest = pos.0 + Vec2::new(0.0, LEDGE_WALL_PROBE_Y);
+        let Some(wall) = spatial.cast_ray(chest, dir, reach, true, &filter) else {
+            continue;
+        };
+        // ...but open air just above the head
+        let above = pos.0 + Vec2::new(0.0, LEDGE_CLEAR_PROBE_Y);
+        if spatial.cast_ray(above, dir, reach, true, &filter).is_some() {
+            continue;
+        }
+        // Find the top surface just past the wall face
+        let wall_x = chest.x + side * wall.distance;
+        let probe = Vec2::new(wall_x + side * 2.0, above.y);
+        let max_down = LEDGE_CLEAR_PROBE_Y - LEDGE_WALL_PROBE_Y;
+        let Some(top) = spatial.cast_ray(probe, Dir2::NEG_Y, max_down, true, &filter) else {
+            continue;
+        };
+        if top.distance <= 0.0 {
+            continue;
+        }
+        let top_y = probe.y - top.distance;
+
+        commands.entity(e).insert(LedgeGrab {
+            pos: Vec2::new(
+                wall_x - side * (PLAYER_RADIUS + 0.1),
+                top_y - LEDGE_HANG_DROP,
+            ),
+        });
+    }
+}
+
+fn on_added_hanging_snap(
+    mut commands: Commands,
+    mut q: Query<
+        (
+            Entity,
+            &mut Position,
+            &mut LinearVelocity,
+            Option<&LedgeGrab>,
+        ),
+        Added<Hanging>,
+    >,
+) {
+    for (e, mut pos, mut vel, grab) in &mut q {
+        if let Some(grab) = grab {
+            pos.0 = grab.pos;
+        }
+        vel.0 = Vec2::ZERO;
+        commands
+            .entity(e)
+            .insert(GravityScale(0.0))
+            .remove::<LedgeGrab>();
+    }
+}
+
+fn on_removed_hanging_release(
+    mut commands: Commands,
+    mut removed: RemovedComponents<Hanging>,
+    q: Query<(), With<Player>>,
+) {
+    for e in removed.read() {
+        if !q.contains(e) {
+            continue;
+        }
+        commands.entity(e).insert((
+            GravityScale(1.0),
+            LedgeRegrabCooldown(Timer::from_seconds(LEDGE_REGRAB_SECS, TimerMode::Once)),
+        ));
+    }
+}
+
+/// Drop stale grabs (the machine left Falling another way) and tick cooldowns.
+fn tidy_ledge_state(
+    time: Res<Time>,
+    mut commands: Commands,
+    stale: Query<Entity, (With<LedgeGrab>, Without<Falling>, Without<Hanging>)>,
+    mut cooldowns: Query<(Entity, &mut LedgeRegrabCooldown)>,
+) {
+    for e in &stale {
+        commands.entity(e).remove::<LedgeGrab>();
+    }
+    for (e, mut cd) in &mut cooldowns {
+        cd.0.tick(time.delta());
+        if cd.0.finished() {
+            commands.entity(e).remove::<LedgeRegrabCooldown>();
+        }
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct AssistsPlugin;
+
+impl Plugin for AssistsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlatformingAssists>()
+            .register_type::<PlatformingAssists>()
+            .add_systems(
+                Update,
+                (
+                    track_last_grounded,
+                    correct_jump_corners,
+                    detect_ledge_grab,
+                    tidy_ledge_state,
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                PostUpdate,
+                (on_added_hanging_snap, on_removed_hanging_release),
+            );
+    }
+}
@@ -1,34 +1,120 @@
-use crate::FilmicControls;
-use crate::character::Player;
-use crate::filmic_post::FilmicSettings;
-use crate::halation_post::HalationSettings;
+use crate::character::{Action, Player};
+use crate::dev::DevToggle;
+use crate::filmic_post::{FilmicOverrides, FilmicSettings};
+use crate::postfx::PostFxDefaults;
 use crate::prelude::*;
+use crate::settings::VideoConfig;
+use bevy::input::mouse::AccumulatedMouseScroll;
 use bevy_egui::PrimaryEguiContext;
 
+const FOLLOW_CAMERA_SCALE: f32 = 0.33;
+
+// Free camera (dev)
+const FREE_CAM_TOGGLE: KeyCode = KeyCode::F1;
+const FREE_CAM_SPEED: f32 = 400.0; // world units/s at the follow zoom
+const FREE_CAM_FAST_MULT: f32 = 3.0; // while Shift is held
+const FREE_CAM_ZOOM_STEP: f32 = 0.1; // per scroll line, relative
+const FREE_CAM_MIN_SCALE: f32 = 0.1;
+const FREE_CAM_MAX_SCALE: f32 = 3.0;
+
 #[derive(Component)]
 pub struct MainCamera;
 
 #[derive(Component)]
 pub struct MenuCamera;
 
-pub fn spawn_follow_camera(mut commands: Commands, existing: Query<(), With<MainCamera>>) {
+/// How `camera_follow` tracks the player vertically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum VerticalFollow {
+    /// Only moves to the height of the last ground the player stood on.
+    Locked,
+    /// Like `Locked`, but follows once the player gets further than
+    /// `vertical_band` from that height (long falls, tall climbs).
+    Banded,
+    /// Tracks every jump.
+    #[default]
+    Smooth,
+}
+
+/// Vertical framing for `camera_follow`: headroom while grounded, a
+/// look-down while falling fast so the landing is on screen.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CameraFraming {
+    /// Offset above the player while standing on something.
+    pub grounded_offset: f32,
+    /// Offset in the air when not falling fast.
+    pub airborne_offset: f32,
+    /// Downward speed where the look-down starts.
+    pub fall_speed_threshold: f32,
+    /// Look-down per unit of fall speed past the threshold.
+    pub fall_lookahead: f32,
+    pub max_fall_offset: f32,
+    /// How quickly the offset eases toward its target (1/s).
+    pub offset_smoothing: f32,
+    pub vertical: VerticalFollow,
+    /// Distance from the last grounded height before `Banded` follows.
+    pub vertical_band: f32,
+}
+
+impl Default for CameraFraming {
+    fn default() -> Self {
+        Self {
+            grounded_offset: 20.0,
+            airborne_offset: 3.0,
+            fall_speed_threshold: 250.0,
+            fall_lookahead: 0.12,
+            max_fall_offset: 60.0,
+            offset_smoothing: 4.0,
+            vertical: VerticalFollow::Smooth,
+            vertical_band: 48.0,
+        }
+    }
+}
+
+/// What pixel snapping shaved off the follow camera's position last frame;
+/// `camera_follow` adds it back so the smoothing keeps sub-pixel precision.
+#[derive(Component, Default)]
+pub struct SubPixelOffset(Vec2);
+
+/// Detached inspection camera: while on, `camera_follow` stops and
+/// WASD/arrows pan, scroll zooms, and the player ignores input.
+#[derive(Resource, Default)]
+pub struct FreeCam(pub bool);
+
+impl DevToggle for FreeCam {
+    const KEY: KeyCode = FREE_CAM_TOGGLE;
+
+    fn enabled(&self) -> bool {
+        self.0
+    }
+
+    fn set_enabled(&mut self, on: bool) {
+        self.0 = on;
+    }
+}
+
+pub fn spawn_follow_camera(
+    mut commands: Commands,
+    postfx: Res<PostFxDefaults>,
+    existing: Query<(), With<MainCamera>>,
+) {
     if existing.is_empty() {
         let mut projection = OrthographicProjection::default_2d();
-        projection.scale = 0.33;
+        projection.scale = FOLLOW_CAMERA_SCALE;
         commands.spawn((
             MainCamera,
             Camera2d,
-            HalationSettings {
-                p0: Vec4::new(0.6, 3.0, 0.7, 0.08),
-                p1: Vec4::new(1.0, 0.35, 0.25, 1.25),
-                p2: Vec4::new(1.2, 0.0, 0.0, 0.0),
-            },
+            postfx.halation.to_settings(),
             Msaa::Off,
+            postfx.deband_dither(),
             FilmicSettings::default(),
-            FilmicControls::default(),
+            postfx.filmic.clone(),
+            FilmicOverrides::default(),
             TiledParallaxCamera,
             PrimaryEguiContext,
             Projection::Orthographic(projection),
+            SubPixelOffset::default(),
             Light2d {
                 ambient_light: AmbientLight2d {
                     brightness: 0.1,
@@ -41,24 +127,166 @@ pub fn spawn_follow_camera(mut commands: Commands, existing: Query<(), With<Main
 
 pub fn camera_follow(
     time: Res<Time>,
-    player_q: Query<&GlobalTransform, With<Player>>,
-    mut cam_q: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
+    free_cam: Res<FreeCam>,
+    framing: Res<CameraFraming>,
+    mut offset: Local<f32>,
+    mut grounded_y: Local<Option<f32>>,
+    player_q: Query<(&GlobalTransform, &LinearVelocity, &CollidingEntities), With<Player>>,
+    mut cam_q: Query<(&mut Transform, &SubPixelOffset), (With<MainCamera>, Without<Player>)>,
 ) {
-    let Ok(player_gt) = player_q.single() else {
+    if free_cam.0 {
+        return;
+    }
+    let Ok((player_gt, vel, contacts)) = player_q.single() else {
         return;
     };
-    let Ok(mut cam_tf) = cam_q.single_mut() else {
+    let Ok((mut cam_tf, sub_pixel)) = cam_q.single_mut() else {
         return;
     };
-    let cam_adjust = Vec2::new(0., 3.);
-    let target_xy = player_gt.translation().truncate() + cam_adjust;
-    let current_xy = cam_tf.translation.truncate() + cam_adjust;
-    let t = 1.0 - (-10.0 * time.delta_secs()).exp();
+    let dt = time.delta_secs();
+
+    let grounded = !contacts.is_empty() && vel.y.abs() < 1.0;
+    let fall_speed = -vel.y - framing.fall_speed_threshold;
+    let target_offset = if grounded {
+        framing.grounded_offset
+    } else if fall_speed > 0.0 {
+        -(fall_speed * framing.fall_lookahead).min(framing.max_fall_offset)
+    } else {
+        framing.airborne_offset
+    };
+    *offset += (target_offset - *offset) * (1.0 - (-framing.offset_smoothing * dt).exp());
+
+    let player_xy = player_gt.translation().truncate();
+    if grounded {
+        *grounded_y = Some(player_xy.y);
+    }
+    let ground_y = grounded_y.unwrap_or(player_xy.y);
+    let follow = match framing.vertical {
+        VerticalFollow::Smooth => true,
+        VerticalFollow::Locked => false,
+        VerticalFollow::Banded => (player_xy.y - ground_y).abs() > framing.vertical_band,
+    };
+    let target_y = if follow {
+        player_xy.y + *offset
+    } else {
+        ground_y + framing.grounded_offset
+    };
+    let target_xy = Vec2::new(player_xy.x, target_y);
+    let current_xy = cam_tf.translation.truncate() + sub_pixel.0;
+    let t = 1.0 - (-10.0 * dt).exp();
     let new_xy = current_xy.lerp(target_xy, t);
     cam_tf.translation.x = new_xy.x;
     cam_tf.translation.y = new_xy.y;
 }
 
+/// Pixel-art shimmers when the view sits between pixels, so (if enabled)
+/// round the follow camera to whole screen pixels after `camera_follow`.
+pub fn snap_camera_to_pixels(
+    video: Res<VideoConfig>,
+    free_cam: Res<FreeCam>,
+    mut cam_q: Query<(&mut Transform, &Projection, &mut SubPixelOffset), With<MainCamera>>,
+) {
+    let Ok((mut cam_tf, projection, mut sub_pixel)) = cam_q.single_mut() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection else {
+        return;
+    };
+    if !video.pixel_snap || free_cam.0 || ortho.scale <= 0.0 {
+        sub_pixel.0 = Vec2::ZERO;
+        return;
+    }
+    // One screen pixel is `scale` world units
+    let raw = cam_tf.translation.truncate();
+    let snapped = (raw / ortho.scale).round() * ortho.scale;
+    sub_pixel.0 = raw - snapped;
+    cam_tf.translation.x = snapped.x;
+    cam_tf.translation.y = snapped.y;
+}
+
+/// Run when `FreeCam` flips (F1 via `toggle_dev_tool`, dev only). Turning it
+/// off, or disabling the dev tools, snaps back to the player at the normal zoom.
+pub fn apply_free_cam(
+    free_cam: Res<FreeCam>,
+    mut player_q: Query<(&GlobalTransform, &mut ActionState<Action>), With<Player>>,
+    mut cam_q: Query<(&mut Transform, &mut Projection), (With<MainCamera>, Without<Player>)>,
+) {
+    for (player_gt, mut actions) in &mut player_q {
+        if free_cam.0 {
+            actions.disable_all();
+        } else {
+            actions.enable_all();
+            if let Ok((mut cam_tf, mut projection)) = cam_q.single_mut() {
+                let p = player_gt.translation();
+                cam_tf.translation.x = p.x;
+                cam_tf.translation.y = p.y;
+                if let Projection::Orthographic(ortho) = &mut *projection {
+                    ortho.scale = FOLLOW_CAMERA_SCALE;
+                }
+            }
+        }
+    }
+}
+
+pub fn free_cam_move(
+    time: Res<Time<Real>>,
+    free_cam: Res<FreeCam>,
+    keys: Res<ButtonInput<KeyCode>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    mut cam_q: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    if !free_cam.0 {
+        return;
+    }
+    let Ok((mut cam_tf, mut projection)) = cam_q.single_mut() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = &mut *projection else {
+        return;
+    };
+
+    if scroll.delta.y != 0.0 {
+        let factor = (1.0 - FREE_CAM_ZOOM_STEP).powf(scroll.delta.y);
+        ortho.scale = (ortho.scale * factor).clamp(FREE_CAM_MIN_SCALE, FREE_CAM_MAX_SCALE);
+    }
+
+    let axis = |neg: [KeyCode; 2], pos: [KeyCode; 2]| {
+        let mut v = 0.0;
+        if keys.any_pressed(neg) {
+            v -= 1.0;
+        }
+        if keys.any_pressed(pos) {
+            v += 1.0;
+        }
+        v
+    };
+    let dir = Vec2::new(
+        axis(
+            [KeyCode::KeyA, KeyCode::ArrowLeft],
+            [KeyCode::KeyD, KeyCode::ArrowRight],
+        ),
+        axis(
+            [KeyCode::KeyS, KeyCode::ArrowDown],
+            [KeyCode::KeyW, KeyCode::ArrowUp],
+        ),
+    )
+    .normalize_or_zero();
+    let fast = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        FREE_CAM_FAST_MULT
+    } else {
+        1.0
+    };
+    // Keep the on-screen pan speed the same at any zoom
+    let speed = FREE_CAM_SPEED * fast * ortho.scale / FOLLOW_CAMERA_SCALE;
+    let step = dir * speed * time.delta_secs();
+    cam_tf.translation.x += step.x;
+    cam_tf.translation.y += step.y;
+}
+
+pub fn reset_free_cam(mut free_cam: ResMut<FreeCam>) {
+    free_cam.0 = false;
+}
+
 pub fn spawn_menu_camera(mut commands: Commands, q_existing: Query<(), With<MenuCamera>>) {
     if q_existing.is_empty() {
         commands.spawn((Camera2d, MenuCamera));
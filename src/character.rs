@@ -1,18 +1,27 @@
 use crate::animations::PlayerSpritesheet;
-use crate::animations::{DEFAULT_FRAME_MS, to_anim_name};
+use crate::animations::{
+    AnimClipRow, AnimHeld, AnimHold, AnimMap, AnimState, hold_anim_state, select_anim_state,
+};
+use crate::assists::{coyote_jump, ledge_grabbed};
 use crate::class::*;
-use crate::gameflow::{GameplayRoot, PlayerDied};
-use crate::hud::PlayerStats;
-use crate::level::PassThroughOneWayPlatform;
+use crate::enemy::{EnemyDead, EnemyStunned, GrabAttack};
+use crate::gameflow::{GameState, GameplayRoot, PlayerDied};
+use crate::hud::{DamagePlayer, PlayerStats, ShieldAbsorbed};
+use crate::level::{PassThroughOneWayPlatform, SpawnCheck};
 use crate::prelude::*;
-use crate::raycasts::{MeleeAttackActive, MeleeRaycastHit, MeleeRaycastSpec, RaycastMeleePlugin};
+use crate::raycasts::{
+    AimDirection, MeleeAttackActive, MeleeRaycastHit, MeleeRaycastSpec, RayBudgetFocus,
+    RaycastMeleePlugin, RaycastMeleeSet, SwingModifiers,
+};
+use crate::settings::{
+    AimMode, ControlsConfig, DifficultyConfig, InputConfig, Palette, SprintMode, variant_from_name,
+};
+use crate::world_scale::WorldScale;
 use avian2d::collision::collider::{CollisionLayers, LayerMask, PhysicsLayer};
 use avian2d::spatial_query::SpatialQueryFilter;
+use bevy::ecs::query::QueryData;
 use bevy::log::info;
 use bevy::sprite::Anchor;
-use seldom_state::trigger::just_pressed;
-use serde::Deserialize;
-use std::collections::HashMap;
 
 // ───────── Raycast Layers ─────────
 #[derive(PhysicsLayer, Default)]
@@ -23,14 +32,111 @@ pub enum GameLayer {
     Enemy,
 }
 
+// ───────── Render smoothing ─────────
+/// Physics runs in the fixed step; this eases the rendered `Transform` between
+/// the last two fixed-step positions (Avian's transform interpolation), so
+/// bodies move smoothly at any refresh rate.
+#[derive(Component, Default)]
+#[require(TransformInterpolation)]
+pub struct Interpolated;
+
 // ───────── Input ─────────
 #[derive(Actionlike, Clone, Eq, Hash, PartialEq, Reflect, Debug)]
 pub enum Action {
     #[actionlike(Axis)]
     Move,
+    /// Right stick; steers attacks when stick aim is enabled.
+    #[actionlike(DualAxis)]
+    Aim,
     Jump,
     Attack,
     Sprint,
+    Drop,
+    SwapWeapon,
+    /// Held with a grounded attack to swing the launcher.
+    Up,
+    /// Spends a full focus meter on the focus burst.
+    Special,
+    /// Short burst along the facing; also cancels late attack recovery.
+    Dash,
+}
+
+impl Action {
+    pub const ALL: [Action; 10] = [
+        Action::Move,
+        Action::Aim,
+        Action::Jump,
+        Action::Attack,
+        Action::Sprint,
+        Action::Drop,
+        Action::SwapWeapon,
+        Action::Up,
+        Action::Special,
+        Action::Dash,
+    ];
+}
+
+/// The player's `InputMap` from the persisted bindings. Names that don't
+/// parse are skipped with a warning; the sticks are always bound.
+pub fn build_input_map(config: &InputConfig) -> InputMap<Action> {
+    let mut map = InputMap::default()
+        .with_axis(
+            Action::Move,
+            GamepadControlAxis::new(GamepadAxis::LeftStickX),
+        )
+        .with_dual_axis(Action::Aim, GamepadStick::RIGHT);
+
+    let key = |name: &str| {
+        let parsed = variant_from_name::<KeyCode>(name);
+        if parsed.is_none() {
+            warn!("Input: unknown key {name:?}");
+        }
+        parsed
+    };
+    if let (Some(left), Some(right)) = (key(&config.move_left), key(&config.move_right)) {
+        map.insert_axis(Action::Move, VirtualAxis::new(left, right));
+    }
+
+    let buttons = [
+        (Action::Jump, &config.jump),
+        (Action::Attack, &config.attack),
+        (Action::Sprint, &config.sprint),
+        (Action::Drop, &config.drop),
+        (Action::Up, &config.up),
+        (Action::SwapWeapon, &config.swap_weapon),
+        (Action::Special, &config.special),
+        (Action::Dash, &config.dash),
+    ];
+    for (action, binding) in buttons {
+        for k in binding.keys.iter().filter_map(|n| key(n)) {
+            map.insert(action.clone(), k);
+        }
+        for name in &binding.gamepad {
+            match variant_from_name::<GamepadButton>(name) {
+                Some(b) => {
+                    map.insert(action.clone(), b);
+                }
+                None => warn!("Input: unknown gamepad button {name:?}"),
+            }
+        }
+    }
+    map
+}
+
+/// Apply the axis preferences in `ControlsConfig` to the player's raw input,
+/// before anything reads the move or aim values this frame.
+pub fn shape_axis_input(
+    controls: Res<ControlsConfig>,
+    mut q: Query<&mut ActionState<Action>, With<Player>>,
+) {
+    for mut actions in &mut q {
+        let shaped = controls.shape_move(actions.value(&Action::Move));
+        actions.set_value(&Action::Move, shaped);
+        if controls.invert_aim_y {
+            let aim = actions.axis_pair(&Action::Aim);
+            actions.set_axis_pair(&Action::Aim, Vec2::new(aim.x, -aim.y));
+        }
+    }
 }
 
 // ───────── States ─────────
@@ -58,6 +164,16 @@ pub struct SprintJumping;
 #[component(storage = "SparseSet")]
 pub struct Falling;
 
+/// Holding onto a ledge (see `assists`); Jump climbs, Drop lets go.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct Hanging;
+
+/// Dash burst (`Action::Dash`): fixed horizontal speed, no gravity, then Falling.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct Dashing;
+
 // Attack states & animation handling
 #[derive(Component, Reflect, Default, Debug, Clone)]
 #[component(storage = "SparseSet")]
@@ -86,6 +202,7 @@ struct AttackDurationsComp {
     run: f32,
     jump: f32,
     fall: f32,
+    charged: f32,
 }
 
 // ───────── Stun / Death ─────────
@@ -97,6 +214,40 @@ pub struct Stunned;
 #[component(storage = "SparseSet")]
 pub struct Dead;
 
+/// Held by a grappler (`GrabAttack`): no movement, facing, jumps or swings.
+/// Each Attack/Jump press takes one off `mash`; reaching zero breaks free
+/// with knockback on both sides, otherwise the hold lapses after `remaining`.
+#[derive(Component, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct Grabbed {
+    pub by: Entity,
+    pub remaining: f32,
+    pub mash: u32,
+    dps: f32,
+    owed: f32,
+    next_tick: f32,
+}
+
+impl Grabbed {
+    pub fn new(by: Entity, grab: &GrabAttack) -> Self {
+        Self {
+            by,
+            remaining: grab.hold,
+            mash: grab.mashes.max(1),
+            dps: grab.dps,
+            owed: 0.0,
+            next_tick: GRAB_TICK_S,
+        }
+    }
+}
+
+/// The player mashed out of `grabber`'s hold while standing at `from`.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct GrabBroken {
+    pub grabber: Entity,
+    pub from: Vec2,
+}
+
 #[derive(Component)]
 struct StunTimer(Timer);
 
@@ -113,62 +264,128 @@ struct ImpactDurations {
 #[derive(Component, Default, Debug, Clone, Copy)]
 struct LastHitDir(Vec2);
 
-#[derive(Deserialize)]
-struct MiniAnim {
-    name: String,
-    last_col: usize,
-}
-#[derive(Deserialize)]
-struct MiniManifest {
-    animations: Vec<MiniAnim>,
-}
-
-fn load_anim_seconds_from_json(json_path: &str) -> HashMap<String, f32> {
-    let mut map = HashMap::new();
-    if let Ok(text) = std::fs::read_to_string(json_path) {
-        if let Ok(manifest) = serde_json::from_str::<MiniManifest>(&text) {
-            for a in manifest.animations {
-                let pretty = to_anim_name(&a.name);
-                let frames = a.last_col as u32;
-                let secs = (frames * DEFAULT_FRAME_MS) as f32 / 1000.0;
-                map.insert(pretty, secs);
-            }
-        }
-    }
-    map
-}
-
 // ───────── Animation ────────
 #[derive(Component, Clone, Copy)]
+#[require(AnimHeld)]
 struct CurrentAnim(AnimationId);
 
-#[derive(Component, Clone, Copy)]
-pub struct AnimClips {
-    pub idle: AnimationId,
-    pub walk: Option<AnimationId>,
-    pub run: Option<AnimationId>,
-    pub jump: Option<AnimationId>,
-    pub fall: Option<AnimationId>,
-    pub attack_idle: AnimationId,
-    pub attack_walk: Option<AnimationId>,
-    pub attack_run: Option<AnimationId>,
-    pub attack_jump: Option<AnimationId>,
-    pub attack_fall: Option<AnimationId>,
-    // NEW:
-    pub stunned: Option<AnimationId>,
-    pub die: Option<AnimationId>,
-}
+/// Player clips per state; each row lists fallbacks after the preferred clip.
+const PLAYER_ANIM_CLIPS: &[AnimClipRow] = &[
+    (AnimState::Idle, &["player_combat:swordidle"]),
+    (AnimState::Walking, &["player_combat:swordrun"]),
+    (
+        AnimState::Running,
+        &["player_combat:swordsprint", "player_combat:swordrun"],
+    ),
+    (
+        AnimState::Jumping,
+        &["player_combat:swordjumpmid", "player_combat:swordjumpfall"],
+    ),
+    (AnimState::Falling, &["player_combat:swordjumpfall"]),
+    (
+        AnimState::Hanging,
+        &["player_combat:ledgehang", "player_combat:swordjumpfall"],
+    ),
+    (AnimState::IdleAttack, &["player_combat:standingslash"]),
+    (
+        AnimState::WalkingAttack,
+        &["player_combat:swordrunslash", "player_combat:standingslash"],
+    ),
+    (
+        AnimState::RunningAttack,
+        &[
+            "player_combat:swordsprintslash",
+            "player_combat:swordrunslash",
+            "player_combat:standingslash",
+        ],
+    ),
+    (
+        AnimState::JumpingAttack,
+        &["player_combat:airslashup", "player_combat:standingslash"],
+    ),
+    (
+        AnimState::FallingAttack,
+        &[
+            "player_combat:airslashdown",
+            "player_combat:airslashup",
+            "player_combat:standingslash",
+        ],
+    ),
+    (
+        AnimState::ChargedAttack,
+        &["player_combat:chargedslash", "player_combat:standingslash"],
+    ),
+    (AnimState::Stunned, &["player_combat:stunned"]),
+    (AnimState::Dead, &["player:die"]),
+];
+
+/// Spear attack clips, layered over `PLAYER_ANIM_CLIPS`; rows the sheet
+/// doesn't have keep the sword clip.
+const SPEAR_ANIM_CLIPS: &[AnimClipRow] = &[
+    (AnimState::IdleAttack, &["player_combat:spearthrust"]),
+    (
+        AnimState::WalkingAttack,
+        &["player_combat:spearrunthrust", "player_combat:spearthrust"],
+    ),
+    (
+        AnimState::RunningAttack,
+        &["player_combat:spearrunthrust", "player_combat:spearthrust"],
+    ),
+];
+
+/// Clip selection order for the player; first active state wins.
+const PLAYER_ANIM_PRIORITY: &[AnimState] = &[
+    AnimState::Dead,
+    AnimState::Stunned,
+    AnimState::Hanging,
+    AnimState::ChargedAttack,
+    AnimState::IdleAttack,
+    AnimState::WalkingAttack,
+    AnimState::RunningAttack,
+    AnimState::JumpingAttack,
+    AnimState::FallingAttack,
+    AnimState::Jumping,
+    AnimState::Falling,
+    AnimState::Running,
+    AnimState::Walking,
+    AnimState::Idle,
+];
 
 // ───────── Tuning ────────
 const PLAYER_SPEED: f32 = 160.0;
 const SPRINT_MULTIPLIER: f32 = 1.75;
 const JUMP_VELOCITY: f32 = 520.0;
+/// Base melee hit before level scaling (see `progression`).
+pub const PLAYER_MELEE_DAMAGE: i32 = 20;
 const ATTACK_COOLDOWN_S: f32 = 0.15;
+// How long an early attack press is remembered (covers swing tail + cooldown)
+const ATTACK_BUFFER_S: f32 = 0.2;
+// Input ignored this long after (re-)entering gameplay, e.g. un-pausing
+const INPUT_GRACE_S: f32 = 0.1;
+
+// Charged attack tuning
+const CHARGE_THRESHOLD_S: f32 = 0.6;
+const CHARGED_DAMAGE_MULT: f32 = 2.0;
+const CHARGED_KNOCKBACK_MULT: f32 = 1.8;
+const CHARGE_INDICATOR_DELAY_S: f32 = 0.12; // don't flash the meter on taps
+const CHARGE_INDICATOR_WIDTH: f32 = 24.0;
+
+// Launcher (up + grounded attack): pops enemies into the air for juggles
+const LAUNCHER_DAMAGE_MULT: f32 = 0.75;
+const LAUNCHER_KNOCKBACK_MULT: f32 = 0.25; // little horizontal push
+const LAUNCHER_POP: f32 = 460.0;
 
 // Knockback tuning
 const KNOCKBACK_SPEED: f32 = 280.0; // horiz push (was 240)
 const KNOCKBACK_POP: f32 = 260.0; // upward pop (was 40) ~ half a jump
 
+// Hold damage is banked and dealt in chunks this far apart
+const GRAB_TICK_S: f32 = 0.5;
+// Dash burst speed, length and the wait before the next one
+const DASH_SPEED: f32 = 520.0;
+const DASH_SECS: f32 = 0.18;
+const DASH_COOLDOWN_S: f32 = 0.5;
+
 // ───────── Tags ─────────
 #[derive(Component)]
 pub struct Player;
@@ -183,17 +400,102 @@ struct AttackTimer(Timer);
 #[derive(Component)]
 struct AttackDone;
 
+/// Recent attack request (a release on the ground, a press in the air) that
+/// couldn't start a swing yet; replayed by the attack trigger as soon as the
+/// current swing and cooldown allow.
+#[derive(Component)]
+struct AttackBuffer(Timer);
+
+/// Attack press already used in the air; its release (even after landing)
+/// doesn't ask for another swing.
+#[derive(Component)]
+struct AttackPressSpent;
+
+/// Toggle-mode sprint latch, flipped by each Sprint press and cleared when
+/// the player lets go of the stick.
+#[derive(Component, Default)]
+pub struct SprintToggled(pub bool);
+
+/// Sprint as the triggers see it: the held button in `Hold` mode, the
+/// latched `SprintToggled` flag in `Toggle` mode.
+pub fn effective_sprint(
+    mode: SprintMode,
+    actions: &ActionState<Action>,
+    toggled: Option<&SprintToggled>,
+) -> bool {
+    match mode {
+        SprintMode::Hold => actions.pressed(&Action::Sprint),
+        SprintMode::Toggle => toggled.is_some_and(|t| t.0),
+    }
+}
+
+/// Short window after entering `InGame` (new run or un-pause) in which player
+/// input is ignored, so whatever closed the menu doesn't also jump or swing.
+#[derive(Resource)]
+pub struct InputGrace {
+    pub timer: Timer,
+    /// Attack was already held on entry; ignored until it's let go.
+    swallow_attack: bool,
+}
+
+impl Default for InputGrace {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(INPUT_GRACE_S, TimerMode::Once),
+            swallow_attack: false,
+        }
+    }
+}
+
+impl InputGrace {
+    pub fn active(&self) -> bool {
+        !self.timer.finished()
+    }
+
+    pub fn blocks_attack(&self) -> bool {
+        self.active() || self.swallow_attack
+    }
+}
+
+/// Accumulates while Attack is held in a ground state; released past the
+/// threshold, the next swing is a charged one.
+#[derive(Component)]
+struct ChargeTimer(Timer);
+
+/// Current swing is charged (bonus damage/knockback, own clip).
+#[derive(Component)]
+struct ChargedSwing;
+
+/// Current swing is the launcher.
+#[derive(Component)]
+struct LauncherSwing;
+
+#[derive(Component)]
+struct ChargeIndicator;
+
+/// Running dash: its timer and horizontal direction (-1 or 1).
+#[derive(Component)]
+struct DashBurst {
+    timer: Timer,
+    dir: f32,
+}
+
+#[derive(Component)]
+struct DashCooldown(Timer);
+
 // ───────── Bundle ─────────
 #[derive(Bundle)]
 struct PlayerBundle {
     player: Player,
     gameflow: GameplayRoot,
+    ray_focus: RayBudgetFocus,
+    spawn_check: SpawnCheck,
     class: ClassAttachTarget,
     machine: StateMachine,
     idle: Idle,
     sprite: Sprite,
     anim: SpritesheetAnimation,
-    clips: AnimClips,
+    clips: AnimMap,
     current: CurrentAnim,
     body: RigidBody,
     lock: LockedAxes,
@@ -208,6 +510,7 @@ struct PlayerBundle {
     action_state: ActionState<Action>,
     transform: Transform,
     global_transform: GlobalTransform,
+    interpolated: Interpolated,
     // NEW:
     impacts: ImpactDurations,
 }
@@ -217,29 +520,12 @@ pub fn spawn_main_character(
     mut commands: Commands,
     sheet: Res<PlayerSpritesheet>,
     library: Res<AnimationLibrary>,
+    input: Res<InputConfig>,
+    world: Res<WorldScale>,
 ) {
     // Anim IDs
-    let idle_id = library
-        .animation_with_name("player_combat:swordidle")
-        .expect("missing animation: player_combat:swordidle");
-
-    let clips = AnimClips {
-        idle: idle_id,
-        walk: library.animation_with_name("player_combat:swordrun"),
-        run: library.animation_with_name("player_combat:swordsprint"),
-        jump: library.animation_with_name("player_combat:swordjumpmid"),
-        fall: library.animation_with_name("player_combat:swordjumpfall"),
-        attack_idle: library
-            .animation_with_name("player_combat:standingslash")
-            .expect("missing animation: player_combat:standingslash"),
-        attack_walk: library.animation_with_name("player_combat:swordrunslash"),
-        attack_run: library.animation_with_name("player_combat:swordsprintslash"),
-        attack_jump: library.animation_with_name("player_combat:airslashup"),
-        attack_fall: library.animation_with_name("player_combat:airslashdown"),
-        // NEW:
-        stunned: library.animation_with_name("player_combat:stunned"),
-        die: library.animation_with_name("player:die"),
-    };
+    let clips = AnimMap::build(&library, PLAYER_ANIM_CLIPS);
+    let idle_id = clips.clip(AnimState::Idle);
 
     // Sprite
     let mut sprite = Sprite::from_atlas_image(
@@ -252,25 +538,14 @@ pub fn spawn_main_character(
     sprite.anchor = Anchor::Custom(Vec2::new(0.0, -0.3));
 
     // Input
-    let input_map = InputMap::default()
-        .with_axis(Action::Move, VirtualAxis::new(KeyCode::KeyA, KeyCode::KeyD))
-        .with_axis(
-            Action::Move,
-            GamepadControlAxis::new(GamepadAxis::LeftStickX),
-        )
-        .with(Action::Jump, KeyCode::Space)
-        .with(Action::Jump, GamepadButton::South)
-        .with(Action::Attack, KeyCode::KeyJ)
-        .with(Action::Attack, GamepadButton::West)
-        .with(Action::Sprint, KeyCode::ShiftLeft)
-        .with(Action::Sprint, GamepadButton::LeftTrigger);
+    let input_map = build_input_map(&input);
 
     // Anim
     let mut anim = SpritesheetAnimation::from_id(idle_id);
     anim.playing = true;
 
-    // Durations from JSON
-    let secs_map = load_anim_seconds_from_json("assets/PlayerSheet2.json");
+    // Durations from the sheet manifest (same source as registration)
+    let secs_map = sheet.anim_seconds();
 
     // Attack durations
     let dur_idle = *secs_map.get("player_combat:standingslash").unwrap_or(&0.5);
@@ -286,12 +561,65 @@ pub fn spawn_main_character(
     let dur_fall = *secs_map
         .get("player_combat:airslashdown")
         .unwrap_or(&dur_jump);
+    let dur_charged = *secs_map
+        .get("player_combat:chargedslash")
+        .unwrap_or(&dur_idle);
     let attack_durs = AttackDurationsComp {
         idle: dur_idle,
         walk: dur_walk,
         run: dur_run,
         jump: dur_jump,
         fall: dur_fall,
+        charged: dur_charged,
+    };
+
+    // Weapons: sword (default) and spear. Same ray origin; the spear reaches
+    // further for less damage.
+    let enemy_mask = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Enemy));
+    let sword = WeaponDef {
+        name: "Sword",
+        spec: MeleeRaycastSpec {
+            offset: Vec2::new(18.0, 8.0),
+            length: 46.0,
+            max_hits: 1,
+            damage: PLAYER_MELEE_DAMAGE,
+            filter: enemy_mask.clone(),
+            solid: false,
+            once_per_swing: true,
+        },
+        clips: clips.clone(),
+        durations: attack_durs.clone(),
+    };
+    let spear_table: Vec<AnimClipRow> = PLAYER_ANIM_CLIPS
+        .iter()
+        .chain(SPEAR_ANIM_CLIPS)
+        .copied()
+        .collect();
+    let spear_thrust = *secs_map
+        .get("player_combat:spearthrust")
+        .unwrap_or(&attack_durs.idle);
+    let spear_run = *secs_map
+        .get("player_combat:spearrunthrust")
+        .unwrap_or(&attack_durs.run);
+    let spear = WeaponDef {
+        name: "Spear",
+        spec: MeleeRaycastSpec {
+            length: 72.0,
+            damage: PLAYER_MELEE_DAMAGE * 3 / 4,
+            ..sword.spec.clone()
+        },
+        // Later rows win, so the spear rows replace the sword's
+        clips: AnimMap::build(&library, &spear_table),
+        durations: AttackDurationsComp {
+            idle: spear_thrust,
+            walk: spear_run,
+            run: spear_run,
+            ..attack_durs.clone()
+        },
+    };
+    let weapons = Weapons {
+        current: 0,
+        specs: vec![sword, spear],
     };
 
     // NEW: impact (stun/death) durations with defaults
@@ -301,16 +629,28 @@ pub fn spawn_main_character(
     };
 
     // Triggers
-    fn walking(In(e): In<Entity>, act_q: Query<&ActionState<Action>>) -> bool {
+    fn walking(
+        In(e): In<Entity>,
+        act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
+    ) -> bool {
         if let Ok(a) = act_q.get(e) {
-            a.value(&Action::Move).abs() >= 0.5 && !a.pressed(&Action::Sprint)
+            a.value(&Action::Move).abs() >= 0.5
+                && !effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
         } else {
             false
         }
     }
-    fn sprinting(In(e): In<Entity>, act_q: Query<&ActionState<Action>>) -> bool {
+    fn sprinting(
+        In(e): In<Entity>,
+        act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
+    ) -> bool {
         if let Ok(a) = act_q.get(e) {
-            a.value(&Action::Move).abs() >= 0.5 && a.pressed(&Action::Sprint)
+            a.value(&Action::Move).abs() >= 0.5
+                && effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
         } else {
             false
         }
@@ -357,6 +697,8 @@ pub fn spawn_main_character(
     fn landed_walking(
         In(e): In<Entity>,
         act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
         contacts_q: Query<&CollidingEntities>,
         vel_q: Query<&LinearVelocity>,
         falling_q: Query<&Falling>,
@@ -376,12 +718,17 @@ pub fn spawn_main_character(
             && act_q
                 .get(e)
                 .ok()
-                .map(|a| a.value(&Action::Move).abs() >= 0.5 && !a.pressed(&Action::Sprint))
+                .map(|a| {
+                    a.value(&Action::Move).abs() >= 0.5
+                        && !effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
+                })
                 .unwrap_or(false)
     }
     fn landed_sprinting(
         In(e): In<Entity>,
         act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
         contacts_q: Query<&CollidingEntities>,
         vel_q: Query<&LinearVelocity>,
         falling_q: Query<&Falling>,
@@ -401,7 +748,10 @@ pub fn spawn_main_character(
             && act_q
                 .get(e)
                 .ok()
-                .map(|a| a.value(&Action::Move).abs() >= 0.5 && a.pressed(&Action::Sprint))
+                .map(|a| {
+                    a.value(&Action::Move).abs() >= 0.5
+                        && effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
+                })
                 .unwrap_or(false)
     }
     fn apex(
@@ -414,23 +764,102 @@ pub fn spawn_main_character(
         in_air && vy <= 0.0
     }
 
-    // Attack triggers
+    // Button triggers that respect the post-menu input grace
+    fn jump_pressed(
+        In(e): In<Entity>,
+        grace: Res<InputGrace>,
+        act_q: Query<&ActionState<Action>>,
+        grab_q: Query<(), With<Grabbed>>,
+    ) -> bool {
+        !grace.active()
+            && !grab_q.contains(e)
+            && act_q.get(e).is_ok_and(|a| a.just_pressed(&Action::Jump))
+    }
+    fn drop_pressed(
+        In(e): In<Entity>,
+        grace: Res<InputGrace>,
+        act_q: Query<&ActionState<Action>>,
+    ) -> bool {
+        !grace.active() && act_q.get(e).is_ok_and(|a| a.just_pressed(&Action::Drop))
+    }
+
+    // Attack triggers: on release where holding can charge (ground), on
+    // press where it can't (air); buffered requests count for both
+    fn attack_released_and_ready(
+        In(e): In<Entity>,
+        grace: Res<InputGrace>,
+        act_q: Query<&ActionState<Action>>,
+        cd_q: Query<&AttackCooldown>,
+        buffer_q: Query<&AttackBuffer>,
+        spent_q: Query<(), With<AttackPressSpent>>,
+        blocked_q: Query<(), Or<(With<Stunned>, With<Dead>, With<Grabbed>)>>,
+    ) -> bool {
+        if grace.blocks_attack() || blocked_q.contains(e) {
+            return false;
+        }
+        if let (Ok(a), Ok(cd)) = (act_q.get(e), cd_q.get(e)) {
+            let released = a.just_released(&Action::Attack) && !spent_q.contains(e);
+            let requested = released || buffer_q.get(e).is_ok();
+            requested && cd.0.finished()
+        } else {
+            false
+        }
+    }
     fn attack_pressed_and_ready(
         In(e): In<Entity>,
+        grace: Res<InputGrace>,
         act_q: Query<&ActionState<Action>>,
         cd_q: Query<&AttackCooldown>,
-        stun_q: Query<&Stunned>,
-        dead_q: Query<&Dead>,
+        buffer_q: Query<&AttackBuffer>,
+        blocked_q: Query<(), Or<(With<Stunned>, With<Dead>, With<Grabbed>)>>,
     ) -> bool {
-        if stun_q.get(e).is_ok() || dead_q.get(e).is_ok() {
+        if grace.blocks_attack() || blocked_q.contains(e) {
             return false;
         }
         if let (Ok(a), Ok(cd)) = (act_q.get(e), cd_q.get(e)) {
-            a.just_pressed(&Action::Attack) && cd.0.finished()
+            let requested = a.just_pressed(&Action::Attack) || buffer_q.get(e).is_ok();
+            requested && cd.0.finished()
         } else {
             false
         }
     }
+    // Dash triggers: a fresh press once the cooldown is over; from a swing
+    // only past the `AttackCancel` fraction of its timer
+    fn dash_pressed(
+        In(e): In<Entity>,
+        grace: Res<InputGrace>,
+        act_q: Query<&ActionState<Action>>,
+        cd_q: Query<&DashCooldown>,
+        blocked_q: Query<(), Or<(With<Stunned>, With<Dead>, With<Grabbed>)>>,
+    ) -> bool {
+        !grace.active()
+            && !blocked_q.contains(e)
+            && cd_q.get(e).ok().is_none_or(|cd| cd.0.finished())
+            && act_q.get(e).is_ok_and(|a| a.just_pressed(&Action::Dash))
+    }
+    fn dash_cancel(
+        In(e): In<Entity>,
+        grace: Res<InputGrace>,
+        cancel: Res<AttackCancel>,
+        act_q: Query<&ActionState<Action>>,
+        cd_q: Query<&DashCooldown>,
+        timer_q: Query<&AttackTimer>,
+        blocked_q: Query<(), Or<(With<Stunned>, With<Dead>, With<Grabbed>)>>,
+    ) -> bool {
+        let late = timer_q
+            .get(e)
+            .is_ok_and(|t| t.0.fraction() >= cancel.dash_after.clamp(0.0, 1.0));
+        late && !grace.active()
+            && !blocked_q.contains(e)
+            && cd_q.get(e).ok().is_none_or(|cd| cd.0.finished())
+            && act_q.get(e).is_ok_and(|a| a.just_pressed(&Action::Dash))
+    }
+    fn dash_finished(In(e): In<Entity>, q: Query<&DashBurst>) -> bool {
+        q.get(e).is_ok_and(|d| d.timer.finished())
+    }
+    fn stunned_or_dead(In(e): In<Entity>, q: Query<(), Or<(With<Stunned>, With<Dead>)>>) -> bool {
+        q.contains(e)
+    }
     fn attack_finished(In(e): In<Entity>, q: Query<&AttackDone>) -> bool {
         q.get(e).is_ok()
     }
@@ -438,75 +867,102 @@ pub fn spawn_main_character(
         In(e): In<Entity>,
         done_q: Query<&AttackDone>,
         act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
     ) -> bool {
         done_q.get(e).is_ok()
             && act_q
                 .get(e)
                 .ok()
-                .map(|a| a.value(&Action::Move).abs() >= 0.5 && !a.pressed(&Action::Sprint))
+                .map(|a| {
+                    a.value(&Action::Move).abs() >= 0.5
+                        && !effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
+                })
                 .unwrap_or(false)
     }
     fn attack_finished_sprinting(
         In(e): In<Entity>,
         done_q: Query<&AttackDone>,
         act_q: Query<&ActionState<Action>>,
+        controls: Res<ControlsConfig>,
+        toggle_q: Query<&SprintToggled>,
     ) -> bool {
         done_q.get(e).is_ok()
             && act_q
                 .get(e)
                 .ok()
-                .map(|a| a.value(&Action::Move).abs() >= 0.5 && a.pressed(&Action::Sprint))
+                .map(|a| {
+                    a.value(&Action::Move).abs() >= 0.5
+                        && effective_sprint(controls.sprint_mode, a, toggle_q.get(e).ok())
+                })
                 .unwrap_or(false)
     }
 
     // ───── Machine
     let machine = StateMachine::default()
         // IDLE
-        .trans::<Idle, _>(just_pressed(Action::Jump), Jumping)
-        .trans::<Idle, _>(attack_pressed_and_ready, IdleAttack)
+        .trans::<Idle, _>(jump_pressed, Jumping)
+        .trans::<Idle, _>(dash_pressed, Dashing)
+        .trans::<Idle, _>(attack_released_and_ready, IdleAttack)
         .trans::<Idle, _>(sprinting, Running)
         .trans::<Idle, _>(walking, Walking)
         .trans::<Idle, _>(step_off, Falling)
         // WALKING
-        .trans::<Walking, _>(just_pressed(Action::Jump), Jumping)
-        .trans::<Walking, _>(attack_pressed_and_ready, WalkingAttack)
+        .trans::<Walking, _>(jump_pressed, Jumping)
+        .trans::<Walking, _>(dash_pressed, Dashing)
+        .trans::<Walking, _>(attack_released_and_ready, WalkingAttack)
         .trans::<Walking, _>(sprinting, Running)
         .trans::<Walking, _>(stopped_moving, Idle)
         .trans::<Walking, _>(step_off, Falling)
         // RUNNING
-        .trans::<Running, _>(just_pressed(Action::Jump), SprintJumping)
-        .trans::<Running, _>(attack_pressed_and_ready, RunningAttack)
+        .trans::<Running, _>(jump_pressed, SprintJumping)
+        .trans::<Running, _>(dash_pressed, Dashing)
+        .trans::<Running, _>(attack_released_and_ready, RunningAttack)
         .trans::<Running, _>(walking, Walking)
         .trans::<Running, _>(stopped_moving, Idle)
         .trans::<Running, _>(step_off, Falling)
         // AIR (base)
+        .trans::<Jumping, _>(dash_pressed, Dashing)
         .trans::<Jumping, _>(attack_pressed_and_ready, JumpingAttack)
         .trans::<Jumping, _>(apex, Falling)
         .trans::<Jumping, _>(landed_sprinting, Running)
         .trans::<Jumping, _>(landed_walking, Walking)
         .trans::<Jumping, _>(landed, Idle)
+        .trans::<SprintJumping, _>(dash_pressed, Dashing)
         .trans::<SprintJumping, _>(attack_pressed_and_ready, JumpingAttack)
         .trans::<SprintJumping, _>(apex, Falling)
         .trans::<SprintJumping, _>(landed_sprinting, Running)
         .trans::<SprintJumping, _>(landed_walking, Walking)
         .trans::<SprintJumping, _>(landed, Idle)
+        .trans::<Falling, _>(ledge_grabbed, Hanging)
+        .trans::<Falling, _>(coyote_jump, Jumping)
+        .trans::<Falling, _>(dash_pressed, Dashing)
         .trans::<Falling, _>(attack_pressed_and_ready, FallingAttack)
         .trans::<Falling, _>(landed_sprinting, Running)
         .trans::<Falling, _>(landed_walking, Walking)
         .trans::<Falling, _>(landed, Idle)
+        // LEDGE
+        .trans::<Hanging, _>(stunned_or_dead, Falling)
+        .trans::<Hanging, _>(jump_pressed, Jumping)
+        .trans::<Hanging, _>(drop_pressed, Falling)
+        // DASH — lands through Falling when the burst ends
+        .trans::<Dashing, _>(dash_finished, Falling)
         // ATTACK (ground) — keep attack while moving; exit when timer finishes
+        .trans::<IdleAttack, _>(dash_cancel, Dashing)
         .trans::<IdleAttack, _>(attack_finished_sprinting, Running)
         .trans::<IdleAttack, _>(attack_finished_walking, Walking)
         .trans::<IdleAttack, _>(attack_finished, Idle)
         .trans::<IdleAttack, _>(sprinting, RunningAttack)
         .trans::<IdleAttack, _>(walking, WalkingAttack)
         .trans::<IdleAttack, _>(step_off, FallingAttack)
+        .trans::<WalkingAttack, _>(dash_cancel, Dashing)
         .trans::<WalkingAttack, _>(attack_finished_sprinting, Running)
         .trans::<WalkingAttack, _>(attack_finished_walking, Walking)
         .trans::<WalkingAttack, _>(attack_finished, Idle)
         .trans::<WalkingAttack, _>(sprinting, RunningAttack)
         .trans::<WalkingAttack, _>(stopped_moving, IdleAttack)
         .trans::<WalkingAttack, _>(step_off, FallingAttack)
+        .trans::<RunningAttack, _>(dash_cancel, Dashing)
         .trans::<RunningAttack, _>(attack_finished_sprinting, Running)
         .trans::<RunningAttack, _>(attack_finished_walking, Walking)
         .trans::<RunningAttack, _>(attack_finished, Idle)
@@ -514,22 +970,24 @@ pub fn spawn_main_character(
         .trans::<RunningAttack, _>(stopped_moving, IdleAttack)
         .trans::<RunningAttack, _>(step_off, FallingAttack)
         // ATTACK (air) — follow air logic; exit to air base when timer ends
+        .trans::<JumpingAttack, _>(dash_cancel, Dashing)
         .trans::<JumpingAttack, _>(attack_finished, Jumping)
         .trans::<JumpingAttack, _>(apex, FallingAttack)
         .trans::<JumpingAttack, _>(landed_sprinting, RunningAttack)
         .trans::<JumpingAttack, _>(landed_walking, WalkingAttack)
         .trans::<JumpingAttack, _>(landed, IdleAttack)
+        .trans::<FallingAttack, _>(dash_cancel, Dashing)
         .trans::<FallingAttack, _>(attack_finished, Falling)
         .trans::<FallingAttack, _>(landed_sprinting, RunningAttack)
         .trans::<FallingAttack, _>(landed_walking, WalkingAttack)
         .trans::<FallingAttack, _>(landed, IdleAttack);
 
-    let enemy_mask = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Enemy));
-
     let entity = commands
         .spawn(PlayerBundle {
             player: Player,
             gameflow: GameplayRoot,
+            ray_focus: RayBudgetFocus,
+            spawn_check: SpawnCheck::default(),
             class: ClassAttachTarget,
             machine,
             idle: Idle,
@@ -542,7 +1000,7 @@ pub fn spawn_main_character(
             restitution: Restitution::ZERO.with_combine_rule(CoefficientCombine::Min),
             friction: Friction::ZERO.with_combine_rule(CoefficientCombine::Min),
             damping: LinearDamping(2.0),
-            collider: Collider::capsule(8.0, 26.0),
+            collider: world.body_collider(),
             speculative: SpeculativeMargin(0.1),
             collisions: CollidingEntities::default(),
             one_way: PassThroughOneWayPlatform::Never,
@@ -550,18 +1008,14 @@ pub fn spawn_main_character(
             action_state: ActionState::default(),
             transform: Transform::from_xyz(0., 0., -100.1),
             global_transform: GlobalTransform::default(),
+            interpolated: Interpolated,
             impacts,
         })
-        .insert(MeleeRaycastSpec {
-            offset: Vec2::new(18.0, 8.0),
-            length: 46.0,
-            max_hits: 1,
-            damage: 20,
-            filter: enemy_mask,
-            solid: false,
-            once_per_swing: true,
-        })
+        .insert(weapons.active().spec.clone())
         .insert(attack_durs)
+        .insert(weapons)
+        .insert(SprintToggled::default())
+        .insert(AimDirection::default())
         .insert(Name::new("Player"))
         .insert(CollisionLayers::new(
             LayerMask::from(GameLayer::Player),
@@ -571,28 +1025,96 @@ pub fn spawn_main_character(
 
     commands
         .entity(entity)
-        .insert(AttackCooldown(Timer::from_seconds(0.0, TimerMode::Once)));
+        .insert(AttackCooldown(Timer::from_seconds(0.0, TimerMode::Once)))
+        .with_child((
+            ChargeIndicator,
+            Sprite::from_color(Color::WHITE, Vec2::new(0.0, 3.0)),
+            Transform::from_xyz(0.0, 30.0, 0.1),
+            Visibility::Hidden,
+            Name::new("ChargeIndicator"),
+        ));
 }
 
 // ───────── Motion ─────────
+/// How much movement a grounded swing keeps, per attack state: the input
+/// target speed is scaled by this and reached with `ATTACK_SLIDE_ACCEL`, so
+/// a running slash slides on instead of stopping dead (1.0 = full control).
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct AttackMomentum {
+    pub idle: f32,
+    pub walk: f32,
+    pub run: f32,
+}
+
+impl Default for AttackMomentum {
+    fn default() -> Self {
+        Self {
+            idle: 0.0,
+            walk: 0.4,
+            run: 0.6,
+        }
+    }
+}
+
+/// Late part of a swing that Dash can cancel: past this fraction of the
+/// attack timer, a dash press skips the rest of the swing and its cooldown.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct AttackCancel {
+    pub dash_after: f32,
+}
+
+impl Default for AttackCancel {
+    fn default() -> Self {
+        Self { dash_after: 0.6 }
+    }
+}
+
+const GROUND_ACCEL: f32 = 3600.0;
+const AIR_ACCEL: f32 = 1800.0;
+// Gentler than the ground accel so swings ease down rather than snap
+const ATTACK_SLIDE_ACCEL: f32 = 900.0;
+
 fn drive_motion_set_velocity(
     time: Res<Time>,
+    grace: Res<InputGrace>,
+    controls: Res<ControlsConfig>,
+    momentum: Res<AttackMomentum>,
+    world: Res<WorldScale>,
     mut q: Query<
         (
             &ActionState<Action>,
+            Option<&SprintToggled>,
             &mut LinearVelocity,
             Option<&Jumping>,
             Option<&Falling>,
             Option<&SprintJumping>,
             Option<&Stunned>,
             Option<&Dead>,
+            (Has<Hanging>, Has<Grabbed>),
+            (Has<IdleAttack>, Has<WalkingAttack>, Has<RunningAttack>),
+            Option<&DashBurst>,
         ),
         With<Player>,
     >,
 ) {
-    for (actions, mut vel, jumping, falling, sprint_jumping, stunned, dead) in &mut q {
-        // Dead → completely frozen
-        if dead.is_some() {
+    for (
+        actions,
+        toggled,
+        mut vel,
+        jumping,
+        falling,
+        sprint_jumping,
+        stunned,
+        dead,
+        (hanging, grabbed),
+        (idle_attack, walk_attack, run_attack),
+        dash,
+    ) in &mut q
+    {
+        // Dead, grabbed or hanging on a ledge → completely frozen
+        if dead.is_some() || ((hanging || grabbed) && stunned.is_none()) {
             vel.x = 0.0;
             vel.y = 0.0;
             continue;
@@ -601,21 +1123,47 @@ fn drive_motion_set_velocity(
         if stunned.is_some() {
             continue;
         }
+        // Dashing → fixed burst, held at its height
+        if let Some(dash) = dash {
+            vel.x = dash.dir * world.len(DASH_SPEED);
+            vel.y = 0.0;
+            continue;
+        }
 
-        let axis = actions.value(&Action::Move);
+        let axis = if grace.active() {
+            0.0
+        } else {
+            actions.value(&Action::Move)
+        };
         let in_air = jumping.is_some() || falling.is_some() || sprint_jumping.is_some();
-        let base_speed_mag = axis.abs() * PLAYER_SPEED;
+        let speed = world.len(PLAYER_SPEED);
+        let base_speed_mag = axis.abs() * speed;
         let already_above_base = vel.x.abs() > base_speed_mag;
         let sprint_mult = if sprint_jumping.is_some()
             || (falling.is_some() && already_above_base)
-            || (!in_air && actions.pressed(&Action::Sprint))
+            || (!in_air && effective_sprint(controls.sprint_mode, actions, toggled))
         {
             SPRINT_MULTIPLIER
         } else {
             1.0
         };
-        let target = axis * PLAYER_SPEED * sprint_mult;
-        let accel = if in_air { 1800.0 } else { 3600.0 };
+        let swing_keep = if idle_attack {
+            Some(momentum.idle)
+        } else if walk_attack {
+            Some(momentum.walk)
+        } else if run_attack {
+            Some(momentum.run)
+        } else {
+            None
+        };
+        let target = axis * speed * sprint_mult * swing_keep.unwrap_or(1.0).clamp(0.0, 1.0);
+        let accel = world.len(if in_air {
+            AIR_ACCEL
+        } else if swing_keep.is_some() {
+            ATTACK_SLIDE_ACCEL
+        } else {
+            GROUND_ACCEL
+        });
         let max_step = accel * time.delta_secs();
         let delta = (target - vel.x).clamp(-max_step, max_step);
         vel.x += delta;
@@ -623,6 +1171,7 @@ fn drive_motion_set_velocity(
 }
 
 fn on_added_jumping_set_impulse(
+    world: Res<WorldScale>,
     mut q: Query<
         (&mut LinearVelocity, Option<&Stunned>, Option<&Dead>),
         Or<(Added<Jumping>, Added<SprintJumping>)>,
@@ -632,12 +1181,13 @@ fn on_added_jumping_set_impulse(
         if stunned.is_some() || dead.is_some() {
             continue;
         }
-        vel.y = JUMP_VELOCITY;
+        vel.y = world.len(JUMP_VELOCITY);
     }
 }
 
 // NEW: Apply knockback velocity when Stunned is added
 fn on_added_stunned_apply_knockback(
+    world: Res<WorldScale>,
     mut q: Query<(&mut LinearVelocity, Option<&LastHitDir>, Option<&Sprite>), Added<Stunned>>,
 ) {
     for (mut vel, last_hit, sprite) in &mut q {
@@ -660,26 +1210,34 @@ fn on_added_stunned_apply_knockback(
             let facing_right = sprite.map(|s| !s.flip_x).unwrap_or(true);
             if facing_right { -1.0 } else { 1.0 }
         };
-        vel.x = x_sign * KNOCKBACK_SPEED;
-        vel.y = vel.y.max((dir.y.abs() * 0.5 + 1.0) * KNOCKBACK_POP); // force upward even if we were falling
+        vel.x = x_sign * world.len(KNOCKBACK_SPEED);
+        vel.y = vel
+            .y
+            .max((dir.y.abs() * 0.5 + 1.0) * world.len(KNOCKBACK_POP)); // force upward even if we were falling
     }
 }
 
 fn face_by_input(
+    controls: Res<ControlsConfig>,
     mut q: Query<
         (
             &ActionState<Action>,
             Option<&Stunned>,
             Option<&Dead>,
+            Has<MeleeAttackActive>,
             &mut Sprite,
         ),
-        With<Player>,
+        (With<Player>, Without<Grabbed>),
     >,
 ) {
-    for (actions, stunned, dead, mut sprite) in &mut q {
+    for (actions, stunned, dead, swinging, mut sprite) in &mut q {
         if stunned.is_some() || dead.is_some() {
             continue;
         }
+        // Aim assist picked the facing for this swing; keep it until it ends
+        if swinging && controls.aim_assist {
+            continue;
+        }
         let axis = actions.value(&Action::Move);
         if axis > 0.1 {
             sprite.flip_x = false;
@@ -703,6 +1261,56 @@ fn tick_attack_timers(
     }
 }
 
+/// Remember attack requests for `ATTACK_BUFFER_S`; starting a swing consumes it.
+fn buffer_attack_input(
+    time: Res<Time>,
+    grace: Res<InputGrace>,
+    mut commands: Commands,
+    mut q: Query<
+        (
+            Entity,
+            &ActionState<Action>,
+            Option<&mut AttackBuffer>,
+            Has<Grabbed>,
+            Has<AttackPressSpent>,
+            (
+                Has<Jumping>,
+                Has<SprintJumping>,
+                Has<Falling>,
+                Has<JumpingAttack>,
+                Has<FallingAttack>,
+            ),
+        ),
+        With<Player>,
+    >,
+) {
+    for (e, actions, buffer, grabbed, spent, air) in &mut q {
+        let airborne = air.0 || air.1 || air.2 || air.3 || air.4;
+        // Done with the spent press once it's fully let go
+        if spent && !actions.pressed(&Action::Attack) && !actions.just_released(&Action::Attack) {
+            commands.entity(e).remove::<AttackPressSpent>();
+        }
+        let pressed_in_air = airborne && actions.just_pressed(&Action::Attack);
+        // A release still counts in the air when the press began on the ground
+        let requested = pressed_in_air || (actions.just_released(&Action::Attack) && !spent);
+        // Mashing out of a grab shouldn't queue a swing for afterwards
+        if requested && !grace.blocks_attack() && !grabbed {
+            commands.entity(e).insert(AttackBuffer(Timer::from_seconds(
+                ATTACK_BUFFER_S,
+                TimerMode::Once,
+            )));
+            if pressed_in_air {
+                commands.entity(e).insert(AttackPressSpent);
+            }
+        } else if let Some(mut b) = buffer {
+            b.0.tick(time.delta());
+            if b.0.finished() {
+                commands.entity(e).remove::<AttackBuffer>();
+            }
+        }
+    }
+}
+
 fn on_enter_attack_start_timer(
     mut commands: Commands,
     q_added: Query<
@@ -725,9 +1333,11 @@ fn on_enter_attack_start_timer(
         ),
         With<Player>,
     >,
-    _q_clips: Query<&AnimClips, With<Player>>,
     q_durs: Query<&AttackDurationsComp, With<Player>>,
+    q_charge: Query<&ChargeTimer>,
+    q_actions: Query<&ActionState<Action>>,
     mut q_cd: Query<&mut AttackCooldown>,
+    world: Res<WorldScale>,
 ) {
     for e in &q_added {
         let d = q_durs.get(e).ok().cloned().unwrap_or(AttackDurationsComp {
@@ -736,6 +1346,7 @@ fn on_enter_attack_start_timer(
             run: 0.5,
             jump: 0.5,
             fall: 0.5,
+            charged: 0.5,
         });
         let (idle_a, walk_a, run_a, jump_a, fall_a) = q_state
             .get(e)
@@ -755,6 +1366,32 @@ fn on_enter_attack_start_timer(
             d.idle
         };
 
+        let charged = q_charge.get(e).is_ok_and(|c| c.0.finished());
+        let secs = if charged { d.charged } else { secs };
+        let grounded = idle_a.is_some() || walk_a.is_some() || run_a.is_some();
+        let launcher =
+            !charged && grounded && q_actions.get(e).is_ok_and(|a| a.pressed(&Action::Up));
+        commands.entity(e).remove::<(ChargeTimer, AttackBuffer)>();
+        if charged {
+            commands.entity(e).insert((
+                ChargedSwing,
+                SwingModifiers {
+                    damage_mult: CHARGED_DAMAGE_MULT,
+                    knockback_mult: CHARGED_KNOCKBACK_MULT,
+                    launch: 0.0,
+                },
+            ));
+        } else if launcher {
+            commands.entity(e).insert((
+                LauncherSwing,
+                SwingModifiers {
+                    damage_mult: LAUNCHER_DAMAGE_MULT,
+                    knockback_mult: LAUNCHER_KNOCKBACK_MULT,
+                    launch: world.len(LAUNCHER_POP),
+                },
+            ));
+        }
+
         commands
             .entity(e)
             .insert(AttackTimer(Timer::from_seconds(secs, TimerMode::Once)));
@@ -789,11 +1426,126 @@ fn finish_attack_when_timer_done(
             commands
                 .entity(e)
                 .insert(AttackDone)
-                .remove::<AttackTimer>();
+                .remove::<AttackTimer>()
+                .remove::<(ChargedSwing, LauncherSwing, SwingModifiers)>();
+        }
+    }
+}
+
+// ───────── Dash ─────────
+/// Start the burst along the stick (or the facing) and its cooldown. A
+/// swing it cancelled ends here, without `AttackDone` or the attack cooldown.
+fn on_enter_dash_start_timer(
+    mut commands: Commands,
+    q: Query<(Entity, &ActionState<Action>, &Sprite), (With<Player>, Added<Dashing>)>,
+) {
+    for (e, actions, sprite) in &q {
+        let axis = actions.value(&Action::Move);
+        let dir = if axis.abs() >= 0.1 {
+            axis.signum()
+        } else if sprite.flip_x {
+            -1.0
+        } else {
+            1.0
+        };
+        commands
+            .entity(e)
+            .insert((
+                DashBurst {
+                    timer: Timer::from_seconds(DASH_SECS, TimerMode::Once),
+                    dir,
+                },
+                DashCooldown(Timer::from_seconds(DASH_COOLDOWN_S, TimerMode::Once)),
+            ))
+            .remove::<(AttackTimer, AttackBuffer, ChargeTimer)>()
+            .remove::<(ChargedSwing, LauncherSwing, SwingModifiers)>();
+    }
+}
+
+fn tick_dash_timers(
+    time: Res<Time>,
+    mut q_burst: Query<&mut DashBurst, With<Player>>,
+    mut q_cd: Query<&mut DashCooldown, With<Player>>,
+) {
+    for mut d in &mut q_burst {
+        d.timer.tick(time.delta());
+    }
+    for mut cd in &mut q_cd {
+        cd.0.tick(time.delta());
+    }
+}
+
+fn clear_dash_burst(mut commands: Commands, q: Query<Entity, (With<DashBurst>, Without<Dashing>)>) {
+    for e in &q {
+        commands.entity(e).remove::<DashBurst>();
+    }
+}
+
+// ───────── Charged attack ─────────
+fn tick_attack_charge(
+    time: Res<Time>,
+    grace: Res<InputGrace>,
+    mut commands: Commands,
+    mut q: Query<
+        (
+            Entity,
+            &ActionState<Action>,
+            Option<&mut ChargeTimer>,
+            Has<Idle>,
+            Has<Walking>,
+            Has<Running>,
+            Has<Stunned>,
+            Has<Dead>,
+            Has<AttackPressSpent>,
+        ),
+        With<Player>,
+    >,
+) {
+    for (e, actions, charge, idle, walking, running, stunned, dead, spent) in &mut q {
+        // A press already spent in the air can't charge after landing
+        let chargeable =
+            (idle || walking || running) && !stunned && !dead && !spent && !grace.blocks_attack();
+        if chargeable && actions.pressed(&Action::Attack) {
+            match charge {
+                Some(mut c) => {
+                    c.0.tick(time.delta());
+                }
+                None => {
+                    commands.entity(e).insert(ChargeTimer(Timer::from_seconds(
+                        CHARGE_THRESHOLD_S,
+                        TimerMode::Once,
+                    )));
+                }
+            }
+        } else if charge.is_some() && !actions.just_released(&Action::Attack) {
+            // Left a chargeable state while holding, or the release didn't
+            // start an attack (cooldown); on_enter_attack_start_timer consumes it otherwise
+            commands.entity(e).remove::<ChargeTimer>();
         }
     }
 }
 
+fn update_charge_indicator(
+    q_player: Query<Option<&ChargeTimer>, With<Player>>,
+    mut q: Query<(&mut Sprite, &mut Visibility), With<ChargeIndicator>>,
+    palette: Res<Palette>,
+) {
+    let charge = q_player.single().ok().flatten();
+    for (mut sprite, mut vis) in &mut q {
+        let Some(c) = charge.filter(|c| c.0.elapsed_secs() >= CHARGE_INDICATOR_DELAY_S) else {
+            *vis = Visibility::Hidden;
+            continue;
+        };
+        *vis = Visibility::Inherited;
+        sprite.custom_size = Some(Vec2::new(CHARGE_INDICATOR_WIDTH * c.0.fraction(), 3.0));
+        sprite.color = if c.0.finished() {
+            palette.charge_ready
+        } else {
+            palette.charge
+        };
+    }
+}
+
 fn clear_attack_done(
     mut commands: Commands,
     q: Query<
@@ -831,34 +1583,18 @@ fn react_to_health_changes(
         return;
     }
 
-    if stats.health < prev && is_dead.is_none() {
-        if stats.health <= 0.0 {
-            // death path unchanged...
-            commands
-                .entity(e)
-                .remove::<Stunned>()
-                .remove::<StunTimer>()
-                .insert(Dead)
-                .insert(DeathTimer(Timer::from_seconds(
-                    impacts.die,
-                    TimerMode::Once,
-                )));
-        } else {
-            // ✅ Replace the old "clear attacks" block with these two lines:
-            // Disable hitbox while stunned, but keep the current machine state intact.
-            commands.entity(e).remove::<MeleeAttackActive>();
-            // Let the machine gracefully exit any attack state on its own:
-            commands.entity(e).insert(AttackDone);
-
-            // Enter stun (knockback is applied by on_added_stunned_apply_knockback)
-            commands
-                .entity(e)
-                .insert(Stunned)
-                .insert(StunTimer(Timer::from_seconds(
-                    impacts.stun,
-                    TimerMode::Once,
-                )));
-        }
+    // Stun for non-lethal hits lives in apply_damage_to_player, so ticking
+    // damage (statuses) never interrupts the player.
+    if stats.health < prev && is_dead.is_none() && stats.health <= 0.0 {
+        commands
+            .entity(e)
+            .remove::<Stunned>()
+            .remove::<StunTimer>()
+            .insert(Dead)
+            .insert(DeathTimer(Timer::from_seconds(
+                impacts.die,
+                TimerMode::Once,
+            )));
     }
 }
 
@@ -887,84 +1623,87 @@ fn tick_stun_and_death_timers(
 }
 
 // ───────── Animation (refactored to avoid >16 tuple limit) ─────────
+/// State flags the player clip selection looks at.
+#[derive(QueryData)]
+struct PlayerAnimFlags {
+    idle: Has<Idle>,
+    walking: Has<Walking>,
+    running: Has<Running>,
+    jumping: Has<Jumping>,
+    sprint_jumping: Has<SprintJumping>,
+    falling: Has<Falling>,
+    hanging: Has<Hanging>,
+    dashing: Has<Dashing>,
+    idle_attack: Has<IdleAttack>,
+    walking_attack: Has<WalkingAttack>,
+    running_attack: Has<RunningAttack>,
+    jumping_attack: Has<JumpingAttack>,
+    falling_attack: Has<FallingAttack>,
+    charged: Has<ChargedSwing>,
+    stunned: Has<Stunned>,
+    grabbed: Has<Grabbed>,
+    dead: Has<Dead>,
+}
+
 fn drive_animation(
     mut q_anim: Query<
         (
-            Entity,
-            &AnimClips,
+            &AnimMap,
             &mut SpritesheetAnimation,
             &mut CurrentAnim,
+            &mut AnimHeld,
             &LinearVelocity,
+            PlayerAnimFlags,
         ),
         With<Player>,
     >,
-    q_idle: Query<(), With<Idle>>,
-    q_walking: Query<(), With<Walking>>,
-    q_running: Query<(), With<Running>>,
-    q_jumping: Query<(), With<Jumping>>,
-    q_falling: Query<(), With<Falling>>,
-    q_sprint_jump: Query<(), With<SprintJumping>>,
-    q_idle_a: Query<(), With<IdleAttack>>,
-    q_walking_a: Query<(), With<WalkingAttack>>,
-    q_running_a: Query<(), With<RunningAttack>>,
-    q_jumping_a: Query<(), With<JumpingAttack>>,
-    q_falling_a: Query<(), With<FallingAttack>>,
-    q_stunned: Query<(), With<Stunned>>,
-    q_dead: Query<(), With<Dead>>,
+    time: Res<Time>,
+    hold: Res<AnimHold>,
 ) {
-    for (e, clips, mut anim, mut current, vel) in &mut q_anim {
-        let want = if q_dead.get(e).is_ok() {
-            clips.die.or(Some(clips.idle))
-        } else if q_stunned.get(e).is_ok() {
-            clips.stunned.or(Some(clips.idle))
-        } else if q_idle_a.get(e).is_ok() {
-            Some(clips.attack_idle)
-        } else if q_walking_a.get(e).is_ok() {
-            clips.attack_walk.or(Some(clips.attack_idle))
-        } else if q_running_a.get(e).is_ok() {
-            clips
-                .attack_run
-                .or(clips.attack_walk)
-                .or(Some(clips.attack_idle))
-        } else if q_jumping_a.get(e).is_ok() {
-            clips.attack_jump.or(Some(clips.attack_idle))
-        } else if q_falling_a.get(e).is_ok() {
-            clips
-                .attack_fall
-                .or(clips.attack_jump)
-                .or(Some(clips.attack_idle))
-        } else if q_sprint_jump.get(e).is_ok() || q_jumping.get(e).is_ok() {
-            clips.jump.or(clips.fall).or(Some(clips.idle))
-        } else if q_falling.get(e).is_ok() {
-            if vel.y > 0.0 {
-                clips.jump.or(clips.fall)
-            } else {
-                clips.fall
-            }
-            .or(Some(clips.idle))
-        } else if q_running.get(e).is_ok() {
-            clips.run.or(clips.walk).or(Some(clips.idle))
-        } else if q_walking.get(e).is_ok() {
-            clips.walk.or(Some(clips.idle))
-        } else if q_idle.get(e).is_ok() {
-            Some(clips.idle)
-        } else {
-            Some(clips.idle)
-        };
+    for (clips, mut anim, mut current, mut held, vel, f) in &mut q_anim {
+        let wanted = select_anim_state(PLAYER_ANIM_PRIORITY, |s| match s {
+            AnimState::Dead => f.dead,
+            AnimState::Stunned => f.stunned || f.grabbed,
+            AnimState::Hurt => false,
+            AnimState::Hanging => f.hanging,
+            AnimState::ChargedAttack => f.charged,
+            AnimState::IdleAttack => f.idle_attack,
+            AnimState::WalkingAttack => f.walking_attack,
+            AnimState::RunningAttack => f.running_attack,
+            AnimState::JumpingAttack => f.jumping_attack,
+            AnimState::FallingAttack => f.falling_attack,
+            // Falling while still moving up keeps the jump pose
+            AnimState::Jumping => f.jumping || f.sprint_jumping || (f.falling && vel.y > 0.0),
+            AnimState::Falling => f.falling,
+            AnimState::Running => f.running || f.dashing,
+            AnimState::Walking => f.walking,
+            AnimState::Idle => f.idle,
+        });
+        let state = hold_anim_state(
+            PLAYER_ANIM_PRIORITY,
+            &mut held,
+            wanted,
+            time.delta_secs(),
+            hold.min_secs,
+        );
 
-        if let Some(id) = want {
-            if current.0 != id {
-                *anim = SpritesheetAnimation::from_id(id);
-                anim.playing = true;
-                current.0 = id;
-            }
+        let id = clips.clip(state);
+        if current.0 != id {
+            *anim = SpritesheetAnimation::from_id(id);
+            anim.playing = true;
+            current.0 = id;
         }
     }
 }
 
 // ───────── Debug (refactored to small queries) ─────────
+/// The player's state machine moved to another state (by name).
+#[derive(Event, Debug, Clone)]
+pub struct PlayerStateChanged(pub &'static str);
+
 fn debug_log_player_state(
-    q_players: Query<Entity, With<Player>>,
+    mut changed: EventWriter<PlayerStateChanged>,
+    q_players: Query<(Entity, Has<Dashing>), With<Player>>,
     q_idle: Query<(), With<Idle>>,
     q_walking: Query<(), With<Walking>>,
     q_running: Query<(), With<Running>>,
@@ -980,11 +1719,13 @@ fn debug_log_player_state(
     q_dead: Query<(), With<Dead>>,
     mut last: Local<Option<&'static str>>,
 ) {
-    for e in &q_players {
+    for (e, dashing) in &q_players {
         let now: &'static str = if q_dead.get(e).is_ok() {
             "Dead"
         } else if q_stunned.get(e).is_ok() {
             "Stunned"
+        } else if dashing {
+            "Dashing"
         } else if q_idle_a.get(e).is_ok() {
             "IdleAttack"
         } else if q_walking_a.get(e).is_ok() {
@@ -1013,6 +1754,7 @@ fn debug_log_player_state(
 
         if last.as_deref() != Some(now) {
             info!("Player state → {}", now);
+            changed.write(PlayerStateChanged(now));
             *last = Some(now);
         }
     }
@@ -1066,84 +1808,434 @@ fn log_melee_hits(mut ev: EventReader<MeleeRaycastHit>) {
     }
 }
 
-// Record last hit direction for knockback
-fn record_last_hit_dir(
+/// Turn melee hits on the player into damage requests. A grappler's close
+/// hit grabs (see `grab_player_on_hit`) instead of staggering.
+fn melee_hits_to_damage_player(
     mut events: EventReader<MeleeRaycastHit>,
-    mut commands: Commands,
+    mut damage: EventWriter<DamagePlayer>,
     players: Query<(), With<Player>>,
     xforms: Query<&GlobalTransform>,
+    grabbers: Query<&GrabAttack>,
 ) {
     for hit in events.read() {
         if players.get(hit.target).is_ok() {
-            // Direction from attacker → target (i.e., away from attacker)
-            let dir = if let (Ok(att_tf), Ok(tgt_tf)) =
-                (xforms.get(hit.attacker), xforms.get(hit.target))
-            {
-                let d = tgt_tf.translation() - att_tf.translation();
-                Vec2::new(d.x, d.y).normalize_or_zero()
+            let source_pos = xforms
+                .get(hit.attacker)
+                .ok()
+                .map(|t| t.translation().truncate());
+            let grabs = grabbers
+                .get(hit.attacker)
+                .is_ok_and(|g| g.reaches(hit.distance));
+            damage.write(if grabs {
+                DamagePlayer::held(hit.damage as f32, source_pos)
             } else {
-                // Fallback: use normal if we couldn't get transforms
-                Vec2::new(hit.normal.x, hit.normal.y).normalize_or_zero()
-            };
+                DamagePlayer::hit(hit.damage as f32, source_pos)
+            });
+        }
+    }
+}
 
-            commands.entity(hit.target).insert(LastHitDir(dir));
+/// Hold damage, mashing and release for a grabbed player.
+fn struggle_in_grab(
+    mut commands: Commands,
+    time: Res<Time>,
+    world: Res<WorldScale>,
+    mut damage: EventWriter<DamagePlayer>,
+    mut broken: EventWriter<GrabBroken>,
+    mut q: Query<
+        (
+            Entity,
+            &ActionState<Action>,
+            &GlobalTransform,
+            &mut Grabbed,
+            &mut LinearVelocity,
+            Has<Stunned>,
+            Has<Dead>,
+        ),
+        With<Player>,
+    >,
+    grabbers: Query<&GlobalTransform, (Without<EnemyDead>, Without<EnemyStunned>)>,
+) {
+    let dt = time.delta_secs();
+    for (e, actions, gt, mut grab, mut vel, stunned, dead) in &mut q {
+        // Another hit staggering us, or the grappler going down, ends it
+        let Some(src) = grabbers
+            .get(grab.by)
+            .ok()
+            .filter(|_| !stunned && !dead)
+            .map(|t| t.translation().truncate())
+        else {
+            commands.entity(e).remove::<Grabbed>();
+            continue;
+        };
+        if grab.is_added() {
+            // Drop any swing in progress, as a stagger would
+            commands
+                .entity(e)
+                .remove::<MeleeAttackActive>()
+                .insert(AttackDone);
+        }
+        let presses = [Action::Attack, Action::Jump]
+            .iter()
+            .filter(|a| actions.just_pressed(a))
+            .count() as u32;
+        grab.mash = grab.mash.saturating_sub(presses);
+        grab.remaining -= dt;
+        grab.owed += grab.dps * dt;
+        grab.next_tick -= dt;
+        let ending = grab.mash == 0 || grab.remaining <= 0.0;
+        // No source: the grab hit already placed its indicator
+        if grab.next_tick <= 0.0 || ending {
+            damage.write(DamagePlayer::held(grab.owed, None));
+            grab.owed = 0.0;
+            grab.next_tick += GRAB_TICK_S;
+        }
+
+        let pos = gt.translation().truncate();
+        if grab.mash == 0 {
+            let away = if pos.x >= src.x { 1.0 } else { -1.0 };
+            vel.x = away * world.len(KNOCKBACK_SPEED);
+            vel.y = world.len(KNOCKBACK_POP);
+            broken.write(GrabBroken {
+                grabber: grab.by,
+                from: pos,
+            });
+            commands.entity(e).remove::<Grabbed>();
+        } else if grab.remaining <= 0.0 {
+            commands.entity(e).remove::<Grabbed>();
         }
     }
 }
 
-fn apply_melee_damage_to_player(
-    mut events: EventReader<MeleeRaycastHit>,
+/// The single place player health goes down: applies class defense, then
+/// stun + knockback for staggering hits.
+fn apply_damage_to_player(
+    mut commands: Commands,
+    mut events: EventReader<DamagePlayer>,
+    mut absorbed: EventWriter<ShieldAbsorbed>,
     mut stats: ResMut<PlayerStats>,
-    players: Query<Entity, With<Player>>,
-    dead_q: Query<(), With<Dead>>,
-    classes: Query<&PlayerClass>,
+    difficulty: Res<DifficultyConfig>,
+    q: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &ImpactDurations,
+            Option<&PlayerClass>,
+            Option<&StaminaShield>,
+            Option<&Dead>,
+        ),
+        With<Player>,
+    >,
 ) {
-    // If there's no player entity yet, bail.
-    if players.is_empty() {
+    let Ok((e, tf, impacts, class, shield, dead)) = q.single() else {
+        events.clear();
+        return;
+    };
+    // Don't process further damage after death
+    if dead.is_some() {
+        events.clear();
         return;
     }
 
-    for hit in events.read() {
-        // Only care about hits where the player is the target
-        if players.get(hit.target).is_ok() {
-            // Don't process further damage after death
-            if dead_q.get(hit.target).is_ok() {
-                continue;
+    // Pull defense from the player's class (0.0..=0.95)
+    let defense = class
+        .map(|c| c.0.base_stats.defense)
+        .unwrap_or(0.0)
+        .clamp(0.0, 0.95);
+    let shielded = shield.is_some_and(|s| s.0);
+
+    let mut stagger = false;
+    let mut hit_dir = None;
+    for ev in events.read() {
+        let dmg = if ev.ignore_defense {
+            ev.amount
+        } else {
+            ev.amount * (1.0 - defense)
+        };
+        let mut dmg = dmg.max(0.0) * difficulty.incoming_damage_scale.max(0.0);
+        // Hits land in whole points; ticks and holds stay fractional
+        if ev.stagger {
+            dmg = dmg.ceil();
+        }
+        // Stamina shield soaks what it can; the rest spills into health
+        if shielded {
+            let soaked = dmg.min(stats.stamina.max(0.0));
+            if soaked > 0.0 {
+                stats.stamina -= soaked;
+                dmg -= soaked;
+                absorbed.write(ShieldAbsorbed { amount: soaked });
+            }
+        }
+        stats.health = (stats.health - dmg).max(0.0);
+
+        if ev.stagger {
+            stagger = true;
+            // Direction from source → player (i.e., away from the attacker)
+            if let Some(src) = ev.source_pos {
+                hit_dir = Some((tf.translation().truncate() - src).normalize_or_zero());
             }
+        }
+    }
 
-            // Pull defense from the player's class (0.0..=0.95)
-            let defense = classes
-                .get(hit.target)
-                .map(|c| c.0.base_stats.defense)
-                .unwrap_or(0.0)
-                .clamp(0.0, 0.95);
+    // Lethal damage is handled by react_to_health_changes
+    if !stagger || stats.health <= 0.0 {
+        return;
+    }
+    if let Some(dir) = hit_dir {
+        commands.entity(e).insert(LastHitDir(dir));
+    }
+    // Disable hitbox while stunned, but keep the current machine state intact.
+    commands.entity(e).remove::<MeleeAttackActive>();
+    // Let the machine gracefully exit any attack state on its own:
+    commands.entity(e).insert(AttackDone);
+
+    // Enter stun (knockback is applied by on_added_stunned_apply_knockback)
+    commands
+        .entity(e)
+        .insert(Stunned)
+        .insert(StunTimer(Timer::from_seconds(
+            impacts.stun,
+            TimerMode::Once,
+        )));
+}
 
-            let dmg = (hit.damage as f32) * (1.0 - defense);
-            stats.health = (stats.health - dmg.max(0.0)).max(0.0);
+// ───────── Sprint toggle ─────────
+fn update_sprint_toggle(
+    controls: Res<ControlsConfig>,
+    mut q: Query<(&ActionState<Action>, &mut SprintToggled), With<Player>>,
+) {
+    for (actions, mut toggled) in &mut q {
+        let on = controls.sprint_mode == SprintMode::Toggle
+            && actions.value(&Action::Move).abs() >= 0.1
+            && (toggled.0 ^ actions.just_pressed(&Action::Sprint));
+        if toggled.0 != on {
+            toggled.0 = on;
         }
     }
 }
 
+// ───────── Stick aim ─────────
+// Stick deflection needed before it overrides facing
+const AIM_DEADZONE: f32 = 0.35;
+
+fn update_aim_direction(
+    controls: Res<ControlsConfig>,
+    mut q: Query<(&ActionState<Action>, &mut AimDirection), With<Player>>,
+) {
+    for (actions, mut aim) in &mut q {
+        let stick = actions.axis_pair(&Action::Aim);
+        let dir = if stick.length() < AIM_DEADZONE {
+            Vec2::ZERO
+        } else {
+            match controls.aim_mode {
+                AimMode::Off => Vec2::ZERO,
+                AimMode::Free => stick.normalize(),
+                AimMode::EightWay => {
+                    let step = std::f32::consts::FRAC_PI_4;
+                    Vec2::from_angle((stick.to_angle() / step).round() * step)
+                }
+            }
+        };
+        if aim.0 != dir {
+            aim.0 = dir;
+        }
+    }
+}
+
+// ───────── Aim assist ─────────
+// Vertical half-height of the band an enemy must be in to count
+const AIM_ASSIST_BAND_Y: f32 = 24.0;
+
+/// Accessibility: as a swing starts (and the stick isn't aiming it), turn to
+/// the nearest enemy within the weapon's reach on either side.
+fn aim_assist_facing(
+    controls: Res<ControlsConfig>,
+    spatial: SpatialQuery,
+    mut q: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &MeleeRaycastSpec,
+            &AimDirection,
+            &mut Sprite,
+        ),
+        (
+            With<Player>,
+            Or<(
+                Added<IdleAttack>,
+                Added<WalkingAttack>,
+                Added<RunningAttack>,
+                Added<JumpingAttack>,
+                Added<FallingAttack>,
+            )>,
+        ),
+    >,
+    targets: Query<&GlobalTransform, Without<Player>>,
+) {
+    if !controls.aim_assist {
+        return;
+    }
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Enemy));
+    for (e, gt, spec, aim, mut sprite) in &mut q {
+        if aim.0 != Vec2::ZERO {
+            continue;
+        }
+        let pos = gt.translation().truncate();
+        let reach = spec.offset.x.abs() + spec.length;
+        let band = Collider::rectangle(reach * 2.0, AIM_ASSIST_BAND_Y * 2.0);
+        let nearest = spatial
+            .shape_intersections(&band, pos, 0.0, &filter)
+            .into_iter()
+            .filter(|t| *t != e)
+            .filter_map(|t| targets.get(t).ok())
+            .map(|t| t.translation().x - pos.x)
+            .min_by(|a, b| a.abs().total_cmp(&b.abs()));
+        if let Some(dx) = nearest {
+            let flip = dx < 0.0;
+            if sprite.flip_x != flip {
+                sprite.flip_x = flip;
+            }
+        }
+    }
+}
+
+// ───────── Weapons ─────────
+
+/// One carried weapon: hit ray, attack clips and matching swing durations.
+#[derive(Clone)]
+pub struct WeaponDef {
+    pub name: &'static str,
+    pub spec: MeleeRaycastSpec,
+    pub clips: AnimMap,
+    durations: AttackDurationsComp,
+}
+
+/// Weapons the player carries; `Action::SwapWeapon` cycles `current`.
+#[derive(Component, Clone)]
+pub struct Weapons {
+    pub current: usize,
+    pub specs: Vec<WeaponDef>,
+}
+
+impl Weapons {
+    pub fn active(&self) -> &WeaponDef {
+        &self.specs[self.current]
+    }
+}
+
+/// Cycle to the next weapon (not mid-swing), swapping in its ray and clips.
+fn swap_weapon(
+    grace: Res<InputGrace>,
+    mut q: Query<
+        (
+            &ActionState<Action>,
+            &mut Weapons,
+            &mut MeleeRaycastSpec,
+            &mut AnimMap,
+            &mut AttackDurationsComp,
+            Has<MeleeAttackActive>,
+        ),
+        With<Player>,
+    >,
+) {
+    for (actions, mut weapons, mut spec, mut clips, mut durs, swinging) in &mut q {
+        if grace.active()
+            || swinging
+            || weapons.specs.len() < 2
+            || !actions.just_pressed(&Action::SwapWeapon)
+        {
+            continue;
+        }
+        // Carry over any scaling applied to the active damage (level-ups)
+        let scale = spec.damage as f32 / weapons.active().spec.damage.max(1) as f32;
+        weapons.current = (weapons.current + 1) % weapons.specs.len();
+
+        let def = weapons.active();
+        *spec = MeleeRaycastSpec {
+            damage: (def.spec.damage as f32 * scale).round() as i32,
+            ..def.spec.clone()
+        };
+        *clips = def.clips.clone();
+        *durs = def.durations.clone();
+        info!("Weapon: {}", def.name);
+    }
+}
+
+// ───────── Input grace ─────────
+fn start_input_grace(mut grace: ResMut<InputGrace>, q: Query<&ActionState<Action>, With<Player>>) {
+    grace.timer.reset();
+    grace.swallow_attack = q.iter().any(|a| a.pressed(&Action::Attack));
+}
+
+fn tick_input_grace(
+    time: Res<Time>,
+    mut grace: ResMut<InputGrace>,
+    q: Query<&ActionState<Action>, With<Player>>,
+) {
+    grace.timer.tick(time.delta());
+    // Keep swallowing through the release frame so it can't start a swing
+    if grace.swallow_attack
+        && q.iter()
+            .all(|a| !a.pressed(&Action::Attack) && !a.just_released(&Action::Attack))
+    {
+        grace.swallow_attack = false;
+    }
+}
+
 // ───────── Plugin ─────────
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(RaycastMeleePlugin)
+            .add_event::<PlayerStateChanged>()
+            .add_event::<GrabBroken>()
+            .init_resource::<AttackMomentum>()
+            .register_type::<AttackMomentum>()
+            .init_resource::<AttackCancel>()
+            .register_type::<AttackCancel>()
+            .init_resource::<InputGrace>()
+            .add_systems(OnEnter(GameState::InGame), start_input_grace)
+            .add_systems(Update, tick_input_grace.before(buffer_attack_input))
+            .add_systems(
+                Update,
+                update_sprint_toggle.before(drive_motion_set_velocity),
+            )
+            .add_systems(
+                PreUpdate,
+                shape_axis_input.in_set(InputManagerSystem::ManualControl),
+            )
+            .add_systems(Update, update_aim_direction.before(RaycastMeleeSet::Cast))
+            .add_systems(Update, swap_weapon.after(tick_input_grace))
+            .add_systems(
+                Update,
+                (melee_hits_to_damage_player, apply_damage_to_player)
+                    .chain()
+                    .in_set(RaycastMeleeSet::ApplyDamage),
+            )
             .add_systems(
                 Update,
                 (
-                    record_last_hit_dir,
-                    apply_melee_damage_to_player,
                     react_to_health_changes,
                     tick_stun_and_death_timers,
                     drive_motion_set_velocity,
+                    // After motion, so the break-free knockback isn't zeroed
+                    struggle_in_grab.after(drive_motion_set_velocity),
                     face_by_input,
+                    aim_assist_facing.after(face_by_input),
                     debug_log_player_state,
                     tick_attack_timers,
-                    on_enter_attack_start_timer,
+                    buffer_attack_input,
+                    (on_enter_attack_start_timer, tick_attack_charge).chain(),
+                    update_charge_indicator,
                     finish_attack_when_timer_done,
                     clear_attack_done,
+                    // Drop the cancelled swing's timer before it can finish
+                    on_enter_dash_start_timer
+                        .before(drive_motion_set_velocity)
+                        .before(finish_attack_when_timer_done),
+                    tick_dash_timers,
+                    clear_dash_burst,
                     bridge_attack_states_to_melee_tag,
                     log_melee_hits,
                 ),
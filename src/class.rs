@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use serde::Deserialize;
+use std::path::Path;
 
 #[derive(Debug, Clone, Deserialize, Reflect, Resource)]
 pub struct ClassFile {
@@ -33,6 +34,43 @@ pub struct BaseStats {
     pub projectile_speed: f32,
     pub stamina_max: f32,
     pub stamina_regen_per_s: f32,
+    /// Incoming damage drains stamina first and only spills into health
+    /// once stamina is empty.
+    #[serde(default)]
+    pub stamina_shield: bool,
+}
+
+/// Built-in class used when the class JSON is missing or malformed.
+impl Default for ClassFile {
+    fn default() -> Self {
+        Self {
+            id: "class_unknown".into(),
+            display_name: "Unknown".into(),
+            tags: Vec::new(),
+            attribute_start: Attributes {
+                might: 5,
+                agility: 5,
+                focus: 5,
+                grit: 5,
+            },
+            base_stats: BaseStats {
+                max_health: 100,
+                defense: 0.0,
+                knockback_resist: 0.0,
+                melee_power: 1.0,
+                spell_power: 1.0,
+                knockback: 1.0,
+                move_speed: 1.0,
+                crit_chance: 0.05,
+                crit_multiplier: 1.5,
+                attack_cooldown_reduction: 0.0,
+                projectile_speed: 1.0,
+                stamina_max: 100.0,
+                stamina_regen_per_s: 10.0,
+                stamina_shield: false,
+            },
+        }
+    }
 }
 
 #[derive(Component, Reflect)]
@@ -43,9 +81,70 @@ pub struct ClassAttachTarget;
 #[reflect(Component)]
 pub struct PlayerClass(pub ClassFile);
 
+/// Whether stamina soaks damage before health; set from
+/// `BaseStats::stamina_shield` when the class attaches.
+#[derive(Component, Reflect, Clone, Copy, Default)]
+#[reflect(Component)]
+pub struct StaminaShield(pub bool);
+
+/// One class listed in the roster manifest. `path` is relative to the
+/// manifest; `description` is extra text for selection screens.
+#[derive(Debug, Clone, Deserialize, Reflect)]
+pub struct RosterEntry {
+    pub id: String,
+    pub path: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// `classes.json`: every playable class plus the one a new run starts with
+/// (the first entry if `default` is empty).
+#[derive(Debug, Clone, Deserialize)]
+struct ClassManifest {
+    #[serde(default)]
+    default: String,
+    classes: Vec<RosterEntry>,
+}
+
+/// All playable classes, loaded once from the manifest (or the single class
+/// file when there is none). `ClassFile` holds the one in play.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ClassRoster {
+    pub default: String,
+    pub classes: Vec<(RosterEntry, ClassFile)>,
+}
+
+impl ClassRoster {
+    pub fn get(&self, id: &str) -> Option<&ClassFile> {
+        self.classes
+            .iter()
+            .find(|(entry, _)| entry.id == id)
+            .map(|(_, class)| class)
+    }
+
+    pub fn default_class(&self) -> Option<&ClassFile> {
+        self.get(&self.default)
+    }
+
+    fn single(path: &str, class: ClassFile) -> Self {
+        let entry = RosterEntry {
+            id: class.id.clone(),
+            path: path.into(),
+            description: String::new(),
+        };
+        Self {
+            default: class.id.clone(),
+            classes: vec![(entry, class)],
+        }
+    }
+}
+
 #[derive(Resource, Clone)]
 pub struct ClassPluginConfig {
     pub path: String,
+    /// Roster manifest; when it's missing the single `path` class is used.
+    pub manifest: Option<String>,
     pub spawn_debug_holder_if_missing: bool,
 }
 
@@ -58,11 +157,19 @@ impl ClassPlugin {
         Self {
             config: ClassPluginConfig {
                 path: path.into(),
+                manifest: None,
                 spawn_debug_holder_if_missing: true,
             },
         }
     }
 
+    /// Load the class roster from a manifest, falling back to the single
+    /// class file if the manifest can't be read.
+    pub fn with_manifest(mut self, path: impl Into<String>) -> Self {
+        self.config.manifest = Some(path.into());
+        self
+    }
+
     pub fn spawn_debug_holder(mut self, enabled: bool) -> Self {
         self.config.spawn_debug_holder_if_missing = enabled;
         self
@@ -74,24 +181,83 @@ impl Plugin for ClassPlugin {
         app.insert_resource(self.config.clone())
             .register_type::<ClassAttachTarget>()
             .register_type::<PlayerClass>()
+            .register_type::<StaminaShield>()
             .register_type::<ClassFile>()
             .register_type::<Attributes>()
             .register_type::<BaseStats>()
-            .add_systems(PreStartup, (load_class_from_json, maybe_spawn_debug_holder))
+            .register_type::<RosterEntry>()
+            .register_type::<ClassRoster>()
+            .add_systems(PreStartup, (load_class_roster, maybe_spawn_debug_holder))
             .add_systems(Update, attach_class_to_targets);
     }
 }
 
-fn load_class_from_json(mut commands: Commands, cfg: Res<ClassPluginConfig>) {
-    let path = &cfg.path;
-    let json = std::fs::read_to_string(path).unwrap_or_else(|e| {
-        panic!("Failed to read class JSON at {path}: {e}");
+fn read_class_file(path: &str) -> Result<ClassFile, String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {path}: {e}"))
+        .and_then(|json| {
+            serde_json::from_str::<ClassFile>(&json)
+                .map_err(|e| format!("invalid class JSON in {path}: {e}"))
+        })
+}
+
+/// Every manifest entry that loads; broken ones are logged and left out.
+fn read_manifest(path: &str) -> Result<ClassRoster, String> {
+    let manifest = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {path}: {e}"))
+        .and_then(|json| {
+            serde_json::from_str::<ClassManifest>(&json)
+                .map_err(|e| format!("invalid manifest JSON in {path}: {e}"))
+        })?;
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let classes: Vec<_> = manifest
+        .classes
+        .into_iter()
+        .filter_map(|entry| {
+            let file = dir.join(&entry.path);
+            match read_class_file(&file.to_string_lossy()) {
+                Ok(class) => Some((entry, class)),
+                Err(e) => {
+                    error!("ClassPlugin: roster entry '{}': {e}", entry.id);
+                    None
+                }
+            }
+        })
+        .collect();
+    let Some((first, _)) = classes.first() else {
+        return Err(format!("no loadable classes in {path}"));
+    };
+    let default = if manifest.default.is_empty() {
+        first.id.clone()
+    } else {
+        manifest.default
+    };
+    Ok(ClassRoster { default, classes })
+}
+
+fn load_class_roster(mut commands: Commands, cfg: Res<ClassPluginConfig>) {
+    let from_manifest = cfg.manifest.as_deref().and_then(|path| {
+        read_manifest(path)
+            .inspect_err(|e| warn!("ClassPlugin: {e}; using {}", cfg.path))
+            .ok()
     });
-    let class_file: ClassFile = serde_json::from_str(&json).unwrap_or_else(|e| {
-        panic!("Invalid class JSON format for {path}: {e}");
+    let roster = from_manifest.unwrap_or_else(|| {
+        let class = read_class_file(&cfg.path).unwrap_or_else(|e| {
+            error!("ClassPlugin: {e}; using the built-in default class");
+            ClassFile::default()
+        });
+        ClassRoster::single(&cfg.path, class)
     });
 
+    let class_file = roster.default_class().cloned().unwrap_or_else(|| {
+        error!(
+            "ClassPlugin: default class '{}' isn't in the roster; using the first",
+            roster.default
+        );
+        roster.classes[0].1.clone()
+    });
     commands.insert_resource(class_file);
+    commands.insert_resource(roster);
 }
 
 fn maybe_spawn_debug_holder(
@@ -123,7 +289,10 @@ fn attach_class_to_targets(
     let Some(class_file) = class_file else { return };
     for (e, maybe_existing) in &q_targets {
         if maybe_existing.is_none() {
-            commands.entity(e).insert(PlayerClass(class_file.clone()));
+            commands.entity(e).insert((
+                PlayerClass(class_file.clone()),
+                StaminaShield(class_file.base_stats.stamina_shield),
+            ));
         }
     }
 }
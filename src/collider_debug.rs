@@ -0,0 +1,128 @@
+// collider_debug.rs
+use crate::dev::{DevToggle, dev_tool_on, toggle_dev_tool};
+use crate::gameflow::GameState;
+use crate::level::OneWayPlatform;
+use crate::prelude::*;
+use avian2d::parry::shape::Shape;
+
+const COLLIDER_DEBUG_TOGGLE: KeyCode = KeyCode::F9;
+const SOLID_COLOR: Color = Color::srgb(0.2, 0.85, 1.0);
+const ONE_WAY_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+/// Outlines of the static level colliders (one-way platforms in yellow),
+/// drawn with gizmos instead of the full `PhysicsDebugPlugin`. F9 toggles it
+/// while the dev tools are on; nothing runs while it's off.
+#[derive(Resource, Default)]
+pub struct ColliderDebug {
+    pub enabled: bool,
+}
+
+impl DevToggle for ColliderDebug {
+    const KEY: KeyCode = COLLIDER_DEBUG_TOGGLE;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, on: bool) {
+        self.enabled = on;
+    }
+}
+
+fn v2(p: &avian2d::parry::math::Point<f32>) -> Vec2 {
+    Vec2::new(p.x, p.y)
+}
+
+fn draw_shape(gizmos: &mut Gizmos, shape: &dyn Shape, iso: Isometry2d, color: Color) {
+    let closed = |gizmos: &mut Gizmos, points: Vec<Vec2>| {
+        let first = points.first().copied();
+        gizmos.linestrip_2d(
+            points
+                .into_iter()
+                .chain(first)
+                .map(|p| iso.transform_point(p)),
+            color,
+        );
+    };
+
+    if let Some(cuboid) = shape.as_cuboid() {
+        let half = Vec2::new(cuboid.half_extents.x, cuboid.half_extents.y);
+        gizmos.rect_2d(iso, half * 2.0, color);
+    } else if let Some(poly) = shape.as_convex_polygon() {
+        closed(gizmos, poly.points().iter().map(v2).collect());
+    } else if let Some(tri) = shape.as_triangle() {
+        closed(gizmos, vec![v2(&tri.a), v2(&tri.b), v2(&tri.c)]);
+    } else if let Some(line) = shape.as_polyline() {
+        for seg in line.segments() {
+            gizmos.line_2d(
+                iso.transform_point(v2(&seg.a)),
+                iso.transform_point(v2(&seg.b)),
+                color,
+            );
+        }
+    } else if let Some(seg) = shape.as_segment() {
+        gizmos.line_2d(
+            iso.transform_point(v2(&seg.a)),
+            iso.transform_point(v2(&seg.b)),
+            color,
+        );
+    } else if let Some(ball) = shape.as_ball() {
+        gizmos.circle_2d(iso, ball.radius, color);
+    } else if let Some(compound) = shape.as_compound() {
+        for (sub_iso, sub) in compound.shapes() {
+            let local = Isometry2d::new(
+                Vec2::new(sub_iso.translation.x, sub_iso.translation.y),
+                Rot2::radians(sub_iso.rotation.angle()),
+            );
+            draw_shape(gizmos, &**sub, iso * local, color);
+        }
+    } else {
+        // Anything else: its bounding box
+        let aabb = shape.compute_local_aabb();
+        let (min, max) = (v2(&aabb.mins), v2(&aabb.maxs));
+        let center = iso.transform_point((min + max) * 0.5);
+        gizmos.rect_2d(Isometry2d::new(center, iso.rotation), max - min, color);
+    }
+}
+
+fn draw_level_colliders(
+    mut gizmos: Gizmos,
+    colliders: Query<(
+        &Collider,
+        &GlobalTransform,
+        Option<&ColliderOf>,
+        Option<&RigidBody>,
+        Has<OneWayPlatform>,
+    )>,
+    bodies: Query<&RigidBody>,
+) {
+    for (collider, gt, collider_of, own_body, one_way) in &colliders {
+        let body = own_body.or_else(|| collider_of.and_then(|c| bodies.get(c.body).ok()));
+        if !matches!(body, Some(RigidBody::Static)) {
+            continue;
+        }
+        let (_, rotation, translation) = gt.to_scale_rotation_translation();
+        let iso = Isometry2d::new(
+            translation.truncate(),
+            Rot2::radians(rotation.to_euler(EulerRot::ZYX).0),
+        );
+        let color = if one_way { ONE_WAY_COLOR } else { SOLID_COLOR };
+        draw_shape(&mut gizmos, &**collider.shape_scaled(), iso, color);
+    }
+}
+
+pub struct ColliderDebugPlugin;
+
+impl Plugin for ColliderDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColliderDebug>().add_systems(
+            Update,
+            (
+                toggle_dev_tool::<ColliderDebug>,
+                draw_level_colliders.run_if(dev_tool_on::<ColliderDebug>),
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
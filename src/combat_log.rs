@@ -0,0 +1,190 @@
+// combat_log.rs
+use crate::character::{Player, PlayerStateChanged};
+use crate::dev::{DevToggle, toggle_dev_tool};
+use crate::enemy::{EnemyKilled, EnemyStunned};
+use crate::gameflow::{GameState, PlayerDied};
+use crate::hud::DamagePlayer;
+use crate::prelude::*;
+use crate::raycasts::MeleeRaycastHit;
+use bevy::ui::GlobalZIndex;
+use std::collections::VecDeque;
+
+const COMBAT_LOG_TOGGLE: KeyCode = KeyCode::F4;
+const COMBAT_LOG_LINES: usize = 14;
+const COMBAT_LOG_FONT_SIZE: f32 = 11.0;
+
+/// Recent hits, damage, stuns, deaths and player state changes, shown in a
+/// corner panel. F4 toggles it while the dev tools are on; events are only
+/// recorded while it's open.
+#[derive(Resource, Default)]
+pub struct CombatLog {
+    pub enabled: bool,
+    lines: VecDeque<String>,
+}
+
+impl CombatLog {
+    pub fn push(&mut self, line: String) {
+        if self.lines.len() == COMBAT_LOG_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+#[derive(Component)]
+struct CombatLogPanel;
+
+impl DevToggle for CombatLog {
+    const KEY: KeyCode = COMBAT_LOG_TOGGLE;
+
+    fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, on: bool) {
+        self.enabled = on;
+    }
+}
+
+/// "Player", the entity's `Name`, or its id.
+fn who(e: Entity, players: &Query<(), With<Player>>, names: &Query<&Name>) -> String {
+    if players.contains(e) {
+        "Player".into()
+    } else if let Ok(name) = names.get(e) {
+        format!("{name} {e}")
+    } else {
+        format!("{e}")
+    }
+}
+
+fn record_combat_events(
+    time: Res<Time>,
+    mut log: ResMut<CombatLog>,
+    mut hits: EventReader<MeleeRaycastHit>,
+    mut taken: EventReader<DamagePlayer>,
+    mut kills: EventReader<EnemyKilled>,
+    mut died: EventReader<PlayerDied>,
+    mut states: EventReader<PlayerStateChanged>,
+    stunned: Query<Entity, Added<EnemyStunned>>,
+    players: Query<(), With<Player>>,
+    names: Query<&Name>,
+) {
+    if !log.enabled {
+        hits.clear();
+        taken.clear();
+        kills.clear();
+        died.clear();
+        states.clear();
+        return;
+    }
+    let t = time.elapsed_secs();
+    for hit in hits.read() {
+        let mut line = format!(
+            "{t:7.2} {} hit {} for {}",
+            who(hit.attacker, &players, &names),
+            who(hit.target, &players, &names),
+            hit.damage
+        );
+        if hit.knockback_mult != 1.0 {
+            line.push_str(&format!(" kb x{:.1}", hit.knockback_mult));
+        }
+        if hit.launch > 0.0 {
+            line.push_str(" launch");
+        }
+        log.push(line);
+    }
+    for d in taken.read() {
+        let kind = if d.stagger { "hit" } else { "tick" };
+        log.push(format!("{t:7.2} Player took {:.1} ({kind})", d.amount));
+    }
+    for e in &stunned {
+        log.push(format!("{t:7.2} {} stunned", who(e, &players, &names)));
+    }
+    for k in kills.read() {
+        log.push(format!("{t:7.2} {} killed", who(k.enemy, &players, &names)));
+    }
+    for _ in died.read() {
+        log.push(format!("{t:7.2} Player died"));
+    }
+    for s in states.read() {
+        log.push(format!("{t:7.2} Player state -> {}", s.0));
+    }
+}
+
+fn sync_combat_log_panel(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    log: Res<CombatLog>,
+    panels: Query<Entity, With<CombatLogPanel>>,
+    mut text: Query<&mut Text, With<CombatLogPanel>>,
+) {
+    if !log.enabled {
+        for e in &panels {
+            commands.entity(e).despawn();
+        }
+        return;
+    }
+    let shown = || log.lines.iter().cloned().collect::<Vec<_>>().join("\n");
+    if panels.is_empty() {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(8.0),
+                bottom: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            Text::new(shown()),
+            TextFont {
+                font: assets.load("fonts/GohuFont14NerdFontMono-Regular.ttf"),
+                font_size: COMBAT_LOG_FONT_SIZE,
+                ..default()
+            },
+            TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            CombatLogPanel,
+            GlobalZIndex(2),
+            Name::new("CombatLog"),
+        ));
+        return;
+    }
+    if !log.is_changed() {
+        return;
+    }
+    let shown = shown();
+    for mut t in &mut text {
+        t.0.clone_from(&shown);
+    }
+}
+
+fn close_combat_log(
+    mut commands: Commands,
+    mut log: ResMut<CombatLog>,
+    panels: Query<Entity, With<CombatLogPanel>>,
+) {
+    log.enabled = false;
+    log.lines.clear();
+    for e in &panels {
+        commands.entity(e).despawn();
+    }
+}
+
+pub struct CombatLogPlugin;
+
+impl Plugin for CombatLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CombatLog>()
+            .add_systems(
+                Update,
+                (
+                    toggle_dev_tool::<CombatLog>,
+                    record_combat_events,
+                    sync_combat_log_panel,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), close_combat_log)
+            .add_systems(OnEnter(GameState::GameOver), close_combat_log);
+    }
+}
@@ -0,0 +1,165 @@
+// combo.rs
+use crate::character::Player;
+use crate::gameflow::{GameState, GameplayRoot};
+use crate::prelude::*;
+use crate::raycasts::MeleeRaycastHit;
+use crate::settings::JuiceConfig;
+use bevy::ui::GlobalZIndex;
+
+// A hit within this long of the previous one keeps the chain going
+const COMBO_WINDOW_SECS: f32 = 1.5;
+// Counter only appears once there's something to brag about
+const COMBO_MIN_SHOWN: u32 = 2;
+const COMBO_FONT_BASE: f32 = 22.0;
+const COMBO_FONT_PER_HIT: f32 = 2.0;
+const COMBO_FONT_MAX: f32 = 44.0;
+const COMBO_FLASH_SECS: f32 = 0.15;
+const COMBO_COLOR: Color = Color::srgb(1.0, 0.82, 0.3);
+
+/// Consecutive player melee hits; drops to zero after `COMBO_WINDOW_SECS`
+/// without a hit.
+#[derive(Resource)]
+pub struct ComboCounter {
+    pub count: u32,
+    pub timer: Timer,
+}
+
+impl Default for ComboCounter {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            timer: Timer::from_seconds(COMBO_WINDOW_SECS, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ComboRoot;
+
+/// "xN" label; flashes white for a moment on every new hit.
+#[derive(Component)]
+struct ComboText {
+    flash: Timer,
+}
+
+fn count_combo_hits(
+    time: Res<Time>,
+    mut combo: ResMut<ComboCounter>,
+    mut hits: EventReader<MeleeRaycastHit>,
+    player: Query<(), With<Player>>,
+    mut text: Query<&mut ComboText>,
+) {
+    let landed = hits.read().filter(|h| player.contains(h.attacker)).count() as u32;
+    if landed > 0 {
+        combo.count += landed;
+        combo.timer.reset();
+        for mut t in &mut text {
+            t.flash.reset();
+        }
+        return;
+    }
+    if combo.count > 0 && combo.timer.tick(time.delta()).just_finished() {
+        combo.count = 0;
+    }
+}
+
+fn update_combo_text(
+    time: Res<Time>,
+    combo: Res<ComboCounter>,
+    juice: Res<JuiceConfig>,
+    mut q: Query<(
+        &mut ComboText,
+        &mut Text,
+        &mut TextFont,
+        &mut TextColor,
+        &mut Node,
+    )>,
+) {
+    for (mut label, mut text, mut font, mut color, mut node) in &mut q {
+        let display = if combo.count >= COMBO_MIN_SHOWN {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        if display == Display::None {
+            continue;
+        }
+
+        let shown = format!("x{}", combo.count);
+        if text.0 != shown {
+            text.0 = shown;
+        }
+        font.font_size =
+            (COMBO_FONT_BASE + combo.count as f32 * COMBO_FONT_PER_HIT).min(COMBO_FONT_MAX);
+        label.flash.tick(time.delta());
+        color.0 = if label.flash.finished() || !juice.flashes {
+            COMBO_COLOR
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+fn spawn_combo_ui(mut commands: Commands, assets: Res<AssetServer>) {
+    let font = assets.load("fonts/GohuFont14NerdFontMono-Regular.ttf");
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(30.0),
+                right: Val::Px(32.0),
+                ..default()
+            },
+            ComboRoot,
+            GameplayRoot,
+            GlobalZIndex(1),
+        ))
+        .with_child((
+            Node {
+                display: Display::None,
+                ..default()
+            },
+            Text::new(""),
+            TextFont {
+                font,
+                font_size: COMBO_FONT_BASE,
+                ..default()
+            },
+            TextColor(COMBO_COLOR),
+            ComboText {
+                flash: Timer::from_seconds(COMBO_FLASH_SECS, TimerMode::Once),
+            },
+        ));
+}
+
+fn despawn_combo_ui(mut commands: Commands, q: Query<Entity, With<ComboRoot>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
+
+fn reset_combo(mut combo: ResMut<ComboCounter>) {
+    *combo = ComboCounter::default();
+}
+
+// ───────── Plugin ─────────
+pub struct ComboPlugin;
+
+impl Plugin for ComboPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ComboCounter>()
+            .add_systems(OnEnter(GameState::InGame), spawn_combo_ui)
+            .add_systems(OnExit(GameState::InGame), despawn_combo_ui)
+            .add_systems(OnEnter(GameState::MainMenu), reset_combo)
+            .add_systems(OnEnter(GameState::GameOver), reset_combo)
+            .add_systems(
+                Update,
+                (count_combo_hits, update_combo_text)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,264 @@
+// danger.rs
+use crate::camera::MainCamera;
+use crate::character::Player;
+use crate::gameflow::{GameState, GameplayRoot};
+use crate::hud::DamagePlayer;
+use crate::prelude::*;
+use crate::settings::{JuiceConfig, Palette};
+use std::collections::HashSet;
+
+// A projectile counts as incoming when it points within this cone of the player...
+const DANGER_CONE_COS: f32 = 0.96; // ~16°
+// ...and would arrive within this many seconds
+const DANGER_MAX_ETA_S: f32 = 1.5;
+const DANGER_PULSE_HZ: f32 = 6.0;
+// Edge arrows sit this far inside the window border
+const DANGER_EDGE_MARGIN_PX: f32 = 24.0;
+const DANGER_ARROW_SIZE_PX: f32 = 18.0;
+// Edge bar pointing back at whatever just hit the player
+const DAMAGE_INDICATOR_SECS: f32 = 1.0;
+const DAMAGE_INDICATOR_LEN_PX: f32 = 72.0;
+const DAMAGE_INDICATOR_THICK_PX: f32 = 6.0;
+const DAMAGE_INDICATOR_EDGE_MARGIN_PX: f32 = 6.0;
+
+/// Hostile projectile. Anything carrying this plus a `LinearVelocity` is
+/// tracked by the incoming-danger warnings; ranged attacks should add it.
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Projectile;
+
+/// Projectile currently heading at the player; remembers the sprite color
+/// to restore once it stops being a threat.
+#[derive(Component)]
+struct Incoming {
+    base: Color,
+}
+
+/// Screen-edge marker pointing at an off-screen incoming projectile.
+#[derive(Component)]
+struct DangerArrow(Entity);
+
+/// Fading bar on the screen edge in the direction a hit came from.
+#[derive(Component)]
+struct DamageIndicator(Timer);
+
+fn flag_incoming_projectiles(
+    mut commands: Commands,
+    juice: Res<JuiceConfig>,
+    player: Query<&GlobalTransform, With<Player>>,
+    mut q: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &LinearVelocity,
+            Option<&mut Sprite>,
+            Option<&Incoming>,
+        ),
+        With<Projectile>,
+    >,
+) {
+    let target = player.single().ok().map(|tf| tf.translation().truncate());
+    for (e, tf, vel, sprite, incoming) in &mut q {
+        let threat = juice.projectile_warnings
+            && target.is_some_and(|target| {
+                let to_player = target - tf.translation().truncate();
+                let speed = vel.0.length();
+                speed > 0.0
+                    && vel.0.dot(to_player) >= DANGER_CONE_COS * speed * to_player.length()
+                    && to_player.length() / speed <= DANGER_MAX_ETA_S
+            });
+        match (threat, incoming) {
+            (true, None) => {
+                let base = sprite.map(|s| s.color).unwrap_or(Color::WHITE);
+                commands.entity(e).insert(Incoming { base });
+            }
+            (false, Some(incoming)) => {
+                if let Some(mut sprite) = sprite {
+                    sprite.color = incoming.base;
+                }
+                commands.entity(e).remove::<Incoming>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Pulse on-screen incoming projectiles toward the danger color.
+fn pulse_incoming_projectiles(
+    time: Res<Time>,
+    palette: Res<Palette>,
+    mut q: Query<(&Incoming, &mut Sprite)>,
+) {
+    let t = 0.5 + 0.5 * (time.elapsed_secs() * DANGER_PULSE_HZ * std::f32::consts::TAU).sin();
+    for (incoming, mut sprite) in &mut q {
+        sprite.color = incoming.base.mix(&palette.danger.with_alpha(1.0), t);
+    }
+}
+
+/// One arrow per off-screen threat, clamped to the window edge.
+fn sync_danger_arrows(
+    mut commands: Commands,
+    palette: Res<Palette>,
+    cam: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    incoming: Query<(Entity, &GlobalTransform), With<Incoming>>,
+    mut arrows: Query<(Entity, &DangerArrow, &mut Node)>,
+) {
+    let Ok((camera, cam_tf)) = cam.single() else {
+        return;
+    };
+    let Some(size) = camera.logical_viewport_size() else {
+        return;
+    };
+    let center = size / 2.0;
+    let half = center - Vec2::splat(DANGER_EDGE_MARGIN_PX);
+
+    // Viewport position of every off-screen threat
+    let mut off_screen = Vec::new();
+    for (e, tf) in &incoming {
+        let Ok(p) = camera.world_to_viewport(cam_tf, tf.translation()) else {
+            continue;
+        };
+        if p.cmplt(Vec2::ZERO).any() || p.cmpgt(size).any() {
+            off_screen.push((e, p));
+        }
+    }
+
+    let mut shown = HashSet::new();
+    for (arrow, DangerArrow(target), mut node) in &mut arrows {
+        let Some((_, p)) = off_screen.iter().find(|(e, _)| e == target) else {
+            commands.entity(arrow).despawn();
+            continue;
+        };
+        place_arrow(*p, center, half, &mut node);
+        shown.insert(*target);
+    }
+    for (target, p) in off_screen {
+        if shown.contains(&target) {
+            continue;
+        }
+        let mut node = Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(DANGER_ARROW_SIZE_PX),
+            height: Val::Px(DANGER_ARROW_SIZE_PX),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        };
+        place_arrow(p, center, half, &mut node);
+        commands
+            .spawn((
+                node,
+                BackgroundColor(palette.danger),
+                DangerArrow(target),
+                GameplayRoot,
+                Name::new("DangerArrow"),
+            ))
+            .with_child((
+                Text::new("!"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+    }
+}
+
+/// Put the marker where the center→projectile line leaves the inset window.
+fn place_arrow(p: Vec2, center: Vec2, half: Vec2, node: &mut Node) {
+    let d = p - center;
+    let scale = (half.x / d.x.abs().max(f32::EPSILON)).min(half.y / d.y.abs().max(f32::EPSILON));
+    let at = center + d * scale.min(1.0);
+    node.left = Val::Px(at.x - DANGER_ARROW_SIZE_PX / 2.0);
+    node.top = Val::Px(at.y - DANGER_ARROW_SIZE_PX / 2.0);
+}
+
+/// A bar on the window edge where the line from the screen center toward the
+/// hit's `source_pos` leaves it; vertical on the side edges, horizontal on
+/// the top and bottom.
+fn spawn_damage_indicators(
+    mut commands: Commands,
+    palette: Res<Palette>,
+    mut hits: EventReader<DamagePlayer>,
+    cam: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+) {
+    let Ok((camera, cam_tf)) = cam.single() else {
+        hits.clear();
+        return;
+    };
+    let Some(size) = camera.logical_viewport_size() else {
+        hits.clear();
+        return;
+    };
+    let center = size / 2.0;
+    let half = center - Vec2::splat(DAMAGE_INDICATOR_EDGE_MARGIN_PX);
+    for source in hits.read().filter_map(|hit| hit.source_pos) {
+        let Ok(p) = camera.world_to_viewport(cam_tf, source.extend(0.0)) else {
+            continue;
+        };
+        let d = p - center;
+        if d.length_squared() < 1.0 {
+            continue;
+        }
+        let to_side = half.x / d.x.abs().max(f32::EPSILON);
+        let to_top = half.y / d.y.abs().max(f32::EPSILON);
+        let at = center + d * to_side.min(to_top);
+        let extent = if to_side < to_top {
+            Vec2::new(DAMAGE_INDICATOR_THICK_PX, DAMAGE_INDICATOR_LEN_PX)
+        } else {
+            Vec2::new(DAMAGE_INDICATOR_LEN_PX, DAMAGE_INDICATOR_THICK_PX)
+        };
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(at.x - extent.x / 2.0),
+                top: Val::Px(at.y - extent.y / 2.0),
+                width: Val::Px(extent.x),
+                height: Val::Px(extent.y),
+                ..default()
+            },
+            BackgroundColor(palette.danger),
+            DamageIndicator(Timer::from_seconds(DAMAGE_INDICATOR_SECS, TimerMode::Once)),
+            GameplayRoot,
+            Name::new("DamageIndicator"),
+        ));
+    }
+}
+
+fn fade_damage_indicators(
+    mut commands: Commands,
+    time: Res<Time>,
+    palette: Res<Palette>,
+    mut q: Query<(Entity, &mut DamageIndicator, &mut BackgroundColor)>,
+) {
+    for (e, mut indicator, mut bg) in &mut q {
+        indicator.0.tick(time.delta());
+        if indicator.0.finished() {
+            commands.entity(e).despawn();
+            continue;
+        }
+        bg.0 = palette
+            .danger
+            .with_alpha(palette.danger.alpha() * indicator.0.fraction_remaining());
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct DangerPlugin;
+
+impl Plugin for DangerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Projectile>().add_systems(
+            Update,
+            (
+                flag_incoming_projectiles,
+                pulse_incoming_projectiles,
+                sync_danger_arrows,
+                spawn_damage_indicators,
+                fade_damage_indicators,
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// dev.rs
+use crate::prelude::*;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+const INSPECTOR_TOGGLE: KeyCode = KeyCode::F10;
+
+/// Master switch for debug-only tools (free camera, overlays, ...).
+/// On by default in debug builds (the only ones with the inspector).
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct DevTools {
+    pub enabled: bool,
+    /// World inspector window, toggled with F10. Only debug builds include
+    /// the inspector at all.
+    pub inspector: bool,
+}
+
+impl Default for DevTools {
+    fn default() -> Self {
+        Self {
+            enabled: cfg!(debug_assertions),
+            inspector: false,
+        }
+    }
+}
+
+/// Run condition for dev-only systems.
+pub fn dev_enabled(dev: Res<DevTools>) -> bool {
+    dev.enabled
+}
+
+/// Dev-only tool or overlay with an on/off switch, flipped by `KEY`.
+pub trait DevToggle: Resource {
+    const KEY: KeyCode;
+    fn enabled(&self) -> bool;
+    fn set_enabled(&mut self, on: bool);
+}
+
+/// `T::KEY` flips `T` while the dev tools are on; turning the dev tools off
+/// also turns `T` off. Only writes on a real change, so `resource_changed`
+/// sees just the flips.
+pub fn toggle_dev_tool<T: DevToggle>(
+    keys: Res<ButtonInput<KeyCode>>,
+    dev: Res<DevTools>,
+    mut tool: ResMut<T>,
+) {
+    let on = tool.enabled();
+    let want = if dev.enabled && keys.just_pressed(T::KEY) {
+        !on
+    } else {
+        on && dev.enabled
+    };
+    if want != on {
+        tool.set_enabled(want);
+    }
+}
+
+/// Run condition: `T` is switched on.
+pub fn dev_tool_on<T: DevToggle>(tool: Res<T>) -> bool {
+    tool.enabled()
+}
+
+fn inspector_visible(dev: Res<DevTools>) -> bool {
+    dev.enabled && dev.inspector
+}
+
+fn toggle_inspector(keys: Res<ButtonInput<KeyCode>>, mut dev: ResMut<DevTools>) {
+    if keys.just_pressed(INSPECTOR_TOGGLE) {
+        dev.inspector = !dev.inspector;
+    }
+}
+
+pub struct DevPlugin;
+
+impl Plugin for DevPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevTools>()
+            .register_type::<DevTools>()
+            .add_systems(Update, toggle_inspector.run_if(dev_enabled));
+        // Release builds ship without the inspector
+        if cfg!(debug_assertions) {
+            app.add_plugins(WorldInspectorPlugin::new().run_if(inspector_visible));
+        }
+    }
+}
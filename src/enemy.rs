@@ -1,67 +1,87 @@
 // enemy.rs
-use crate::animations::{DEFAULT_FRAME_MS, to_enemy_anim_name};
-use crate::character::{GameLayer, Player};
+use crate::animations::{
+    AnimClipRow, AnimHeld, AnimHold, AnimMap, AnimState, hold_anim_state, select_anim_state,
+};
+use crate::character::{
+    Dashing, FallingAttack, GameLayer, GrabBroken, Grabbed, Idle, IdleAttack, Interpolated,
+    JumpingAttack, Player, Running, RunningAttack, SprintJumping, WalkingAttack,
+};
 use crate::enemy_class::{EnemyClass, EnemyClassAttachTarget};
+use crate::enemy_spawner::{EntityBudget, ground_point_below};
+use crate::fx::FadeIn;
 use crate::gameflow::GameplayRoot;
-use crate::raycasts::{MeleeAttackActive, MeleeRaycastHit, MeleeRaycastSpec};
+use crate::hud::DamagePlayer;
+use crate::level::{OneWayPlatform, PassThroughOneWayPlatform};
+use crate::mimic::Mimic;
+use crate::pool::{EffectSprite, Pool, play_effect};
+use crate::progression::NewGamePlus;
+use crate::raycasts::{
+    MeleeAttackActive, MeleeRaycastHit, MeleeRaycastSpec, RayBudgeted, RaycastMeleeSet,
+    SwingModifiers,
+};
+use crate::rng::GameRng;
+use crate::settings::{DifficultyConfig, JuiceConfig, Palette};
+use crate::world_scale::WorldScale;
 use avian2d::collision::collider::{CollisionLayers, LayerMask};
 use avian2d::prelude::*;
 use avian2d::spatial_query::SpatialQueryFilter;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
+use bevy_inspector_egui::InspectorOptions;
+use bevy_inspector_egui::prelude::ReflectInspectorOptions;
 use bevy_spritesheet_animation::prelude::*;
 use big_brain::prelude::*;
+use rand::Rng;
 use serde::Deserialize;
-use std::collections::HashMap;
-
-// ──────────────────────────────────────────────────────────────────────────────
-// Tiny JSON helper (same idea as in character.rs)
-// ──────────────────────────────────────────────────────────────────────────────
-#[derive(Deserialize)]
-struct MiniAnim {
-    name: String,
-    last_col: usize,
-}
-#[derive(Deserialize)]
-struct MiniManifest {
-    animations: Vec<MiniAnim>,
-}
-
-fn load_anim_seconds_from_json(json_path: &str) -> HashMap<String, f32> {
-    let mut map = HashMap::new();
-    if let Ok(text) = std::fs::read_to_string(json_path) {
-        if let Ok(manifest) = serde_json::from_str::<MiniManifest>(&text) {
-            for a in manifest.animations {
-                let pretty = to_enemy_anim_name(&a.name);
-                let frames = a.last_col as u32;
-                let secs = (frames * DEFAULT_FRAME_MS) as f32 / 1000.0;
-                map.insert(pretty, secs);
-            }
-        }
-    }
-    map
-}
+use std::collections::{HashMap, HashSet};
 
 // ====== Animation bits ======
 #[derive(Component, Clone, Copy)]
+#[require(AnimHeld)]
 struct EnemyCurrentAnim(AnimationId);
 
-#[derive(Component, Clone, Copy)]
-pub struct EnemyAnimClips {
-    pub idle: AnimationId,
-    pub walk: Option<AnimationId>,
-    pub run: Option<AnimationId>,
-    pub jump: Option<AnimationId>,
-    pub fall: Option<AnimationId>,
-    pub attack_idle: AnimationId,
-    pub attack_walk: Option<AnimationId>,
-    pub attack_run: Option<AnimationId>,
-    pub attack_jump: Option<AnimationId>,
-    pub attack_fall: Option<AnimationId>,
-    // NEW:
-    pub stunned: Option<AnimationId>,
-    pub die: Option<AnimationId>,
-}
+/// Enemy clips per state; each row lists fallbacks after the preferred clip.
+const ENEMY_ANIM_CLIPS: &[AnimClipRow] = &[
+    (AnimState::Idle, &["enemy:idle"]),
+    (AnimState::Walking, &["enemy:walk"]),
+    (AnimState::Running, &["enemy:run", "enemy:walk"]),
+    (AnimState::Jumping, &["enemy:jumpmid", "enemy:jumpfall"]),
+    (AnimState::Falling, &["enemy:jumpfall"]),
+    (AnimState::IdleAttack, &["enemy:attack"]),
+    (AnimState::WalkingAttack, &["enemy:attack"]),
+    (
+        AnimState::RunningAttack,
+        &["enemy:blastattack", "enemy:attack"],
+    ),
+    (
+        AnimState::JumpingAttack,
+        &["enemy:jumpmid", "enemy:jumpfall", "enemy:attack"],
+    ),
+    (
+        AnimState::FallingAttack,
+        &["enemy:jumpfall", "enemy:jumpmid", "enemy:attack"],
+    ),
+    (AnimState::Stunned, &["enemy:shocka"]),
+    (AnimState::Hurt, &["enemy:hurt", "enemy:flinch"]),
+    (AnimState::Dead, &["enemy:die"]),
+];
+
+/// Clip selection order for enemies; first active state wins.
+const ENEMY_ANIM_PRIORITY: &[AnimState] = &[
+    AnimState::Dead,
+    AnimState::Stunned,
+    AnimState::FallingAttack,
+    AnimState::JumpingAttack,
+    AnimState::RunningAttack,
+    AnimState::WalkingAttack,
+    AnimState::IdleAttack,
+    AnimState::Hurt,
+    AnimState::Jumping,
+    AnimState::Falling,
+    AnimState::Running,
+    AnimState::Walking,
+    AnimState::Idle,
+];
 
 // OPTIONAL: Attack clip lengths so our swing timer matches the clip that plays
 #[derive(Component, Clone, Copy)]
@@ -86,7 +106,17 @@ pub struct PatrolBounds {
 #[derive(Component, Deref, DerefMut)]
 pub struct PatrolDir(pub f32);
 
-#[derive(Component, Default, Debug, Clone, Copy)]
+/// Where the enemy was placed; it walks back here after losing the player
+/// outside its patrol bounds.
+#[derive(Component, Clone, Copy, Debug, Deref)]
+pub struct HomePos(pub Vec2);
+
+/// Short stop while patrolling (at a bound or at random) before walking on.
+#[derive(Component)]
+pub struct PatrolPause(Timer);
+
+#[derive(Component, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemySenses {
     pub target: Option<Entity>,
     pub target_pos: Vec2,
@@ -96,64 +126,522 @@ pub struct EnemySenses {
 
 #[derive(Component)]
 struct EnemyAttackTimer(Timer);
+/// Approach speed at swing start (picks the attack clip) and the horizontal
+/// velocity the swing keeps, easing to zero as the timer runs out.
+#[derive(Component, Clone, Copy)]
+struct SwingCarry {
+    pre_speed: f32,
+    vx: f32,
+}
 #[derive(Component)]
 struct EnemyAttackCooldown(Timer);
 
 // ====== Health / Impacts ======
-#[derive(Component, Debug, Clone, Copy)]
+/// Live enemy health. Editable in the world inspector: lowering `health`
+/// plays the same stun/death reaction as a hit.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyStats {
     pub health: f32,
-    pub _max_health: f32,
+    pub max_health: f32,
 }
 
 impl EnemyStats {
     pub fn new(max: f32) -> Self {
         Self {
             health: max,
-            _max_health: max,
+            max_health: max,
         }
     }
 }
 
 #[derive(Component, Default, Debug, Clone, Copy)]
-struct EnemyLastHitDir(Vec2);
+struct EnemyLastHitDir {
+    dir: Vec2,
+    knockback_mult: f32,
+}
+
+/// Popped into the air by a launcher. Brain actions are suppressed until the
+/// timer runs out and the enemy is back on the ground; any hit while juggled
+/// pops it up again.
+#[derive(Component)]
+struct Juggled(Timer);
 
 #[derive(Component, Default)]
-struct EnemyStunned;
+pub struct EnemyStunned;
+
+// Poise damage shed per second between hits
+const POISE_DECAY_PER_S: f32 = 12.0;
+
+/// Stagger meter: damage taken piles up here (draining over time) and the
+/// enemy is only stunned once it reaches `threshold`. Classes without a
+/// `poise` stat get no meter and flinch on every hit.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Poise {
+    pub threshold: f32,
+    pub damage: f32,
+}
+
+/// Hits never change this enemy's velocity: no knockback, no launch.
+/// Set from `knockback_immune` in the class JSON.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct KnockbackImmune;
 
 #[derive(Component, Default)]
-struct EnemyDead;
+pub struct EnemyDead;
+
+/// An enemy just died (training dummies excluded). Drives XP and any
+/// kill-based scoring.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct EnemyKilled {
+    pub enemy: Entity,
+    pub pos: Vec2,
+}
 
 #[derive(Component)]
 struct EnemyStunTimer(Timer);
 
+/// Horizontal speed of a stunned enemy last frame, so a sudden stop against
+/// static geometry reads as a wall splat (at most one per stun).
+#[derive(Component, Default)]
+struct KnockbackTrack {
+    last_vx: f32,
+    splatted: bool,
+}
+
 #[derive(Component)]
 struct EnemyDeathTimer(Timer);
+/// Finished dying and lingering per `CorpseConfig`; `age` counts from then.
+#[derive(Component, Default)]
+pub struct Corpse {
+    age: f32,
+}
 
 #[derive(Component, Clone, Copy)]
 struct EnemyImpactDurations {
     stun: f32,
     die: f32,
+    hurt: f32,
+}
+
+// Flinch length when the sheet has no hurt clip to take it from
+const FLINCH_SECS: f32 = 0.25;
+
+/// Took a hit that didn't stun (poise held); plays the hurt clip, if the
+/// sheet has one, until the timer runs out. Without one the hit flash is
+/// the only feedback.
+#[derive(Component)]
+struct Flinch(Timer);
+
+/// Blows up when the death timer runs out, hurting the player within `radius`.
+/// Enabled per class via `explodes_on_death` in the enemy class JSON.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct ExplodesOnDeath {
+    pub radius: f32,
+    pub damage: f32,
+}
+
+/// Hurts the player on touch, at most once per `cooldown` seconds per enemy.
+/// Enabled per class via `contact_damage` in the enemy class JSON.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct ContactDamage {
+    pub damage: f32,
+    pub cooldown: f32,
+}
+
+#[derive(Component)]
+struct ContactDamageCooldown(Timer);
+
+/// Closes medium distances with a telegraphed burst of speed instead of
+/// running the whole way. Enabled per class via `dash_attack` in the enemy
+/// class JSON.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct DashAttack {
+    pub speed: f32,
+    /// Dashes when the target is beyond the attack band but within this.
+    pub range: f32,
+    pub cooldown: f32,
+}
+
+#[derive(Component)]
+struct DashCooldown(Timer);
+
+/// Grappler: a swing that connects within `range` grabs the player instead
+/// of staggering them, dealing `dps` for up to `hold` seconds unless they
+/// mash free (`mashes` presses of Attack or Jump). Enabled per class via
+/// `grab_attack` in the enemy class JSON.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct GrabAttack {
+    pub range: f32,
+    pub hold: f32,
+    pub dps: f32,
+    pub mashes: u32,
+}
+
+impl GrabAttack {
+    /// Whether a hit landed at `distance` is close enough to grab.
+    pub fn reaches(&self, distance: f32) -> bool {
+        distance <= self.range
+    }
+}
+
+/// One attack an `EnemyAttackSet` can pick. Multipliers scale the normal
+/// swing (class damage, `EnemyTuning::range`, clip length), so NG+ scaling
+/// and live tuning still apply.
+#[derive(Debug, Clone, Deserialize, Reflect)]
+#[serde(default)]
+pub struct EnemyAttackDef {
+    pub name: String,
+    pub damage_mult: f32,
+    pub reach_mult: f32,
+    /// Swing timer scale; > 1.0 is a slower, heavier attack.
+    pub windup_mult: f32,
+    /// Only picked while the target is this far away (horizontally).
+    pub min_range: f32,
+    pub max_range: f32,
+    pub weight: f32,
+}
+
+impl Default for EnemyAttackDef {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            damage_mult: 1.0,
+            reach_mult: 1.0,
+            windup_mult: 1.0,
+            min_range: 0.0,
+            max_range: f32::MAX,
+            weight: 1.0,
+        }
+    }
+}
+
+/// Distinct attacks to choose from at swing start: a weighted roll among
+/// those whose range band holds the target. Enabled per class via
+/// `attack_set` in the enemy class JSON.
+#[derive(Component, Debug, Clone, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct EnemyAttackSet {
+    pub attacks: Vec<EnemyAttackDef>,
+}
+
+impl EnemyAttackSet {
+    fn pick(&self, dx: f32, rng: &mut impl Rng) -> Option<&EnemyAttackDef> {
+        let fits =
+            |a: &&EnemyAttackDef| a.weight > 0.0 && (a.min_range..=a.max_range).contains(&dx);
+        let total: f32 = self.attacks.iter().filter(fits).map(|a| a.weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0.0..total);
+        let mut last = None;
+        for a in self.attacks.iter().filter(fits) {
+            if roll < a.weight {
+                return Some(a);
+            }
+            roll -= a.weight;
+            last = Some(a);
+        }
+        // Float rounding at the top end
+        last
+    }
+}
+
+/// Dash in progress: wind-up (`telegraph` running, "!" shown), then the burst.
+#[derive(Component)]
+struct EnemyDashing {
+    telegraph: Timer,
+    burst: Timer,
+    dir: f32,
+    marker: Entity,
+}
+
+/// Calls reinforcements when it first spots the player, at most once per
+/// `cooldown` seconds. Enabled per class via `summons_help` in the enemy
+/// class JSON.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct SummonsHelp {
+    pub count: u32,
+    pub cooldown: f32,
+}
+
+#[derive(Component)]
+struct SummonCooldown(Timer);
+
+/// Summon wind-up: holds still under a "!!" until `telegraph` runs out.
+#[derive(Component)]
+struct EnemySummoning {
+    telegraph: Timer,
+    marker: Entity,
+}
+
+/// Ring shown around a dying bomber, growing to the blast radius.
+#[derive(Component)]
+struct ExplosionTelegraph {
+    radius: f32,
+}
+
+/// Just spawned: fades in, can't score targets (so never attacks) and
+/// ignores incoming hits until the timer runs out.
+#[derive(Component)]
+pub struct SpawnGrace(pub Timer);
+
+/// Chasing down through a one-way platform; pass-through reverts when it ends.
+#[derive(Component)]
+struct EnemyDropThrough(Timer);
+
+/// Practice target: never thinks or attacks, tops its health back up once a
+/// stun ends and walks back to `home` after being knocked away.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct TrainingDummy {
+    pub home: Vec2,
+}
+
+/// Brief tint after taking a hit; `base` is the color restored afterwards.
+#[derive(Component)]
+struct HitFlash {
+    timer: Timer,
+    base: Color,
 }
 
 // ====== Tuning ======
-const WALK: f32 = 50.0;
-const RUN: f32 = 200.0;
 const ACCEL: f32 = 3000.0;
-const AGGRO: f32 = 260.0;
-const RANGE: f32 = 46.0;
 // These remain fallback defaults; we’ll override from JSON when available.
 const SWING_DEFAULT: f32 = 0.35;
-const COOLDOWN: f32 = 0.60;
+
+/// Live-editable enemy feel (inspector), read by perception, scorers and actions.
+#[derive(Resource, Clone, Debug, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct EnemyTuning {
+    #[inspector(min = 0.0, max = 400.0, speed = 1.0)]
+    pub walk: f32,
+    #[inspector(min = 0.0, max = 600.0, speed = 1.0)]
+    pub run: f32,
+    /// Player within this distance (and in sight) becomes the target.
+    #[inspector(min = 0.0, max = 1000.0, speed = 1.0)]
+    pub aggro: f32,
+    /// Melee ray length; chase stops just inside it.
+    #[inspector(min = 0.0, max = 200.0, speed = 0.5)]
+    pub range: f32,
+    /// Horizontal distance at which the attack scorer fires.
+    #[inspector(min = 0.0, max = 300.0, speed = 0.5)]
+    pub attack_band_x: f32,
+    #[inspector(min = 0.0, max = 5.0, speed = 0.01)]
+    pub cooldown: f32,
+    /// Walk speed back to `HomePos` after losing the target.
+    #[inspector(min = 0.0, max = 400.0, speed = 1.0)]
+    pub return_speed: f32,
+    /// Share of approach speed a walking swing carries forward (0 = plant).
+    #[inspector(min = 0.0, max = 1.0, speed = 0.01)]
+    pub walk_attack_momentum: f32,
+    /// Same for running swings.
+    #[inspector(min = 0.0, max = 1.0, speed = 0.01)]
+    pub run_attack_momentum: f32,
+    /// Steepest ground (degrees from flat) an enemy walks up; steeper
+    /// contacts count as walls it stops or turns at.
+    #[inspector(min = 0.0, max = 90.0, speed = 0.5)]
+    pub max_slope_deg: f32,
+    /// Draw the aggro circle and attack band around each enemy.
+    pub show_bands: bool,
+}
+
+impl Default for EnemyTuning {
+    fn default() -> Self {
+        Self {
+            walk: 50.0,
+            run: 200.0,
+            aggro: 260.0,
+            range: 46.0,
+            attack_band_x: 46.0 + 24.0,
+            cooldown: 0.60,
+            return_speed: 50.0,
+            walk_attack_momentum: 0.3,
+            run_attack_momentum: 0.6,
+            max_slope_deg: 50.0,
+            show_bands: false,
+        }
+    }
+}
+
+/// Boids-style spacing so a chasing crowd spreads out instead of stacking.
+/// Each enemy gets a horizontal push away from others within `radius`,
+/// capped at `max_push` so it never overrides chase/attack movement.
+#[derive(Resource, Clone, Debug, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct EnemySeparation {
+    #[inspector(min = 0.0, max = 100.0, speed = 0.5)]
+    pub radius: f32,
+    #[inspector(min = 0.0, max = 200.0, speed = 1.0)]
+    pub max_push: f32,
+}
+
+impl Default for EnemySeparation {
+    fn default() -> Self {
+        Self {
+            radius: 20.0,
+            max_push: 30.0,
+        }
+    }
+}
+
+/// Dead enemies stay on the ground `linger` seconds after the death clip
+/// (0 despawns right away); past `max_corpses` the oldest go first.
+/// Bombers never leave one.
+#[derive(Resource, Clone, Debug, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct CorpseConfig {
+    #[inspector(min = 0.0, max = 120.0, speed = 0.5)]
+    pub linger: f32,
+    #[inspector(min = 0, max = 200)]
+    pub max_corpses: usize,
+}
+
+impl Default for CorpseConfig {
+    fn default() -> Self {
+        Self {
+            linger: 0.0,
+            max_corpses: 16,
+        }
+    }
+}
+
+/// How loud each kind of player movement is, as a multiplier on
+/// `EnemyTuning::aggro`: standing still or walking is quieter than the base
+/// radius, sprinting and attacking carry further.
+#[derive(Resource, Clone, Debug, Reflect, InspectorOptions)]
+#[reflect(Resource, InspectorOptions)]
+pub struct NoiseLevels {
+    #[inspector(min = 0.0, max = 3.0, speed = 0.01)]
+    pub still: f32,
+    #[inspector(min = 0.0, max = 3.0, speed = 0.01)]
+    pub walk: f32,
+    #[inspector(min = 0.0, max = 3.0, speed = 0.01)]
+    pub sprint: f32,
+    #[inspector(min = 0.0, max = 3.0, speed = 0.01)]
+    pub attack: f32,
+    /// How fast a loud moment fades back down (per second).
+    #[inspector(min = 0.0, max = 10.0, speed = 0.05)]
+    pub falloff: f32,
+}
+
+impl Default for NoiseLevels {
+    fn default() -> Self {
+        Self {
+            still: 0.5,
+            walk: 0.75,
+            sprint: 1.25,
+            attack: 1.5,
+            falloff: 1.0,
+        }
+    }
+}
+
+/// The player's current loudness (see `NoiseLevels`); `sense_player` scales
+/// the aggro radius by it.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PlayerNoise(pub f32);
+
+impl Default for PlayerNoise {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Crowd throttle for the sight ray in `sense_player`: with more than
+/// `crowd_threshold` enemies, one without a target looks for the player on
+/// one frame out of every `slices`, staggered by entity. Distance, offset and
+/// dropping an out-of-range target still run every frame for everyone.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PerceptionSchedule {
+    pub crowd_threshold: usize,
+    pub slices: u32,
+}
+
+impl Default for PerceptionSchedule {
+    fn default() -> Self {
+        Self {
+            crowd_threshold: 24,
+            slices: 4,
+        }
+    }
+}
+
+const PATROL_PAUSE_MIN: f32 = 0.6;
+const PATROL_PAUSE_MAX: f32 = 1.8;
+const PATROL_RANDOM_PAUSES_PER_S: f64 = 0.08;
 
 const ENEMY_KNOCKBACK_SPEED: f32 = 260.0;
 const ENEMY_KNOCKBACK_POP: f32 = 300.0;
+// Hits whose direction points less upward than this stay horizontal
+const ENEMY_KNOCKBACK_LIFT_DEADZONE: f32 = 0.3;
+
+const HIT_FLASH_SECS: f32 = 0.12;
+
+// Knocked into a wall at this horizontal speed or faster...
+const WALL_SPLAT_MIN_SPEED: f32 = 180.0;
+// ...and losing at least this share of it in one frame: splat
+const WALL_SPLAT_SPEED_LOSS: f32 = 0.5;
+// Contact normals need at least this much sideways component to count as a wall
+const WALL_SPLAT_MIN_NORMAL_X: f32 = 0.7;
+const WALL_SPLAT_DAMAGE: f32 = 8.0;
+const WALL_SPLAT_EXTRA_STUN: f32 = 0.4;
+const WALL_SPLAT_DUST_COLOR: Color = Color::srgba(0.75, 0.7, 0.6, 0.8);
+
+// Launched enemies stay juggled at least this long
+const JUGGLE_MIN_SECS: f32 = 0.5;
+// Upward speed any hit gives an already-juggled enemy
+const JUGGLE_REPOP: f32 = 220.0;
+
+const DASH_TELEGRAPH_SECS: f32 = 0.3;
+const DASH_TELEGRAPH_Y: f32 = 34.0; // "!" above the enemy's center
+// Ledge probe: ray straight down this far ahead of the dashing enemy
+const DASH_LEDGE_PROBE_AHEAD: f32 = 20.0;
+const DASH_LEDGE_PROBE_DEPTH: f32 = 48.0;
+
+// Returning starts this far outside the patrol bounds (ends back inside)
+const RETURN_SLACK: f32 = 16.0;
+// Ledge probe while walking home
+const RETURN_LEDGE_PROBE_AHEAD: f32 = 14.0;
+const RETURN_LEDGE_PROBE_DEPTH: f32 = 48.0;
+
+// Summon call: wind-up, then helpers land this far to either side
+const SUMMON_TELEGRAPH_SECS: f32 = 0.8;
+const SUMMON_SPREAD_MIN: f32 = 40.0;
+const SUMMON_SPREAD_MAX: f32 = 120.0;
+// Ground probe for helper placement starts this far above the caller
+const SUMMON_PROBE_HEIGHT: f32 = 80.0;
+const SUMMON_PROBE_DEPTH: f32 = 400.0;
+const SUMMON_PATROL_SPAN: f32 = 100.0;
+
+const SPAWN_GRACE_SECS: f32 = 0.8;
+
+// Chase drops through a one-way floor when the target is this far below
+const ENEMY_DROP_MIN_DY: f32 = 24.0;
+const ENEMY_DROP_SECS: f32 = 0.3;
+
+const DUMMY_HEALTH: f32 = 100_000.0;
+// Return speed per unit of distance from home (capped at `EnemyTuning::walk`)
+const DUMMY_RETURN_GAIN: f32 = 4.0;
+// Close enough to home to stop nudging
+const DUMMY_HOME_EPSILON: f32 = 1.0;
+
+const EXPLOSION_COLOR: Color = Color::srgba(1.0, 0.55, 0.15, 0.85);
 
 // ====== Bundle ======
 #[derive(Bundle)]
 pub struct EnemyBundle {
     enemy: Enemy,
     patrol: PatrolBounds,
+    home: HomePos,
     dir: PatrolDir,
     senses: EnemySenses,
     gameflow: GameplayRoot,
@@ -167,11 +655,14 @@ pub struct EnemyBundle {
     collider: Collider,
     speculative: SpeculativeMargin,
     collisions: CollidingEntities,
+    one_way: PassThroughOneWayPlatform,
     transform: Transform,
     global_transform: GlobalTransform,
+    interpolated: Interpolated,
     vel: LinearVelocity,
     layers: CollisionLayers,
     ray: MeleeRaycastSpec,
+    ray_budget: RayBudgeted,
     stats: EnemyStats,
     impacts: EnemyImpactDurations,
     class_target: EnemyClassAttachTarget,
@@ -179,13 +670,14 @@ pub struct EnemyBundle {
     name: Name,
 }
 
-pub fn spawn_enemy(cmd: &mut Commands, pos: Vec2, left: f32, right: f32) -> Entity {
+fn enemy_bundle(pos: Vec2, left: f32, right: f32) -> EnemyBundle {
     let player_mask = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Player));
 
-    cmd.spawn(EnemyBundle {
+    EnemyBundle {
         enemy: Enemy,
         gameflow: GameplayRoot,
         patrol: PatrolBounds { left, right },
+        home: HomePos(pos),
         dir: PatrolDir(1.0),
         senses: EnemySenses::default(),
 
@@ -194,11 +686,14 @@ pub fn spawn_enemy(cmd: &mut Commands, pos: Vec2, left: f32, right: f32) -> Enti
         restitution: Restitution::ZERO.with_combine_rule(CoefficientCombine::Min),
         friction: Friction::ZERO.with_combine_rule(CoefficientCombine::Min),
         damping: LinearDamping(2.0),
-        collider: Collider::capsule(8.0, 26.0),
+        // Resized to the live `WorldScale` when the sprite attaches
+        collider: WorldScale::default().body_collider(),
         speculative: SpeculativeMargin(0.1),
         collisions: CollidingEntities::default(),
+        one_way: PassThroughOneWayPlatform::ByNormal,
         transform: Transform::from_xyz(pos.x, pos.y, -1.0),
         global_transform: GlobalTransform::default(),
+        interpolated: Interpolated,
         vel: LinearVelocity::default(),
         layers: CollisionLayers::new(
             LayerMask::from(GameLayer::Enemy),
@@ -206,31 +701,63 @@ pub fn spawn_enemy(cmd: &mut Commands, pos: Vec2, left: f32, right: f32) -> Enti
         ),
         ray: MeleeRaycastSpec {
             offset: Vec2::new(16.0, 8.0),
-            length: RANGE,
+            length: EnemyTuning::default().range,
             max_hits: 1,
             damage: 20,
             filter: player_mask,
             solid: false,
             once_per_swing: true,
         },
+        ray_budget: RayBudgeted,
         // Defaults; will be overwritten by JSON if available
         stats: EnemyStats::new(40.0),
         class_target: EnemyClassAttachTarget,
         impacts: EnemyImpactDurations {
             stun: 0.6,
             die: 1.2,
+            hurt: FLINCH_SECS,
         },
 
         name: Name::new("Enemy"),
-    })
-    .insert(
-        Thinker::build()
-            .picker(FirstToScore::new(0.5))
-            .when(AttackInRange, Attack)
-            .when(HasTarget, Chase)
-            .otherwise(Patrol),
-    )
-    .id()
+    }
+}
+
+pub fn spawn_enemy(cmd: &mut Commands, pos: Vec2, left: f32, right: f32) -> Entity {
+    cmd.spawn(enemy_bundle(pos, left, right))
+        .insert((
+            SpawnGrace(Timer::from_seconds(SPAWN_GRACE_SECS, TimerMode::Once)),
+            FadeIn::new(SPAWN_GRACE_SECS),
+        ))
+        .insert(
+            Thinker::build()
+                .picker(FirstToScore::new(0.5))
+                .when(AttackInRange, Attack)
+                .when(DashInRange, Dash)
+                .when(HasTarget, Chase)
+                .when(AwayFromHome, Returning)
+                .otherwise(Patrol),
+        )
+        .id()
+}
+
+/// Same body as `spawn_enemy` but without a `Thinker`, so it never senses,
+/// chases or swings; it only reacts to hits.
+pub fn spawn_training_dummy(cmd: &mut Commands, pos: Vec2) -> Entity {
+    cmd.spawn(enemy_bundle(pos, pos.x, pos.x))
+        .insert((
+            TrainingDummy { home: pos },
+            EnemyStats::new(DUMMY_HEALTH),
+            Name::new("TrainingDummy"),
+        ))
+        .id()
+}
+
+/// Same body as `spawn_training_dummy`, driven by a `Mimic` replaying the
+/// player `delay` seconds late instead of standing still.
+pub fn spawn_mimic(cmd: &mut Commands, pos: Vec2, delay: f32) -> Entity {
+    cmd.spawn(enemy_bundle(pos, pos.x, pos.x))
+        .insert((Mimic::new(delay), Name::new("Mimic")))
+        .id()
 }
 
 // ====== Scorers ======
@@ -241,11 +768,15 @@ fn has_target_scorer(
     mut q: Query<(&Actor, &mut Score), With<HasTarget>>,
     senses: Query<&EnemySenses>,
     stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
     deads: Query<Option<&EnemyDead>>,
+    graces: Query<(), With<SpawnGrace>>,
 ) {
     for (Actor(actor), mut score) in q.iter_mut() {
         let disabled = stuns.get(*actor).ok().flatten().is_some()
-            || deads.get(*actor).ok().flatten().is_some();
+            || juggled.contains(*actor)
+            || deads.get(*actor).ok().flatten().is_some()
+            || graces.contains(*actor);
         if disabled {
             score.set(0.0);
             continue;
@@ -265,12 +796,16 @@ fn attack_in_range_scorer(
     swinging_q: Query<Option<&MeleeAttackActive>>,
     cd_q: Query<Option<&EnemyAttackCooldown>>,
     stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
     deads: Query<Option<&EnemyDead>>,
+    graces: Query<(), With<SpawnGrace>>,
+    tuning: Res<EnemyTuning>,
 ) {
-    const ATTACK_BAND_X: f32 = RANGE + 24.0;
-
     for (Actor(actor), mut score) in q.iter_mut() {
-        if stuns.get(*actor).ok().flatten().is_some() || deads.get(*actor).ok().flatten().is_some()
+        if stuns.get(*actor).ok().flatten().is_some()
+            || juggled.contains(*actor)
+            || deads.get(*actor).ok().flatten().is_some()
+            || graces.contains(*actor)
         {
             score.set(0.0);
             continue;
@@ -294,28 +829,103 @@ fn attack_in_range_scorer(
         let ok = senses
             .get(*actor)
             .ok()
-            .map(|s| s.target.is_some() && s.dx.abs() <= ATTACK_BAND_X)
+            .map(|s| s.target.is_some() && s.dx.abs() <= tuning.attack_band_x)
             .unwrap_or(false);
 
         score.set(if ok { 1.0 } else { 0.0 });
     }
 }
 
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct DashInRange;
+
+fn dash_in_range_scorer(
+    mut q: Query<(&Actor, &mut Score), With<DashInRange>>,
+    senses: Query<&EnemySenses>,
+    dashers: Query<(&DashAttack, Option<&DashCooldown>, Has<EnemyDashing>)>,
+    stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
+    deads: Query<Option<&EnemyDead>>,
+    graces: Query<(), With<SpawnGrace>>,
+    tuning: Res<EnemyTuning>,
+) {
+    for (Actor(actor), mut score) in q.iter_mut() {
+        let Ok((dash, cd, dashing)) = dashers.get(*actor) else {
+            score.set(0.0);
+            continue;
+        };
+        if stuns.get(*actor).ok().flatten().is_some()
+            || juggled.contains(*actor)
+            || deads.get(*actor).ok().flatten().is_some()
+            || graces.contains(*actor)
+        {
+            score.set(0.0);
+            continue;
+        }
+        // Finish a dash once started
+        if dashing {
+            score.set(1.0);
+            continue;
+        }
+        let ready = cd.is_none_or(|c| c.0.finished());
+        let ok = ready
+            && senses.get(*actor).ok().is_some_and(|s| {
+                s.target.is_some() && s.dx.abs() > tuning.attack_band_x && s.dx.abs() <= dash.range
+            });
+        score.set(if ok { 1.0 } else { 0.0 });
+    }
+}
+
+#[derive(Debug, Clone, Component, ScorerBuilder)]
+pub struct AwayFromHome;
+
+/// No target and wandered (or chased) past the patrol bounds.
+fn away_from_home_scorer(
+    mut q: Query<(&Actor, &mut Score), With<AwayFromHome>>,
+    enemies: Query<(&GlobalTransform, &PatrolBounds, &EnemySenses)>,
+    stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
+    deads: Query<Option<&EnemyDead>>,
+) {
+    for (Actor(actor), mut score) in q.iter_mut() {
+        let Ok((gt, bounds, senses)) = enemies.get(*actor) else {
+            score.set(0.0);
+            continue;
+        };
+        if stuns.get(*actor).ok().flatten().is_some()
+            || juggled.contains(*actor)
+            || deads.get(*actor).ok().flatten().is_some()
+            || senses.target.is_some()
+        {
+            score.set(0.0);
+            continue;
+        }
+        let x = gt.translation().x;
+        let away = x < bounds.left - RETURN_SLACK || x > bounds.right + RETURN_SLACK;
+        score.set(if away { 1.0 } else { 0.0 });
+    }
+}
+
 // ====== Actions ======
 #[derive(Debug, Clone, Component, ActionBuilder)]
 pub struct Patrol;
 
 fn patrol_action(
     time: Res<Time>,
+    mut cmd: Commands,
     mut q: Query<(&Actor, &mut ActionState), With<Patrol>>,
     mut movers: Query<(
         &mut LinearVelocity,
         &GlobalTransform,
         &mut PatrolDir,
         &PatrolBounds,
+        Option<&mut PatrolPause>,
     )>,
     stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
     deads: Query<Option<&EnemyDead>>,
+    mut rng: ResMut<GameRng>,
+    tuning: Res<EnemyTuning>,
 ) {
     for (Actor(actor), mut state) in q.iter_mut() {
         match *state {
@@ -324,22 +934,45 @@ fn patrol_action(
             }
             ActionState::Executing => {
                 if stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
                     || deads.get(*actor).ok().flatten().is_some()
                 {
                     continue;
                 }
 
-                if let Ok((mut vel, gt, mut dir, bounds)) = movers.get_mut(*actor) {
+                if let Ok((mut vel, gt, mut dir, bounds, pause)) = movers.get_mut(*actor) {
+                    let accel = ACCEL * time.delta_secs();
+
+                    // Standing still (idle anim plays once speed drops)
+                    if let Some(mut p) = pause {
+                        p.0.tick(time.delta());
+                        if p.0.finished() {
+                            cmd.entity(*actor).remove::<PatrolPause>();
+                        } else {
+                            vel.x += (0.0 - vel.x).clamp(-accel, accel);
+                            continue;
+                        }
+                    }
+
                     let x = gt.translation().x;
+                    let mut turned = false;
                     if x <= bounds.left {
+                        turned |= dir.0 < 0.0;
                         dir.0 = 1.0;
                     }
                     if x >= bounds.right {
+                        turned |= dir.0 > 0.0;
                         dir.0 = -1.0;
                     }
 
-                    let target_vx = dir.0 * WALK;
-                    let accel = ACCEL * time.delta_secs();
+                    let chance = (PATROL_RANDOM_PAUSES_PER_S * time.delta_secs_f64()).min(1.0);
+                    if turned || rng.random_bool(chance) {
+                        let secs = rng.random_range(PATROL_PAUSE_MIN..PATROL_PAUSE_MAX);
+                        cmd.entity(*actor)
+                            .insert(PatrolPause(Timer::from_seconds(secs, TimerMode::Once)));
+                    }
+
+                    let target_vx = dir.0 * tuning.walk;
                     let delta = (target_vx - vel.x).clamp(-accel, accel);
                     vel.x += delta;
                 }
@@ -359,19 +992,30 @@ pub struct Chase;
 
 fn chase_action(
     time: Res<Time>,
+    mut cmd: Commands,
     mut q: Query<(&Actor, &mut ActionState), With<Chase>>,
     mut movers: Query<(&mut LinearVelocity, &GlobalTransform)>,
     senses: Query<&EnemySenses>,
     stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
     deads: Query<Option<&EnemyDead>>,
+    mut droppers: Query<
+        (&CollidingEntities, &mut PassThroughOneWayPlatform),
+        Without<EnemyDropThrough>,
+    >,
+    one_way: Query<(), With<OneWayPlatform>>,
+    tuning: Res<EnemyTuning>,
 ) {
     for (Actor(actor), mut state) in q.iter_mut() {
         match *state {
             ActionState::Init | ActionState::Requested => {
+                // Aggro cancels any patrol idle
+                cmd.entity(*actor).remove::<PatrolPause>();
                 *state = ActionState::Executing;
             }
             ActionState::Executing => {
                 if stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
                     || deads.get(*actor).ok().flatten().is_some()
                 {
                     continue;
@@ -383,14 +1027,31 @@ fn chase_action(
                         let dir = dx.signum();
 
                         // Slow/stop just inside attack band so Attack scorer can take over
-                        let desired = if s.dist <= RANGE + 8.0 {
+                        let desired = if s.dist <= tuning.range + 8.0 {
                             0.0
                         } else {
-                            dir * RUN
+                            dir * tuning.run
                         };
                         let accel = ACCEL * time.delta_secs();
                         let delta = (desired - vel.x).clamp(-accel, accel);
                         vel.x += delta;
+
+                        // Target below and standing on a one-way floor → drop through
+                        let below = s.target_pos.y < gt.translation().y - ENEMY_DROP_MIN_DY;
+                        let on_one_way = |contacts: &CollidingEntities| {
+                            contacts.iter().any(|c| one_way.contains(*c))
+                        };
+                        if let Ok((contacts, mut pass)) = droppers.get_mut(*actor) {
+                            if below && on_one_way(contacts) {
+                                *pass = PassThroughOneWayPlatform::Always;
+                                cmd.entity(*actor)
+                                    .insert(EnemyDropThrough(Timer::from_seconds(
+                                        ENEMY_DROP_SECS,
+                                        TimerMode::Once,
+                                    )));
+                                cmd.queue(WakeUpBody(*actor));
+                            }
+                        }
                     } else {
                         *state = ActionState::Success;
                     }
@@ -408,11 +1069,94 @@ fn chase_action(
     }
 }
 
-fn on_enemy_class_added_set_hp(mut q: Query<(&EnemyClass, &mut EnemyStats), Added<EnemyClass>>) {
-    for (class, mut stats) in &mut q {
-        let max = class.0.base_stats.max_health as f32;
-        stats._max_health = max;
+#[derive(Debug, Clone, Component, ActionBuilder)]
+pub struct Returning;
+
+/// Walk toward `HomePos` until back inside the patrol bounds. A ledge on the
+/// way home re-anchors home and bounds at the current spot instead.
+fn returning_action(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut q: Query<(&Actor, &mut ActionState), With<Returning>>,
+    mut movers: Query<(
+        &mut LinearVelocity,
+        &GlobalTransform,
+        &mut HomePos,
+        &mut PatrolBounds,
+    )>,
+    stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
+    deads: Query<Option<&EnemyDead>>,
+    spatial: SpatialQuery,
+    tuning: Res<EnemyTuning>,
+) {
+    let ground = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
+    for (Actor(actor), mut state) in q.iter_mut() {
+        match *state {
+            ActionState::Init | ActionState::Requested => {
+                cmd.entity(*actor).remove::<PatrolPause>();
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                if stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
+                    || deads.get(*actor).ok().flatten().is_some()
+                {
+                    continue;
+                }
+                let Ok((mut vel, gt, mut home, mut bounds)) = movers.get_mut(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                let pos = gt.translation().truncate();
+                let accel = ACCEL * time.delta_secs();
+                if pos.x >= bounds.left && pos.x <= bounds.right {
+                    vel.x += (0.0 - vel.x).clamp(-accel, accel);
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let dir = (home.x - pos.x).signum();
+                let ahead = pos + Vec2::X * dir * RETURN_LEDGE_PROBE_AHEAD;
+                let ledge = spatial
+                    .cast_ray(ahead, Dir2::NEG_Y, RETURN_LEDGE_PROBE_DEPTH, true, &ground)
+                    .is_none();
+                if ledge {
+                    // Can't get home on foot: this is home now
+                    let shift = pos.x - home.x;
+                    bounds.left += shift;
+                    bounds.right += shift;
+                    home.0 = pos;
+                    vel.x = 0.0;
+                    *state = ActionState::Success;
+                    continue;
+                }
+
+                let target_vx = dir * tuning.return_speed;
+                vel.x += (target_vx - vel.x).clamp(-accel, accel);
+            }
+            ActionState::Cancelled => {
+                *state = ActionState::Failure;
+            }
+            ActionState::Success | ActionState::Failure => {
+                *state = ActionState::Requested;
+            }
+        }
+    }
+}
+
+fn on_enemy_class_added_set_hp(
+    new_game_plus: Res<NewGamePlus>,
+    mut q: Query<
+        (&EnemyClass, &mut EnemyStats, &mut MeleeRaycastSpec),
+        (Added<EnemyClass>, Without<TrainingDummy>),
+    >,
+) {
+    for (class, mut stats, mut spec) in &mut q {
+        let max = class.0.base_stats.max_health as f32 * new_game_plus.health_scale();
+        stats.max_health = max;
         stats.health = max;
+        spec.damage = (spec.damage as f32 * new_game_plus.damage_scale()).round() as i32;
     }
 }
 
@@ -427,16 +1171,23 @@ fn attack_action(
         Option<&mut EnemyAttackCooldown>,
     )>,
     mut vels: Query<&mut LinearVelocity>,
+    carries: Query<&SwingCarry>,
     senses_q: Query<&EnemySenses>,
     contacts_q: Query<&CollidingEntities>,
     durs_q: Query<&EnemyAttackDurations>,
+    sets_q: Query<&EnemyAttackSet>,
+    mut specs: Query<&mut MeleeRaycastSpec>,
     stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
     deads: Query<Option<&EnemyDead>>,
+    tuning: Res<EnemyTuning>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (Actor(actor), mut state) in q.iter_mut() {
         match *state {
             ActionState::Init | ActionState::Requested => {
                 if stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
                     || deads.get(*actor).ok().flatten().is_some()
                 {
                     *state = ActionState::Failure;
@@ -465,9 +1216,23 @@ fn attack_action(
                         .map(|c| !c.is_empty())
                         .unwrap_or(true);
                     let in_air = !on_ground;
-                    let running = speed > (RUN * 0.7);
+                    let running = speed > (tuning.run * 0.7);
                     let moving = speed > 6.0;
 
+                    let momentum = if in_air {
+                        0.0
+                    } else if running {
+                        tuning.run_attack_momentum
+                    } else if moving {
+                        tuning.walk_attack_momentum
+                    } else {
+                        0.0
+                    };
+                    let carry = SwingCarry {
+                        pre_speed: speed,
+                        vx: v.as_ref().map(|v| v.x).unwrap_or(0.0) * momentum.clamp(0.0, 1.0),
+                    };
+
                     let secs = if in_air {
                         // choose jump/fall by vertical sign if we had it; here use fall as fallback
                         d.map(|d| {
@@ -486,12 +1251,40 @@ fn attack_action(
                         d.map(|d| d.idle).unwrap_or(SWING_DEFAULT)
                     };
 
+                    // Multi-attack classes: pick this swing's variant by range
+                    let picked = sets_q.get(*actor).ok().and_then(|set| {
+                        let dx = s.map(|s| s.dx.abs()).unwrap_or(0.0);
+                        set.pick(dx, &mut *rng)
+                    });
+                    let secs = match picked {
+                        Some(atk) => {
+                            if let Ok(mut spec) = specs.get_mut(*actor) {
+                                spec.length = tuning.range * atk.reach_mult.max(0.0);
+                            }
+                            cmd.entity(*actor).insert(SwingModifiers {
+                                damage_mult: atk.damage_mult.max(0.0),
+                                knockback_mult: 1.0,
+                                launch: 0.0,
+                            });
+                            secs * atk.windup_mult.max(0.05)
+                        }
+                        None => {
+                            // Back to the plain swing, whatever the last variant was
+                            if let Ok(mut spec) = specs.get_mut(*actor) {
+                                spec.length = tuning.range;
+                            }
+                            cmd.entity(*actor).remove::<SwingModifiers>();
+                            secs
+                        }
+                    };
+
                     cmd.entity(*actor).insert((
                         MeleeAttackActive,
                         EnemyAttackTimer(Timer::from_seconds(secs, TimerMode::Once)),
+                        carry,
                     ));
                     if let Ok(mut v) = vels.get_mut(*actor) {
-                        v.x = 0.0;
+                        v.x = carry.vx;
                     }
                     *state = ActionState::Executing;
                 } else {
@@ -502,11 +1295,14 @@ fn attack_action(
             ActionState::Executing => {
                 // If stunned mid-swing: cancel, no cooldown.
                 if stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
                     || deads.get(*actor).ok().flatten().is_some()
                 {
-                    cmd.entity(*actor)
-                        .remove::<MeleeAttackActive>()
-                        .remove::<EnemyAttackTimer>();
+                    cmd.entity(*actor).remove::<MeleeAttackActive>().remove::<(
+                        EnemyAttackTimer,
+                        SwingCarry,
+                        SwingModifiers,
+                    )>();
                     if let Ok(mut v) = vels.get_mut(*actor) {
                         v.x = 0.0;
                     }
@@ -514,12 +1310,17 @@ fn attack_action(
                     continue;
                 }
 
-                // Hold still while the swing timer runs
-                if let Ok(mut v) = vels.get_mut(*actor) {
-                    v.x = 0.0;
-                }
-
                 if let Ok((maybe_timer, _)) = timers.get_mut(*actor) {
+                    // Slide the carried momentum down to a stop over the swing
+                    let left = maybe_timer
+                        .as_ref()
+                        .map(|t| t.0.fraction_remaining())
+                        .unwrap_or(0.0);
+                    let carried = carries.get(*actor).map(|c| c.vx).unwrap_or(0.0);
+                    if let Ok(mut v) = vels.get_mut(*actor) {
+                        v.x = carried * left;
+                    }
+
                     let done = maybe_timer
                         .as_ref()
                         .map(|t| t.0.finished())
@@ -528,9 +1329,9 @@ fn attack_action(
                         // Swing finished: end swing and NOW start cooldown.
                         cmd.entity(*actor)
                             .remove::<MeleeAttackActive>()
-                            .remove::<EnemyAttackTimer>()
+                            .remove::<(EnemyAttackTimer, SwingCarry, SwingModifiers)>()
                             .insert(EnemyAttackCooldown(Timer::from_seconds(
-                                COOLDOWN,
+                                tuning.cooldown,
                                 TimerMode::Once,
                             )));
                         *state = ActionState::Success;
@@ -544,9 +1345,11 @@ fn attack_action(
 
             ActionState::Cancelled => {
                 // Cancel means “didn’t complete swing”; no cooldown here.
-                cmd.entity(*actor)
-                    .remove::<MeleeAttackActive>()
-                    .remove::<EnemyAttackTimer>();
+                cmd.entity(*actor).remove::<MeleeAttackActive>().remove::<(
+                    EnemyAttackTimer,
+                    SwingCarry,
+                    SwingModifiers,
+                )>();
                 *state = ActionState::Failure;
             }
 
@@ -557,30 +1360,447 @@ fn attack_action(
     }
 }
 
-// ====== Perception & misc ======
-fn sense_player(
-    players: Query<(Entity, &GlobalTransform), With<Player>>,
-    mut enemies: Query<(&GlobalTransform, &mut EnemySenses), With<Enemy>>,
-) {
-    let player = players.iter().next();
-    if let Some((pe, pgt)) = player {
-        let p = pgt.translation().truncate();
-        for (egt, mut s) in enemies.iter_mut() {
-            let e = egt.translation().truncate();
-            s.target = if p.distance(e) <= AGGRO {
-                Some(pe)
-            } else {
-                None
-            };
-            s.target_pos = p;
-            s.dx = p.x - e.x;
-            s.dist = p.distance(e);
-        }
-    } else {
-        for (_egt, mut s) in enemies.iter_mut() {
-            s.target = None;
-        }
-    }
+#[derive(Debug, Clone, Component, ActionBuilder)]
+pub struct Dash;
+
+fn dash_action(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut q: Query<(&Actor, &mut ActionState), With<Dash>>,
+    mut dashers: Query<(
+        &DashAttack,
+        &mut LinearVelocity,
+        &GlobalTransform,
+        Option<&mut EnemyDashing>,
+    )>,
+    senses: Query<&EnemySenses>,
+    stuns: Query<Option<&EnemyStunned>>,
+    juggled: Query<(), With<Juggled>>,
+    deads: Query<Option<&EnemyDead>>,
+    spatial: SpatialQuery,
+    tuning: Res<EnemyTuning>,
+    palette: Res<Palette>,
+) {
+    let ground = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
+    let end_dash = |cmd: &mut Commands, actor: Entity, marker: Option<Entity>| {
+        cmd.entity(actor).remove::<EnemyDashing>();
+        if let Some(marker) = marker {
+            cmd.entity(marker).try_despawn();
+        }
+    };
+    for (Actor(actor), mut state) in q.iter_mut() {
+        let Ok((dash, mut vel, gt, dashing)) = dashers.get_mut(*actor) else {
+            *state = ActionState::Failure;
+            continue;
+        };
+        match *state {
+            ActionState::Init | ActionState::Requested => {
+                let Ok(s) = senses.get(*actor) else {
+                    *state = ActionState::Failure;
+                    continue;
+                };
+                let marker = cmd
+                    .spawn((
+                        Text2d::new("!"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(palette.danger.with_alpha(1.0)),
+                        Transform::from_xyz(0.0, DASH_TELEGRAPH_Y, 5.0),
+                        ChildOf(*actor),
+                        Name::new("DashTelegraph"),
+                    ))
+                    .id();
+                cmd.entity(*actor)
+                    .remove::<PatrolPause>()
+                    .insert(EnemyDashing {
+                        telegraph: Timer::from_seconds(DASH_TELEGRAPH_SECS, TimerMode::Once),
+                        burst: Timer::from_seconds(
+                            dash.range / dash.speed.max(1.0),
+                            TimerMode::Once,
+                        ),
+                        dir: s.dx.signum(),
+                        marker,
+                    });
+                vel.x = 0.0;
+                *state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let interrupted = stuns.get(*actor).ok().flatten().is_some()
+                    || juggled.contains(*actor)
+                    || deads.get(*actor).ok().flatten().is_some();
+                if interrupted {
+                    // Knocked out of it: drop the dash without a cooldown
+                    end_dash(&mut cmd, *actor, dashing.map(|d| d.marker));
+                    *state = ActionState::Failure;
+                    continue;
+                }
+                let Some(mut d) = dashing else {
+                    continue;
+                };
+
+                // Wind-up: hold still under the "!", re-aiming at the target
+                if !d.telegraph.finished() {
+                    d.telegraph.tick(time.delta());
+                    vel.x = 0.0;
+                    if let Ok(s) = senses.get(*actor) {
+                        d.dir = s.dx.signum();
+                    }
+                    if d.telegraph.finished() {
+                        cmd.entity(d.marker).try_despawn();
+                    }
+                    continue;
+                }
+
+                d.burst.tick(time.delta());
+                let pos = gt.translation().truncate();
+                let ahead = pos + Vec2::X * d.dir * DASH_LEDGE_PROBE_AHEAD;
+                let ledge = spatial
+                    .cast_ray(ahead, Dir2::NEG_Y, DASH_LEDGE_PROBE_DEPTH, true, &ground)
+                    .is_none();
+                let arrived = senses.get(*actor).is_ok_and(|s| s.dx.abs() <= tuning.range);
+                if d.burst.finished() || ledge || arrived {
+                    vel.x = 0.0;
+                    end_dash(&mut cmd, *actor, None);
+                    cmd.entity(*actor).insert(DashCooldown(Timer::from_seconds(
+                        dash.cooldown,
+                        TimerMode::Once,
+                    )));
+                    *state = ActionState::Success;
+                } else {
+                    vel.x = d.dir * dash.speed;
+                }
+            }
+            ActionState::Cancelled => {
+                end_dash(&mut cmd, *actor, dashing.map(|d| d.marker));
+                *state = ActionState::Failure;
+            }
+            ActionState::Success | ActionState::Failure => {
+                *state = ActionState::Requested;
+            }
+        }
+    }
+}
+
+fn tick_dash_cooldowns(time: Res<Time>, mut q: Query<&mut DashCooldown>) {
+    for mut cd in &mut q {
+        cd.0.tick(time.delta());
+    }
+}
+
+/// The frame a `SummonsHelp` enemy acquires the player, start the call
+/// (if it's off cooldown).
+fn start_summon_on_sight(
+    mut cmd: Commands,
+    mut seen: Local<HashSet<Entity>>,
+    palette: Res<Palette>,
+    q: Query<
+        (
+            Entity,
+            &EnemySenses,
+            Option<&SummonCooldown>,
+            Has<EnemySummoning>,
+        ),
+        (
+            With<SummonsHelp>,
+            Without<EnemyDead>,
+            Without<EnemyStunned>,
+            Without<Juggled>,
+        ),
+    >,
+) {
+    seen.retain(|e| q.contains(*e));
+    for (e, senses, cooldown, summoning) in &q {
+        if senses.target.is_none() {
+            seen.remove(&e);
+            continue;
+        }
+        let spotted_now = seen.insert(e);
+        let ready = cooldown.is_none_or(|cd| cd.0.finished());
+        if !spotted_now || !ready || summoning {
+            continue;
+        }
+        let marker = cmd
+            .spawn((
+                Text2d::new("!!"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(palette.danger.with_alpha(1.0)),
+                Transform::from_xyz(0.0, DASH_TELEGRAPH_Y, 5.0),
+                ChildOf(e),
+                Name::new("SummonTelegraph"),
+            ))
+            .id();
+        cmd.entity(e).insert(EnemySummoning {
+            telegraph: Timer::from_seconds(SUMMON_TELEGRAPH_SECS, TimerMode::Once),
+            marker,
+        });
+    }
+}
+
+/// Finish the wind-up: drop up to `count` helpers on the ground nearby,
+/// never past `EntityBudget::max_enemies`. A stun or death cancels the call.
+fn finish_summons(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut rng: ResMut<GameRng>,
+    spatial: SpatialQuery,
+    budget: Res<EntityBudget>,
+    enemies: Query<(), (With<Enemy>, Without<Corpse>)>,
+    mut q: Query<(
+        Entity,
+        &SummonsHelp,
+        &mut EnemySummoning,
+        &mut LinearVelocity,
+        &GlobalTransform,
+        Has<EnemyDead>,
+        Has<EnemyStunned>,
+    )>,
+) {
+    let mut room = budget.max_enemies.saturating_sub(enemies.iter().count());
+    for (e, summon, mut summoning, mut vel, gt, dead, stunned) in &mut q {
+        if dead || stunned {
+            cmd.entity(summoning.marker).try_despawn();
+            cmd.entity(e).remove::<EnemySummoning>();
+            continue;
+        }
+        vel.x = 0.0;
+        if !summoning.telegraph.tick(time.delta()).finished() {
+            continue;
+        }
+        cmd.entity(summoning.marker).try_despawn();
+        cmd.entity(e)
+            .remove::<EnemySummoning>()
+            .insert(SummonCooldown(Timer::from_seconds(
+                summon.cooldown,
+                TimerMode::Once,
+            )));
+
+        let origin = gt.translation().truncate();
+        for _ in 0..summon.count {
+            if room == 0 {
+                break;
+            }
+            let side = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
+            let x = origin.x + side * rng.random_range(SUMMON_SPREAD_MIN..SUMMON_SPREAD_MAX);
+            let start = Vec2::new(x, origin.y + SUMMON_PROBE_HEIGHT);
+            let Some(pos) = ground_point_below(&spatial, start, SUMMON_PROBE_DEPTH, 8.0) else {
+                continue;
+            };
+            spawn_enemy(
+                &mut cmd,
+                pos,
+                pos.x - SUMMON_PATROL_SPAN,
+                pos.x + SUMMON_PATROL_SPAN,
+            );
+            room -= 1;
+        }
+    }
+}
+
+fn tick_summon_cooldowns(time: Res<Time>, mut q: Query<&mut SummonCooldown>) {
+    for mut cd in &mut q {
+        cd.0.tick(time.delta());
+    }
+}
+
+// ====== Perception & misc ======
+/// Louder states win at once; quieting down fades at `NoiseLevels::falloff`.
+fn update_player_noise(
+    time: Res<Time>,
+    levels: Res<NoiseLevels>,
+    mut noise: ResMut<PlayerNoise>,
+    player: Query<
+        (
+            Has<Idle>,
+            Has<Running>,
+            Has<SprintJumping>,
+            Has<IdleAttack>,
+            Has<WalkingAttack>,
+            Has<RunningAttack>,
+            Has<JumpingAttack>,
+            Has<FallingAttack>,
+            Has<Dashing>,
+        ),
+        With<Player>,
+    >,
+) {
+    let Ok((idle, running, sprint_jump, a0, a1, a2, a3, a4, dashing)) = player.single() else {
+        return;
+    };
+    let target = if a0 || a1 || a2 || a3 || a4 {
+        levels.attack
+    } else if running || sprint_jump || dashing {
+        levels.sprint
+    } else if idle {
+        levels.still
+    } else {
+        levels.walk
+    };
+    let faded = noise.0 - levels.falloff * time.delta_secs();
+    noise.0 = target.max(faded);
+}
+
+/// Fresh offsets every frame; a new target also needs a clear line through
+/// the level, checked on the enemy's `PerceptionSchedule` slice. A target
+/// already held is kept while it stays in range.
+fn sense_player(
+    players: Query<(Entity, &GlobalTransform), With<Player>>,
+    mut enemies: Query<
+        (Entity, &GlobalTransform, &mut EnemySenses),
+        (With<Enemy>, Without<TrainingDummy>),
+    >,
+    tuning: Res<EnemyTuning>,
+    noise: Res<PlayerNoise>,
+    schedule: Res<PerceptionSchedule>,
+    spatial: SpatialQuery,
+    mut frame: Local<u32>,
+) {
+    let Some((pe, pgt)) = players.iter().next() else {
+        for (_, _, mut s) in &mut enemies {
+            s.target = None;
+        }
+        return;
+    };
+    *frame = frame.wrapping_add(1);
+    let slices = if enemies.iter().len() > schedule.crowd_threshold {
+        schedule.slices.max(1)
+    } else {
+        1
+    };
+    let walls = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
+    let aggro = tuning.aggro * noise.0.max(0.0);
+    let p = pgt.translation().truncate();
+    for (entity, egt, mut s) in &mut enemies {
+        let e = egt.translation().truncate();
+        s.target_pos = p;
+        s.dx = p.x - e.x;
+        s.dist = p.distance(e);
+        if s.dist > aggro {
+            s.target = None;
+            continue;
+        }
+        if s.target == Some(pe) {
+            continue;
+        }
+        let my_turn = entity.index().wrapping_add(*frame) % slices == 0;
+        if !my_turn && !s.is_added() {
+            // Whatever it held isn't the player any more
+            s.target = None;
+            continue;
+        }
+        let seen = Dir2::new(p - e)
+            .ok()
+            .is_none_or(|dir| spatial.cast_ray(e, dir, s.dist, true, &walls).is_none());
+        s.target = seen.then_some(pe);
+    }
+}
+
+/// After the actions set velocities: level contacts steeper than
+/// `EnemyTuning::max_slope_deg` are walls. Walking into one stops the enemy
+/// (a patrol turns around) instead of grinding up it.
+fn block_steep_contacts(
+    tuning: Res<EnemyTuning>,
+    collisions: Collisions,
+    mut q: Query<
+        (Entity, &mut LinearVelocity, Option<&mut PatrolDir>),
+        (
+            With<Enemy>,
+            Without<EnemyDead>,
+            Without<EnemyStunned>,
+            Without<Juggled>,
+        ),
+    >,
+    colliders: Query<(Option<&RigidBody>, Option<&ColliderOf>)>,
+    bodies: Query<&RigidBody>,
+) {
+    let min_up = tuning.max_slope_deg.clamp(0.0, 90.0).to_radians().cos();
+    for (e, mut vel, patrol) in &mut q {
+        let mut wall_side = 0.0;
+        for pair in collisions.collisions_with(e) {
+            let (other, flip) = if pair.collider1 == e {
+                (pair.collider2, -1.0)
+            } else {
+                (pair.collider1, 1.0)
+            };
+            if !is_static_collider(other, &colliders, &bodies) {
+                continue;
+            }
+            for manifold in &pair.manifolds {
+                // Normal pointing out of the geometry, into the enemy
+                let n = manifold.normal * flip;
+                if n.y < min_up && n.x.abs() > f32::EPSILON {
+                    wall_side = -n.x.signum();
+                }
+            }
+        }
+        if wall_side == 0.0 || vel.x * wall_side <= 0.0 {
+            continue;
+        }
+        vel.x = 0.0;
+        if let Some(mut dir) = patrol.filter(|d| d.0 * wall_side > 0.0) {
+            dir.0 = -dir.0;
+        }
+    }
+}
+
+/// After the actions set velocities: nudge apart enemies that overlap on
+/// the same spot. Closer neighbours push harder; knocked-about enemies are
+/// left alone.
+fn separate_enemies(
+    spatial: SpatialQuery,
+    sep: Res<EnemySeparation>,
+    mut q: Query<
+        (Entity, &GlobalTransform, &mut LinearVelocity),
+        (
+            With<Enemy>,
+            Without<EnemyDead>,
+            Without<EnemyStunned>,
+            Without<Juggled>,
+            Without<EnemyDashing>,
+        ),
+    >,
+) {
+    if sep.radius <= 0.0 || sep.max_push <= 0.0 {
+        return;
+    }
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Enemy));
+    let probe = Collider::circle(sep.radius);
+    let mut pushes = Vec::new();
+    for (e, gt, _) in &q {
+        let pos = gt.translation().truncate();
+        let mut push = 0.0;
+        for other in spatial.shape_intersections(&probe, pos, 0.0, &filter) {
+            if other == e {
+                continue;
+            }
+            let Ok((_, ogt, _)) = q.get(other) else {
+                continue;
+            };
+            let dx = pos.x - ogt.translation().x;
+            let weight = (1.0 - dx.abs() / sep.radius).max(0.0);
+            // Exactly stacked: split by entity so the pair still separates
+            let side = if dx.abs() > f32::EPSILON {
+                dx.signum()
+            } else if e.index() < other.index() {
+                -1.0
+            } else {
+                1.0
+            };
+            push += side * weight;
+        }
+        if push != 0.0 {
+            pushes.push((e, (push * sep.max_push).clamp(-sep.max_push, sep.max_push)));
+        }
+    }
+    for (e, push) in pushes {
+        if let Ok((_, _, mut vel)) = q.get_mut(e) {
+            vel.x += push;
+        }
+    }
 }
 
 /// Always face the target if aggro’d; fallback to velocity otherwise.
@@ -594,7 +1814,7 @@ fn face_by_target_or_velocity(
             Option<&EnemyStunned>,
             Option<&EnemyDead>,
         ),
-        With<Enemy>,
+        (With<Enemy>, Without<Mimic>),
     >,
 ) {
     for (mut sprite, vel, senses, stunned, dead) in q.iter_mut() {
@@ -618,35 +1838,18 @@ fn on_enemy_added_attach_sprite_and_anims(
     mut commands: Commands,
     sheet: Res<crate::animations::EnemySpritesheet>, // reuse your existing spritesheet asset
     library: Res<AnimationLibrary>,
-    added: Query<Entity, Added<Enemy>>,
+    world: Res<WorldScale>,
+    added: Query<Entity, (Added<Enemy>, Without<Mimic>)>,
 ) {
+    if added.is_empty() {
+        return;
+    }
+    // Precise durations from the sheet manifest (same source as registration)
+    let secs_map = sheet.anim_seconds();
+    // If you have an "enemy_combat:..." set, swap names accordingly.
+    let clips = AnimMap::build(&library, ENEMY_ANIM_CLIPS);
+    let idle_id = clips.clip(AnimState::Idle);
     for e in &added {
-        // If you have an "enemy_combat:..." set, swap names accordingly.
-        let idle_id = library
-            .animation_with_name("enemy:idle")
-            .expect("missing animation: enemy:idle");
-
-        let clips = EnemyAnimClips {
-            idle: idle_id,
-            walk: library.animation_with_name("enemy:walk"),
-            run: library.animation_with_name("enemy:run"),
-            jump: library.animation_with_name("enemy:jumpmid"),
-            fall: library.animation_with_name("enemy:jumpfall"),
-            attack_idle: library
-                .animation_with_name("enemy:attack")
-                .expect("missing animation: enemy:attack"),
-            attack_walk: library.animation_with_name("enemy:attack"),
-            attack_run: library.animation_with_name("enemy:blastattack"),
-            attack_jump: library.animation_with_name("enemy:jumpmid"),
-            attack_fall: library.animation_with_name("enemy:jumpfall"),
-            // NEW:
-            stunned: library.animation_with_name("enemy:shocka"),
-            die: library.animation_with_name("enemy:die"),
-        };
-
-        // Load precise durations from JSON (same source as player)
-        let secs_map = load_anim_seconds_from_json("assets/EnemySheet.json");
-
         let secs_attack_idle = *secs_map.get("enemy:attack").unwrap_or(&SWING_DEFAULT);
         let secs_attack_walk = *secs_map.get("enemy:attack").unwrap_or(&secs_attack_idle);
         let secs_attack_run = *secs_map
@@ -660,6 +1863,10 @@ fn on_enemy_added_attach_sprite_and_anims(
             .or_else(|| secs_map.get("enemy:shocka"))
             .unwrap_or(&0.6);
         let die_secs = *secs_map.get("enemy:die").unwrap_or(&1.2);
+        let hurt_secs = *secs_map
+            .get("enemy:hurt")
+            .or_else(|| secs_map.get("enemy:flinch"))
+            .unwrap_or(&FLINCH_SECS);
 
         let mut sprite = Sprite::from_atlas_image(
             sheet.image.clone(),
@@ -676,7 +1883,8 @@ fn on_enemy_added_attach_sprite_and_anims(
         commands.entity(e).insert((
             sprite,
             anim,
-            clips,
+            clips.clone(),
+            world.body_collider(),
             EnemyCurrentAnim(idle_id),
             // attach attack durations & impact (stun/die) durations
             EnemyAttackDurations {
@@ -689,6 +1897,7 @@ fn on_enemy_added_attach_sprite_and_anims(
             EnemyImpactDurations {
                 stun: stun_secs,
                 die: die_secs,
+                hurt: hurt_secs,
             },
         ));
     }
@@ -698,86 +1907,78 @@ fn drive_enemy_animation(
     mut q: Query<
         (
             Entity,
-            &EnemyAnimClips,
+            &AnimMap,
             &mut SpritesheetAnimation,
             &mut EnemyCurrentAnim,
+            &mut AnimHeld,
             &LinearVelocity,
         ),
         With<Enemy>,
     >,
     stunned_q: Query<(), With<EnemyStunned>>,
     dead_q: Query<(), With<EnemyDead>>,
-    swing_q: Query<(), With<MeleeAttackActive>>,
+    flinch_q: Query<(), With<Flinch>>,
+    swing_q: Query<Option<&SwingCarry>, With<MeleeAttackActive>>,
     contacts_q: Query<&CollidingEntities>,
+    tuning: Res<EnemyTuning>,
+    time: Res<Time>,
+    hold: Res<AnimHold>,
 ) {
-    for (e, clips, mut anim, mut current, vel) in &mut q {
+    for (e, clips, mut anim, mut current, mut held, vel) in &mut q {
         let dead = dead_q.get(e).is_ok();
         let stunned = stunned_q.get(e).is_ok();
-        let swinging = swing_q.get(e).is_ok();
+        let flinching = flinch_q.contains(e) && clips.has(AnimState::Hurt);
+        let swing = swing_q.get(e);
+        let swinging = swing.is_ok();
 
         let on_ground = contacts_q.get(e).map(|c| !c.is_empty()).unwrap_or(true);
         let in_air = !on_ground;
-        let speed = vel.x.abs();
-        let moving = speed > 6.0;
-        let running = speed > (RUN * 0.7);
-
-        let want = if dead {
-            clips.die.or(Some(clips.idle))
-        } else if stunned {
-            clips.stunned.or(Some(clips.idle))
-        } else if swinging {
-            if in_air {
-                if vel.y <= 0.0 {
-                    clips
-                        .attack_fall
-                        .or(clips.attack_jump)
-                        .or(Some(clips.attack_idle))
-                } else {
-                    clips
-                        .attack_jump
-                        .or(clips.attack_fall)
-                        .or(Some(clips.attack_idle))
-                }
-            } else if running {
-                clips
-                    .attack_run
-                    .or(clips.attack_walk)
-                    .or(Some(clips.attack_idle))
-            } else if moving {
-                clips.attack_walk.or(Some(clips.attack_idle))
-            } else {
-                Some(clips.attack_idle)
-            }
-        } else if in_air {
-            if vel.y > 0.0 {
-                clips.jump.or(clips.fall)
-            } else {
-                clips.fall
-            }
-            .or(Some(clips.idle))
-        } else if running {
-            clips.run.or(clips.walk).or(Some(clips.idle))
-        } else if moving {
-            clips.walk.or(Some(clips.idle))
-        } else {
-            Some(clips.idle)
+        // Mid-swing the clip follows the approach speed, not the slide
+        let speed = match swing {
+            Ok(Some(carry)) => carry.pre_speed,
+            _ => vel.x.abs(),
         };
+        let moving = speed > 6.0;
+        let running = speed > (tuning.run * 0.7);
+
+        let wanted = select_anim_state(ENEMY_ANIM_PRIORITY, |s| match s {
+            AnimState::Dead => dead,
+            AnimState::Stunned => stunned,
+            AnimState::Hurt => flinching,
+            AnimState::FallingAttack => swinging && in_air && vel.y <= 0.0,
+            AnimState::JumpingAttack => swinging && in_air,
+            AnimState::RunningAttack => swinging && running,
+            AnimState::WalkingAttack => swinging && moving,
+            AnimState::IdleAttack => swinging,
+            AnimState::Jumping => in_air && vel.y > 0.0,
+            AnimState::Falling => in_air,
+            AnimState::Running => running,
+            AnimState::Walking => moving,
+            AnimState::Idle => true,
+            AnimState::ChargedAttack | AnimState::Hanging => false,
+        });
+        let state = hold_anim_state(
+            ENEMY_ANIM_PRIORITY,
+            &mut held,
+            wanted,
+            time.delta_secs(),
+            hold.min_secs,
+        );
 
-        if let Some(id) = want {
-            if current.0 != id {
-                *anim = SpritesheetAnimation::from_id(id);
-                anim.playing = true;
+        let id = clips.clip(state);
+        if current.0 != id {
+            *anim = SpritesheetAnimation::from_id(id);
+            anim.playing = true;
 
-                // If your animation type supports non-looping, consider disabling looping
-                // for 'stunned' and 'die' here to avoid any rewind blip.
-                // Example (uncomment if your type exposes this):
-                // let non_loop = Some(id) == clips.die || Some(id) == clips.stunned;
-                // anim.repeat = !non_loop;
-                // or:
-                // anim.mode = if non_loop { AnimationMode::OnceHoldLastFrame } else { AnimationMode::Repeat };
+            // If your animation type supports non-looping, consider disabling looping
+            // for 'stunned' and 'die' here to avoid any rewind blip.
+            // Example (uncomment if your type exposes this):
+            // let non_loop = id == clips.clip(AnimState::Dead) || id == clips.clip(AnimState::Stunned);
+            // anim.repeat = !non_loop;
+            // or:
+            // anim.mode = if non_loop { AnimationMode::OnceHoldLastFrame } else { AnimationMode::Repeat };
 
-                current.0 = id;
-            }
+            current.0 = id;
         }
     }
 }
@@ -796,32 +1997,100 @@ fn tick_enemy_attack_timers(
 }
 
 // ====== Damage & impacts ======
+/// Damage that isn't a hit (ground patches and other damage over time):
+/// lowers health without the stun reaction, though it can still kill.
+/// Applied as-is; any defense is up to the sender.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DamageEnemy {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+/// Health lost to `DamageEnemy` since `react_to_enemy_health_changes` last ran.
+#[derive(Component, Default)]
+struct UnstaggeredDamage(f32);
+
+/// Melee damage after the target's defense (clamped to `0.0..=0.95`),
+/// rounded up so any hit that lands does at least 1.
+pub fn reduce_melee_damage(damage: i32, defense: f32) -> f32 {
+    let defense = defense.clamp(0.0, 0.95);
+    (damage as f32 * (1.0 - defense)).max(0.0).ceil()
+}
 
 /// Apply damage to enemies and remember the hit direction (attacker → target).
 fn apply_melee_damage_to_enemies(
     mut events: EventReader<MeleeRaycastHit>,
-    mut enemies: Query<(Entity, &mut EnemyStats, Option<&Sprite>), With<Enemy>>,
+    mut enemies: Query<
+        (
+            Entity,
+            &mut EnemyStats,
+            Option<&Sprite>,
+            Option<&mut HitFlash>,
+        ),
+        (With<Enemy>, Without<SpawnGrace>),
+    >,
     classes: Query<&EnemyClass>,
     xforms: Query<&GlobalTransform>,
+    players: Query<(), With<Player>>,
+    juice: Res<JuiceConfig>,
+    difficulty: Res<DifficultyConfig>,
     mut cmd: Commands,
 ) {
     for hit in events.read() {
-        if let Ok((e, mut stats, _sprite)) = enemies.get_mut(hit.target) {
+        if let Ok((e, mut stats, sprite, flash)) = enemies.get_mut(hit.target) {
             let defense = classes
                 .get(hit.target)
                 .map(|c| c.0.base_stats.defense)
-                .unwrap_or(0.0)
-                .clamp(0.0, 0.95);
-
-            let reduced = (hit.damage as f32) * (1.0 - defense);
-            let dmg = reduced.max(0.0).ceil();
+                .unwrap_or(0.0);
+            let mut dmg = reduce_melee_damage(hit.damage, defense);
+            if players.contains(hit.attacker) {
+                dmg = (dmg * difficulty.outgoing_damage_scale.max(0.0)).ceil();
+            }
             stats.health = (stats.health - dmg).max(0.0);
 
+            // Flash; overlapping hits just refresh the timer
+            if juice.flashes {
+                if let Some(mut f) = flash {
+                    f.timer.reset();
+                } else {
+                    cmd.entity(e).insert(HitFlash {
+                        timer: Timer::from_seconds(HIT_FLASH_SECS, TimerMode::Once),
+                        base: sprite.map(|s| s.color).unwrap_or(Color::WHITE),
+                    });
+                }
+            }
+
             // Remember direction (attacker → target), used for knockback
             if let (Ok(att_tf), Ok(tgt_tf)) = (xforms.get(hit.attacker), xforms.get(hit.target)) {
                 let d = tgt_tf.translation() - att_tf.translation();
                 let dir = Vec2::new(d.x, d.y).normalize_or_zero();
-                cmd.entity(e).insert(EnemyLastHitDir(dir));
+                cmd.entity(e).insert(EnemyLastHitDir {
+                    dir,
+                    knockback_mult: hit.knockback_mult,
+                });
+            }
+        }
+    }
+}
+
+fn apply_damage_events_to_enemies(
+    mut cmd: Commands,
+    mut events: EventReader<DamageEnemy>,
+    mut enemies: Query<
+        (&mut EnemyStats, Option<&mut UnstaggeredDamage>),
+        (With<Enemy>, Without<EnemyDead>, Without<SpawnGrace>),
+    >,
+) {
+    for ev in events.read() {
+        let Ok((mut stats, unstaggered)) = enemies.get_mut(ev.target) else {
+            continue;
+        };
+        let dmg = ev.amount.max(0.0).min(stats.health);
+        stats.health -= dmg;
+        match unstaggered {
+            Some(mut u) => u.0 += dmg,
+            None => {
+                cmd.entity(ev.target).insert(UnstaggeredDamage(dmg));
             }
         }
     }
@@ -830,37 +2099,65 @@ fn apply_melee_damage_to_enemies(
 /// React to health changes: Stun on damage; Die on <= 0.
 fn react_to_enemy_health_changes(
     mut cmd: Commands,
-    q: Query<
+    mut q: Query<
         (
             Entity,
             &EnemyStats,
             &EnemyImpactDurations,
+            &GlobalTransform,
             Option<&EnemyDead>,
+            Option<&mut Poise>,
+            Option<&mut UnstaggeredDamage>,
+            Has<TrainingDummy>,
         ),
         With<Enemy>,
     >,
     mut last: Local<HashMap<Entity, f32>>,
+    mut killed: EventWriter<EnemyKilled>,
 ) {
-    for (e, stats, impacts, is_dead) in &q {
+    for (e, stats, impacts, gt, is_dead, poise, unstaggered, dummy) in &mut q {
         let prev = last.get(&e).copied().unwrap_or(stats.health);
         last.insert(e, stats.health);
+        let ticked = unstaggered.map_or(0.0, |mut u| std::mem::take(&mut u.0));
 
         if stats.health >= prev || is_dead.is_some() {
             continue;
         }
+        // Damage over time alone doesn't stun
+        let staggering = prev - stats.health - ticked;
+        if staggering <= 0.0 && stats.health > 0.0 {
+            continue;
+        }
+
+        // Light hits just build poise damage until it breaks
+        if let Some(mut poise) = poise.filter(|_| stats.health > 0.0) {
+            poise.damage += staggering;
+            if poise.damage < poise.threshold {
+                cmd.entity(e)
+                    .insert(Flinch(Timer::from_seconds(impacts.hurt, TimerMode::Once)));
+                continue;
+            }
+            poise.damage = 0.0;
+        }
 
         // Disable hitbox while stunned/dead
-        cmd.entity(e).remove::<MeleeAttackActive>();
+        cmd.entity(e).remove::<(MeleeAttackActive, Flinch)>();
 
         if stats.health <= 0.0 {
             cmd.entity(e)
                 .remove::<EnemyStunned>()
-                .remove::<EnemyStunTimer>()
+                .remove::<(EnemyStunTimer, KnockbackTrack)>()
                 .insert(EnemyDead)
                 .insert(EnemyDeathTimer(Timer::from_seconds(
                     impacts.die,
                     TimerMode::Once,
                 )));
+            if !dummy {
+                killed.write(EnemyKilled {
+                    enemy: e,
+                    pos: gt.translation().truncate(),
+                });
+            }
         } else {
             // Enter stun; knockback applied on Added<EnemyStunned>
             cmd.entity(e)
@@ -873,8 +2170,10 @@ fn react_to_enemy_health_changes(
     }
 }
 
-/// Apply knockback velocity on stun enter.
+/// Apply knockback velocity on stun enter. Vertical pop scales with how much
+/// the hit points upward, so flat hits don't launch grounded enemies.
 fn on_added_enemy_stunned_knockback(
+    world: Res<WorldScale>,
     mut q: Query<
         (
             Entity,
@@ -882,13 +2181,13 @@ fn on_added_enemy_stunned_knockback(
             Option<&EnemyLastHitDir>,
             Option<&Sprite>,
         ),
-        Added<EnemyStunned>,
+        (Added<EnemyStunned>, Without<KnockbackImmune>),
     >,
     classes: Query<&EnemyClass>,
 ) {
     for (e, mut vel, last_hit, sprite) in &mut q {
         let dir = if let Some(d) = last_hit {
-            d.0
+            d.dir
         } else {
             let facing_right = sprite.map(|s| !s.flip_x).unwrap_or(true);
             if facing_right {
@@ -897,6 +2196,7 @@ fn on_added_enemy_stunned_knockback(
                 Vec2::new(1.0, 0.2)
             }
         };
+        let strength = last_hit.map(|d| d.knockback_mult).unwrap_or(1.0);
 
         let x_sign = if dir.x.abs() >= 0.1 {
             dir.x.signum()
@@ -912,39 +2212,344 @@ fn on_added_enemy_stunned_knockback(
             .clamp(0.0, 0.95);
         let mult = 1.0 - resist;
 
-        vel.x = x_sign * ENEMY_KNOCKBACK_SPEED * mult;
-        vel.y = vel.y.max(ENEMY_KNOCKBACK_POP * mult);
+        let lift = ((dir.y - ENEMY_KNOCKBACK_LIFT_DEADZONE)
+            / (1.0 - ENEMY_KNOCKBACK_LIFT_DEADZONE))
+            .clamp(0.0, 1.0);
+
+        vel.x = x_sign * world.len(ENEMY_KNOCKBACK_SPEED) * mult * strength;
+        if lift > 0.0 {
+            vel.y = vel.y.max(world.len(ENEMY_KNOCKBACK_POP) * lift * mult);
+        }
     }
 }
 
-/// Tick stun/death timers. End stun; despawn on death finish.
+/// Refill dummy health once it recovers, and drift it back home.
+fn tend_training_dummies(
+    mut q: Query<
+        (
+            &TrainingDummy,
+            &mut EnemyStats,
+            &mut LinearVelocity,
+            &GlobalTransform,
+        ),
+        Without<EnemyStunned>,
+    >,
+    tuning: Res<EnemyTuning>,
+) {
+    for (dummy, mut stats, mut vel, gt) in &mut q {
+        if stats.health < stats.max_health {
+            stats.health = stats.max_health;
+        }
+
+        let dx = dummy.home.x - gt.translation().x;
+        vel.x = if dx.abs() <= DUMMY_HOME_EPSILON {
+            0.0
+        } else {
+            (dx * DUMMY_RETURN_GAIN).clamp(-tuning.walk, tuning.walk)
+        };
+    }
+}
+
+fn tick_enemy_drop_through(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut q: Query<(
+        Entity,
+        &mut EnemyDropThrough,
+        &mut PassThroughOneWayPlatform,
+    )>,
+) {
+    for (e, mut drop, mut pass) in &mut q {
+        drop.0.tick(time.delta());
+        if drop.0.finished() {
+            *pass = PassThroughOneWayPlatform::ByNormal;
+            cmd.entity(e).remove::<EnemyDropThrough>();
+        }
+    }
+}
+
+/// Fade freshly spawned enemies in over their grace period.
+fn tick_spawn_grace(time: Res<Time>, mut cmd: Commands, mut q: Query<(Entity, &mut SpawnGrace)>) {
+    for (e, mut grace) in &mut q {
+        if grace.0.tick(time.delta()).finished() {
+            cmd.entity(e).remove::<SpawnGrace>();
+        }
+    }
+}
+
+/// Launcher hits pop enemies up into `Juggled`; follow-up hits on a juggled
+/// enemy keep it aloft. Regular hits on grounded enemies are left to the
+/// stun knockback.
+fn launch_enemies_from_hits(
+    mut cmd: Commands,
+    mut events: EventReader<MeleeRaycastHit>,
+    mut q: Query<
+        (&mut LinearVelocity, Option<&mut Juggled>),
+        (
+            With<Enemy>,
+            Without<EnemyDead>,
+            Without<SpawnGrace>,
+            Without<KnockbackImmune>,
+        ),
+    >,
+    classes: Query<&EnemyClass>,
+    world: Res<WorldScale>,
+) {
+    for hit in events.read() {
+        let Ok((mut vel, juggled)) = q.get_mut(hit.target) else {
+            continue;
+        };
+        let pop = if hit.launch > 0.0 {
+            hit.launch
+        } else if juggled.is_some() {
+            world.len(JUGGLE_REPOP)
+        } else {
+            continue;
+        };
+        let resist = classes
+            .get(hit.target)
+            .map(|c| c.0.base_stats.knockback_resist)
+            .unwrap_or(0.0)
+            .clamp(0.0, 0.95);
+        vel.y = vel.y.max(pop * (1.0 - resist));
+
+        match juggled {
+            Some(mut j) => j.0.reset(),
+            None => {
+                cmd.entity(hit.target).insert(Juggled(Timer::from_seconds(
+                    JUGGLE_MIN_SECS,
+                    TimerMode::Once,
+                )));
+            }
+        }
+    }
+}
+
+/// Juggle ends once the minimum time has passed and the enemy has landed.
+fn tick_juggled(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut q: Query<(Entity, &mut Juggled, &CollidingEntities, &LinearVelocity)>,
+) {
+    for (e, mut j, contacts, vel) in &mut q {
+        j.0.tick(time.delta());
+        if j.0.finished() && !contacts.is_empty() && vel.y <= 0.0 {
+            cmd.entity(e).remove::<Juggled>();
+        }
+    }
+}
+
+fn tick_enemy_hit_flash(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut q: Query<(Entity, &mut HitFlash, &mut Sprite)>,
+    palette: Res<Palette>,
+) {
+    for (e, mut f, mut sprite) in &mut q {
+        f.timer.tick(time.delta());
+        if f.timer.finished() {
+            sprite.color = f.base;
+            cmd.entity(e).remove::<HitFlash>();
+        } else {
+            sprite.color = palette.hit_flash;
+        }
+    }
+}
+
+fn tick_flinch(time: Res<Time>, mut cmd: Commands, mut q: Query<(Entity, &mut Flinch)>) {
+    for (e, mut flinch) in &mut q {
+        if flinch.0.tick(time.delta()).finished() {
+            cmd.entity(e).remove::<Flinch>();
+        }
+    }
+}
+
+/// Tick stun/death timers. End stun; on death finish, explode (if the enemy
+/// carries `ExplodesOnDeath`) and despawn, or leave a `Corpse`.
 fn tick_enemy_impact_timers(
     time: Res<Time>,
+    corpses: Res<CorpseConfig>,
     mut cmd: Commands,
     mut stuns: Query<(Entity, &mut EnemyStunTimer), With<EnemyStunned>>,
-    mut deaths: Query<(Entity, &mut EnemyDeathTimer), With<EnemyDead>>,
+    mut deaths: Query<
+        (
+            Entity,
+            &mut EnemyDeathTimer,
+            &GlobalTransform,
+            Option<&ExplodesOnDeath>,
+        ),
+        With<EnemyDead>,
+    >,
+    spatial: SpatialQuery,
+    players: Query<(), With<Player>>,
+    mut damage: EventWriter<DamagePlayer>,
+    mut fx_pool: ResMut<Pool<EffectSprite>>,
 ) {
     for (e, mut t) in &mut stuns {
         t.0.tick(time.delta());
         if t.0.finished() {
             cmd.entity(e)
                 .remove::<EnemyStunned>()
-                .remove::<EnemyStunTimer>();
+                .remove::<(EnemyStunTimer, KnockbackTrack)>();
         }
     }
-    for (e, mut t) in &mut deaths {
+    for (e, mut t, gt, boom) in &mut deaths {
         t.0.tick(time.delta());
         if t.0.finished() {
+            if let Some(boom) = boom {
+                let pos = gt.translation();
+                detonate(pos.truncate(), boom, &spatial, &players, &mut damage);
+                play_effect(
+                    &mut cmd,
+                    &mut fx_pool,
+                    pos + Vec3::new(0.0, 0.0, 102.0),
+                    EXPLOSION_COLOR,
+                    Vec2::splat(boom.radius * 0.5),
+                    3.0,
+                    0.3,
+                );
+            } else if corpses.linger > 0.0 && corpses.max_corpses > 0 {
+                cmd.entity(e)
+                    .remove::<EnemyDeathTimer>()
+                    .insert(Corpse::default());
+                continue;
+            }
+            cmd.entity(e).despawn();
+        }
+    }
+}
+
+/// Age corpses out after `linger`, and despawn the oldest over the cap.
+fn tidy_corpses(
+    time: Res<Time>,
+    config: Res<CorpseConfig>,
+    mut cmd: Commands,
+    mut q: Query<(Entity, &mut Corpse)>,
+) {
+    let mut kept = Vec::new();
+    for (e, mut corpse) in &mut q {
+        corpse.age += time.delta_secs();
+        if corpse.age >= config.linger {
             cmd.entity(e).despawn();
+        } else {
+            kept.push((e, corpse.age));
+        }
+    }
+    if kept.len() <= config.max_corpses {
+        return;
+    }
+    // Youngest first; everything past the cap goes
+    kept.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (e, _) in kept.drain(config.max_corpses..) {
+        cmd.entity(e).despawn();
+    }
+}
+
+/// Whether `collider` is level geometry: a static body itself, or attached
+/// to one.
+fn is_static_collider(
+    collider: Entity,
+    colliders: &Query<(Option<&RigidBody>, Option<&ColliderOf>)>,
+    bodies: &Query<&RigidBody>,
+) -> bool {
+    colliders.get(collider).is_ok_and(|(own, of)| {
+        let body = own.or_else(|| of.and_then(|of| bodies.get(of.body).ok()));
+        matches!(body, Some(RigidBody::Static))
+    })
+}
+
+/// Stunned enemies knocked hard into a static wall take bonus damage, stay
+/// stunned longer and kick up dust where they hit.
+fn detect_wall_splats(
+    mut cmd: Commands,
+    collisions: Collisions,
+    mut q: Query<
+        (
+            Entity,
+            &LinearVelocity,
+            &GlobalTransform,
+            &mut EnemyStunTimer,
+            Option<&mut KnockbackTrack>,
+        ),
+        (With<EnemyStunned>, Without<EnemyDead>),
+    >,
+    colliders: Query<(Option<&RigidBody>, Option<&ColliderOf>)>,
+    bodies: Query<&RigidBody>,
+    mut damage: EventWriter<DamageEnemy>,
+    mut fx_pool: ResMut<Pool<EffectSprite>>,
+) {
+    for (e, vel, gt, mut stun, track) in &mut q {
+        let Some(mut track) = track else {
+            cmd.entity(e).insert(KnockbackTrack {
+                last_vx: vel.x,
+                splatted: false,
+            });
+            continue;
+        };
+        let before = track.last_vx;
+        track.last_vx = vel.x;
+        if track.splatted
+            || before.abs() < WALL_SPLAT_MIN_SPEED
+            || vel.x.abs() > before.abs() * (1.0 - WALL_SPLAT_SPEED_LOSS)
+        {
+            continue;
+        }
+        // Only static geometry facing back against the flight counts (not the floor)
+        let hit_wall = collisions.collisions_with(e).any(|pair| {
+            let (other, flip) = if pair.collider1 == e {
+                (pair.collider2, -1.0)
+            } else {
+                (pair.collider1, 1.0)
+            };
+            is_static_collider(other, &colliders, &bodies)
+                && pair.manifolds.iter().any(|m| {
+                    let n = m.normal * flip;
+                    n.x.abs() >= WALL_SPLAT_MIN_NORMAL_X && n.x * before < 0.0
+                })
+        });
+        if !hit_wall {
+            continue;
         }
+        track.splatted = true;
+
+        let extended = stun.0.duration().as_secs_f32() + WALL_SPLAT_EXTRA_STUN;
+        stun.0
+            .set_duration(std::time::Duration::from_secs_f32(extended));
+        damage.write(DamageEnemy {
+            target: e,
+            amount: WALL_SPLAT_DAMAGE,
+        });
+        // Dust on the side that hit the wall
+        let side = before.signum();
+        play_effect(
+            &mut cmd,
+            &mut fx_pool,
+            gt.translation() + Vec3::new(side * 10.0, 0.0, 1.0),
+            WALL_SPLAT_DUST_COLOR,
+            Vec2::splat(14.0),
+            2.0,
+            0.3,
+        );
     }
 }
 
 fn on_added_enemy_dead_make_passive(
     mut cmd: Commands,
     mut q: Query<(Entity, &mut Transform, &mut LinearVelocity), Added<EnemyDead>>,
+    mut fx_pool: ResMut<Pool<EffectSprite>>,
 ) {
     for (e, mut t, mut vel) in &mut q {
+        // Death puff (pooled, no spawn/despawn churn)
+        play_effect(
+            &mut cmd,
+            &mut fx_pool,
+            t.translation + Vec3::new(0.0, 4.0, 1.0),
+            Color::srgba(0.8, 0.8, 0.85, 0.7),
+            Vec2::splat(18.0),
+            1.5,
+            0.35,
+        );
+
         // Visual: sit behind the player a bit
         t.translation.z -= 101.0;
 
@@ -960,16 +2565,279 @@ fn on_added_enemy_dead_make_passive(
     }
 }
 
+fn on_enemy_class_added_attach_traits(
+    mut cmd: Commands,
+    q: Query<(Entity, &EnemyClass), Added<EnemyClass>>,
+) {
+    for (e, class) in &q {
+        let stats = &class.0.base_stats;
+        if let Some(boom) = stats.explodes_on_death {
+            cmd.entity(e).insert(boom);
+        }
+        if let Some(contact) = stats.contact_damage {
+            cmd.entity(e).insert(contact);
+        }
+        if let Some(dash) = stats.dash_attack {
+            cmd.entity(e).insert(dash);
+        }
+        if let Some(summon) = stats.summons_help {
+            cmd.entity(e).insert(summon);
+        }
+        if let Some(set) = &stats.attack_set {
+            cmd.entity(e).insert(set.clone());
+        }
+        if let Some(trail) = stats.ground_trail {
+            cmd.entity(e).insert(trail);
+        }
+        if let Some(grab) = stats.grab_attack {
+            cmd.entity(e).insert(grab);
+        }
+        if stats.knockback_immune {
+            cmd.entity(e).insert(KnockbackImmune);
+        }
+        if stats.poise > 0.0 {
+            cmd.entity(e).insert(Poise {
+                threshold: stats.poise,
+                damage: 0.0,
+            });
+        }
+    }
+}
+
+fn decay_poise(time: Res<Time>, mut q: Query<&mut Poise>) {
+    let shed = POISE_DECAY_PER_S * time.delta_secs();
+    for mut poise in &mut q {
+        if poise.damage > 0.0 {
+            poise.damage = (poise.damage - shed).max(0.0);
+        }
+    }
+}
+
+/// Touching a `ContactDamage` enemy hurts and knocks the player away from it.
+fn apply_contact_damage(
+    time: Res<Time>,
+    mut cmd: Commands,
+    mut damage: EventWriter<DamagePlayer>,
+    player: Query<Entity, With<Player>>,
+    mut q: Query<
+        (
+            Entity,
+            &ContactDamage,
+            &GlobalTransform,
+            &CollidingEntities,
+            Option<&mut ContactDamageCooldown>,
+        ),
+        (With<Enemy>, Without<EnemyDead>, Without<SpawnGrace>),
+    >,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    for (e, contact, gt, touching, cooldown) in &mut q {
+        if let Some(mut cd) = cooldown {
+            cd.0.tick(time.delta());
+            if !cd.0.finished() {
+                continue;
+            }
+        }
+        if !touching.contains(&player) {
+            continue;
+        }
+        damage.write(DamagePlayer::hit(
+            contact.damage,
+            Some(gt.translation().truncate()),
+        ));
+        cmd.entity(e)
+            .insert(ContactDamageCooldown(Timer::from_seconds(
+                contact.cooldown,
+                TimerMode::Once,
+            )));
+    }
+}
+
+/// A grappler's close hit on a free player turns into a hold. The grappler
+/// doesn't swing again until the hold could have run out.
+fn grab_player_on_hit(
+    mut cmd: Commands,
+    mut hits: EventReader<MeleeRaycastHit>,
+    grabbers: Query<&GrabAttack, (Without<EnemyDead>, Without<EnemyStunned>)>,
+    players: Query<(), (With<Player>, Without<Grabbed>)>,
+) {
+    for hit in hits.read() {
+        let Ok(grab) = grabbers.get(hit.attacker) else {
+            continue;
+        };
+        if !grab.reaches(hit.distance) || !players.contains(hit.target) {
+            continue;
+        }
+        cmd.entity(hit.target)
+            .insert(Grabbed::new(hit.attacker, grab));
+        cmd.entity(hit.attacker)
+            .insert(EnemyAttackCooldown(Timer::from_seconds(
+                grab.hold,
+                TimerMode::Once,
+            )));
+    }
+}
+
+/// The player mashed free: the grappler reels back as if hit.
+fn stagger_grabber_on_break(
+    mut cmd: Commands,
+    mut broken: EventReader<GrabBroken>,
+    q: Query<(&GlobalTransform, &EnemyImpactDurations), Without<EnemyDead>>,
+) {
+    for ev in broken.read() {
+        let Ok((gt, impacts)) = q.get(ev.grabber) else {
+            continue;
+        };
+        let dir = (gt.translation().truncate() - ev.from).normalize_or_zero();
+        cmd.entity(ev.grabber)
+            .remove::<(MeleeAttackActive, Flinch)>()
+            .insert(EnemyLastHitDir {
+                dir,
+                knockback_mult: 1.0,
+            })
+            .insert(EnemyStunned)
+            .insert(EnemyStunTimer(Timer::from_seconds(
+                impacts.stun,
+                TimerMode::Once,
+            )));
+    }
+}
+
+/// Telegraph the blast for the whole death timer.
+fn on_added_enemy_dead_arm_explosion(
+    mut cmd: Commands,
+    q: Query<(Entity, &ExplodesOnDeath), Added<EnemyDead>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    palette: Res<Palette>,
+) {
+    for (e, boom) in &q {
+        let ring = cmd
+            .spawn((
+                ExplosionTelegraph {
+                    radius: boom.radius,
+                },
+                Mesh2d(meshes.add(Annulus::new(0.9, 1.0))),
+                MeshMaterial2d(materials.add(palette.danger)),
+                Transform::from_xyz(0.0, 0.0, 1.0).with_scale(Vec3::ZERO),
+                Name::new("ExplosionTelegraph"),
+            ))
+            .id();
+        cmd.entity(e).add_child(ring);
+    }
+}
+
+fn animate_explosion_telegraph(
+    juice: Res<JuiceConfig>,
+    deaths: Query<&EnemyDeathTimer>,
+    mut rings: Query<(
+        &ExplosionTelegraph,
+        &ChildOf,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+) {
+    for (ring, child_of, mut t, mut vis) in &mut rings {
+        let Ok(timer) = deaths.get(child_of.parent()) else {
+            continue;
+        };
+        let f = timer.0.fraction();
+        let r = ring.radius * (0.25 + 0.75 * f);
+        t.scale = Vec3::new(r, r, 1.0);
+
+        // Blink faster as the fuse runs out; steady with flashes off
+        let hz = 3.0 + 12.0 * f;
+        *vis = if !juice.flashes || (timer.0.elapsed_secs() * hz).fract() < 0.6 {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Hurt (and knock back) the player if caught in the blast.
+fn detonate(
+    pos: Vec2,
+    boom: &ExplodesOnDeath,
+    spatial: &SpatialQuery,
+    players: &Query<(), With<Player>>,
+    damage: &mut EventWriter<DamagePlayer>,
+) {
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Player));
+    for hit in spatial.shape_intersections(&Collider::circle(boom.radius), pos, 0.0, &filter) {
+        if players.get(hit).is_ok() {
+            damage.write(DamagePlayer::hit(boom.damage, Some(pos)));
+        }
+    }
+}
+
+/// Keep melee reach in step with `EnemyTuning::range` (new spawns and live edits).
+fn sync_enemy_reach(tuning: Res<EnemyTuning>, mut q: Query<&mut MeleeRaycastSpec, With<Enemy>>) {
+    for mut spec in &mut q {
+        if (tuning.is_changed() || spec.is_added()) && spec.length != tuning.range {
+            spec.length = tuning.range;
+        }
+    }
+}
+
+/// Visual tuning aid: aggro circle (at the current noise) plus the attack
+/// band (scorer width) and ray reach.
+fn draw_enemy_tuning_bands(
+    tuning: Res<EnemyTuning>,
+    noise: Res<PlayerNoise>,
+    palette: Res<Palette>,
+    mut gizmos: Gizmos,
+    q: Query<&GlobalTransform, (With<Enemy>, Without<EnemyDead>, Without<TrainingDummy>)>,
+) {
+    for gt in &q {
+        let p = gt.translation().truncate();
+        gizmos.circle_2d(
+            p,
+            tuning.aggro * noise.0.max(0.0),
+            palette.danger.with_alpha(0.35),
+        );
+        gizmos.rect_2d(
+            p,
+            Vec2::new(tuning.attack_band_x * 2.0, 48.0),
+            palette.danger.with_alpha(0.7),
+        );
+        gizmos.line_2d(
+            p - Vec2::X * tuning.range,
+            p + Vec2::X * tuning.range,
+            palette.heal.with_alpha(0.8),
+        );
+    }
+}
+
 // ====== Plugin wiring ======
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(BigBrainPlugin::new(PreUpdate))
+            .add_event::<EnemyKilled>()
+            .add_event::<DamageEnemy>()
+            .init_resource::<EnemyTuning>()
+            .init_resource::<EnemySeparation>()
+            .register_type::<EnemySeparation>()
+            .init_resource::<NoiseLevels>()
+            .register_type::<NoiseLevels>()
+            .init_resource::<PlayerNoise>()
+            .register_type::<PlayerNoise>()
+            .init_resource::<PerceptionSchedule>()
+            .register_type::<PerceptionSchedule>()
+            .init_resource::<CorpseConfig>()
+            .register_type::<CorpseConfig>()
             // 1) Perception & facing in-order BEFORE scorers (register once)
             .add_systems(
                 PreUpdate,
-                (sense_player, face_by_target_or_velocity)
+                (
+                    update_player_noise,
+                    sense_player,
+                    face_by_target_or_velocity,
+                )
                     .chain()
                     .before(BigBrainSet::Scorers),
             )
@@ -979,9 +2847,15 @@ impl Plugin for EnemyPlugin {
                 (
                     has_target_scorer.in_set(BigBrainSet::Scorers),
                     attack_in_range_scorer.in_set(BigBrainSet::Scorers),
+                    dash_in_range_scorer.in_set(BigBrainSet::Scorers),
+                    away_from_home_scorer.in_set(BigBrainSet::Scorers),
                     patrol_action.in_set(BigBrainSet::Actions),
                     chase_action.in_set(BigBrainSet::Actions),
                     attack_action.in_set(BigBrainSet::Actions),
+                    dash_action.in_set(BigBrainSet::Actions),
+                    returning_action.in_set(BigBrainSet::Actions),
+                    separate_enemies.after(BigBrainSet::Actions),
+                    block_steep_contacts.after(separate_enemies),
                 ),
             )
             // 3) Regular update helpers
@@ -992,9 +2866,37 @@ impl Plugin for EnemyPlugin {
                     on_enemy_added_attach_sprite_and_anims,
                     drive_enemy_animation,
                     on_enemy_class_added_set_hp,
+                    on_enemy_class_added_attach_traits,
                     apply_melee_damage_to_enemies,
+                    apply_contact_damage,
+                    apply_damage_events_to_enemies.before(react_to_enemy_health_changes),
                     react_to_enemy_health_changes,
+                    tick_spawn_grace,
+                    tick_flinch,
+                    tick_enemy_drop_through,
+                    tick_enemy_hit_flash,
                     tick_enemy_impact_timers,
+                    tidy_corpses.after(tick_enemy_impact_timers),
+                    // After the stun lands, so the hit still reads before the refill
+                    tend_training_dummies.after(react_to_enemy_health_changes),
+                    animate_explosion_telegraph,
+                    sync_enemy_reach,
+                    draw_enemy_tuning_bands.run_if(|t: Res<EnemyTuning>| t.show_bands),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    launch_enemies_from_hits.after(apply_melee_damage_to_enemies),
+                    tick_juggled,
+                    tick_dash_cooldowns,
+                    start_summon_on_sight,
+                    finish_summons,
+                    tick_summon_cooldowns,
+                    decay_poise.before(react_to_enemy_health_changes),
+                    detect_wall_splats.before(apply_damage_events_to_enemies),
+                    grab_player_on_hit.in_set(RaycastMeleeSet::ApplyDamage),
+                    stagger_grabber_on_break,
                 ),
             )
             // 4) PostUpdate: apply stun knockback on tag add
@@ -1003,7 +2905,76 @@ impl Plugin for EnemyPlugin {
                 (
                     on_added_enemy_stunned_knockback,
                     on_added_enemy_dead_make_passive,
+                    on_added_enemy_dead_arm_explosion,
                 ),
-            );
+            )
+            .register_type::<ExplodesOnDeath>()
+            .register_type::<ContactDamage>()
+            .register_type::<DashAttack>()
+            .register_type::<GrabAttack>()
+            .register_type::<SummonsHelp>()
+            .register_type::<EnemyAttackSet>()
+            .register_type::<EnemyStats>()
+            .register_type::<Poise>()
+            .register_type::<KnockbackImmune>()
+            .register_type::<EnemySenses>()
+            .register_type::<EnemyTuning>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EnemyAttackDef, EnemyAttackSet, reduce_melee_damage};
+    use crate::rng::GameRng;
+
+    #[test]
+    fn defense_reduces_and_rounds_up() {
+        assert_eq!(reduce_melee_damage(20, 0.0), 20.0);
+        assert_eq!(reduce_melee_damage(20, 0.25), 15.0);
+        // 6.7 -> 7
+        assert_eq!(reduce_melee_damage(10, 0.33), 7.0);
+    }
+
+    #[test]
+    fn defense_is_clamped() {
+        // Capped at 95%: a big hit still gets through
+        assert_eq!(
+            reduce_melee_damage(100, 2.0),
+            reduce_melee_damage(100, 0.95)
+        );
+        assert!(reduce_melee_damage(100, 2.0) > 0.0);
+        assert_eq!(reduce_melee_damage(1, 1.0), 1.0);
+        // Negative defense doesn't amplify damage
+        assert_eq!(reduce_melee_damage(20, -0.5), 20.0);
+    }
+
+    #[test]
+    fn negative_damage_does_nothing() {
+        assert_eq!(reduce_melee_damage(-5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn attack_set_only_picks_in_range() {
+        let set = EnemyAttackSet {
+            attacks: vec![
+                EnemyAttackDef {
+                    name: "jab".into(),
+                    max_range: 20.0,
+                    ..Default::default()
+                },
+                EnemyAttackDef {
+                    name: "heavy".into(),
+                    min_range: 20.0,
+                    max_range: 40.0,
+                    ..Default::default()
+                },
+            ],
+        };
+        let mut rng = GameRng::new(Some(7));
+        for _ in 0..32 {
+            assert_eq!(set.pick(5.0, &mut rng).unwrap().name, "jab");
+            assert_eq!(set.pick(30.0, &mut rng).unwrap().name, "heavy");
+        }
+        assert!(set.pick(50.0, &mut rng).is_none());
     }
 }
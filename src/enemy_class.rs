@@ -1,6 +1,13 @@
 // enemy_class.rs
+use crate::enemy::{
+    ContactDamage, DashAttack, EnemyAttackSet, ExplodesOnDeath, GrabAttack, SummonsHelp,
+};
+use crate::ground_effect::GroundTrail;
 use crate::prelude::*;
+use crate::status::StatusOnHit;
 use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Top-level enemy class file loaded from JSON.
 #[derive(Debug, Clone, Deserialize, Reflect, Resource)]
@@ -35,6 +42,78 @@ pub struct EnemyBaseStats {
     pub projectile_speed: f32,
     pub stamina_max: f32,
     pub stamina_regen_per_s: f32,
+    /// Melee hits from this class apply a damage-over-time status.
+    #[serde(default)]
+    pub on_hit_status: Option<StatusOnHit>,
+    /// Bomber: blows up at the end of the death timer.
+    #[serde(default)]
+    pub explodes_on_death: Option<ExplodesOnDeath>,
+    /// Spiky bodies: touching the enemy hurts.
+    #[serde(default)]
+    pub contact_damage: Option<ContactDamage>,
+    /// Aggressive closers: dash at the player from medium range.
+    #[serde(default)]
+    pub dash_attack: Option<DashAttack>,
+    /// Calls in reinforcements on first sight of the player.
+    #[serde(default)]
+    pub summons_help: Option<SummonsHelp>,
+    /// Damage it takes (within a short window) to stun; 0 flinches on every hit.
+    #[serde(default)]
+    pub poise: f32,
+    /// Still stuns and flashes, but hits never move it (brutes, bosses).
+    #[serde(default)]
+    pub knockback_immune: bool,
+    /// Several distinct swings (jab, heavy, ...) picked by range and weight.
+    #[serde(default)]
+    pub attack_set: Option<EnemyAttackSet>,
+    /// Leaves damaging patches behind while moving (fire trails).
+    #[serde(default)]
+    pub ground_trail: Option<GroundTrail>,
+    /// Grapplers: close hits hold the player until they mash free.
+    #[serde(default)]
+    pub grab_attack: Option<GrabAttack>,
+}
+
+/// Built-in class used when the default enemy JSON is missing or malformed.
+impl Default for EnemyClassFile {
+    fn default() -> Self {
+        Self {
+            id: "default_enemy".into(),
+            display_name: "Enemy".into(),
+            tags: Vec::new(),
+            attribute_start: EnemyAttributes {
+                might: 3,
+                agility: 3,
+                focus: 3,
+                grit: 3,
+            },
+            base_stats: EnemyBaseStats {
+                max_health: 60,
+                defense: 0.0,
+                knockback_resist: 0.0,
+                melee_power: 1.0,
+                spell_power: 1.0,
+                knockback: 1.0,
+                move_speed: 1.0,
+                crit_chance: 0.0,
+                crit_multiplier: 1.5,
+                attack_cooldown_reduction: 0.0,
+                projectile_speed: 1.0,
+                stamina_max: 50.0,
+                stamina_regen_per_s: 5.0,
+                on_hit_status: None,
+                explodes_on_death: None,
+                contact_damage: None,
+                dash_attack: None,
+                summons_help: None,
+                poise: 0.0,
+                knockback_immune: false,
+                attack_set: None,
+                ground_trail: None,
+                grab_attack: None,
+            },
+        }
+    }
 }
 
 /// Tag any enemy entity you want this EnemyClass attached to.
@@ -42,6 +121,46 @@ pub struct EnemyBaseStats {
 #[reflect(Component)]
 pub struct EnemyClassAttachTarget;
 
+/// Optional per-enemy class id (e.g. from a Tiled marker). Resolved through
+/// `EnemyClassLibrary`; enemies without it get the default class.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct EnemyClassId(pub String);
+
+/// All enemy classes loaded so far, keyed by `id`. Unknown ids are looked up
+/// as `<id>.json` next to the default class file, once; ids with no usable
+/// file are remembered so they only warn the first time.
+#[derive(Resource, Default)]
+pub struct EnemyClassLibrary {
+    pub classes: HashMap<String, EnemyClassFile>,
+    missing: HashSet<String>,
+}
+
+impl EnemyClassLibrary {
+    fn resolve(&mut self, id: &str, default_path: &str) -> Option<&EnemyClassFile> {
+        if self.missing.contains(id) {
+            return None;
+        }
+        if !self.classes.contains_key(id) {
+            let path = Path::new(default_path).with_file_name(format!("{id}.json"));
+            let loaded = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|json| serde_json::from_str::<EnemyClassFile>(&json).ok());
+            match loaded {
+                Some(class_file) => {
+                    self.classes.insert(id.to_string(), class_file);
+                }
+                None => {
+                    warn!("EnemyClassLibrary: no class '{id}' at {}", path.display());
+                    self.missing.insert(id.to_string());
+                    return None;
+                }
+            }
+        }
+        self.classes.get(id)
+    }
+}
+
 /// Component attached to enemies with their class data.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -76,7 +195,9 @@ impl EnemyClassPlugin {
 impl Plugin for EnemyClassPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(self.config.clone())
+            .init_resource::<EnemyClassLibrary>()
             .register_type::<EnemyClassAttachTarget>()
+            .register_type::<EnemyClassId>()
             .register_type::<EnemyClass>()
             .register_type::<EnemyClassFile>()
             .register_type::<EnemyAttributes>()
@@ -89,15 +210,26 @@ impl Plugin for EnemyClassPlugin {
     }
 }
 
-fn load_enemy_class_from_json(mut commands: Commands, cfg: Res<EnemyClassPluginConfig>) {
+fn load_enemy_class_from_json(
+    mut commands: Commands,
+    cfg: Res<EnemyClassPluginConfig>,
+    mut library: ResMut<EnemyClassLibrary>,
+) {
     let path = &cfg.path;
-    let json = std::fs::read_to_string(path).unwrap_or_else(|e| {
-        panic!("Failed to read enemy class JSON at {path}: {e}");
-    });
-    let class_file: EnemyClassFile = serde_json::from_str(&json).unwrap_or_else(|e| {
-        panic!("Invalid enemy class JSON format for {path}: {e}");
-    });
+    let class_file = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {path}: {e}"))
+        .and_then(|json| {
+            serde_json::from_str::<EnemyClassFile>(&json)
+                .map_err(|e| format!("invalid enemy class JSON in {path}: {e}"))
+        })
+        .unwrap_or_else(|e| {
+            error!("EnemyClassPlugin: {e}; using the built-in default class");
+            EnemyClassFile::default()
+        });
 
+    library
+        .classes
+        .insert(class_file.id.clone(), class_file.clone());
     commands.insert_resource(class_file);
 }
 
@@ -125,13 +257,19 @@ fn maybe_spawn_debug_holder(
 
 fn attach_enemy_class_to_targets(
     class_file: Option<Res<EnemyClassFile>>,
+    cfg: Res<EnemyClassPluginConfig>,
+    mut library: ResMut<EnemyClassLibrary>,
     mut commands: Commands,
-    q_targets: Query<(Entity, Option<&EnemyClass>), With<EnemyClassAttachTarget>>,
+    q_targets: Query<
+        (Entity, Option<&EnemyClassId>),
+        (With<EnemyClassAttachTarget>, Without<EnemyClass>),
+    >,
 ) {
     let Some(class_file) = class_file else { return };
-    for (e, maybe_existing) in &q_targets {
-        if maybe_existing.is_none() {
-            commands.entity(e).insert(EnemyClass(class_file.clone()));
-        }
+    for (e, class_id) in &q_targets {
+        let resolved = class_id
+            .and_then(|id| library.resolve(&id.0, &cfg.path))
+            .unwrap_or(&*class_file);
+        commands.entity(e).insert(EnemyClass(resolved.clone()));
     }
 }
@@ -2,41 +2,125 @@
 use bevy::math::Dir2;
 use bevy::prelude::*;
 use bevy_ecs_tiled::prelude::*;
-use rand::{Rng, rng};
+use rand::Rng;
 
 use avian2d::collision::collider::LayerMask;
 use avian2d::spatial_query::{SpatialQuery, SpatialQueryFilter};
 
-use crate::character::GameLayer; // your PhysicsLayer enum from character.rs
-use crate::enemy::spawn_enemy; // your existing enemy spawner function
+use crate::camera::MainCamera;
+use crate::character::{GameLayer, Player};
+use crate::enemy::{Corpse, Enemy, TrainingDummy, spawn_enemy};
+use crate::enemy_class::{EnemyClass, EnemyClassId};
+use crate::rng::GameRng;
 
 /// Configuration + timer for periodic enemy spawns.
+/// Level-authored enemies come from Tiled markers; this is the optional
+/// endless/random source on top of those.
 #[derive(Resource)]
 pub struct EnemySpawner {
+    pub enabled: bool,
     pub timer: Timer,
     pub attempts_per_tick: u32,
     pub ray_down: f32,
     pub y_above_ground: f32,
     pub _patrol_span: f32,
     pub spawn_z: f32, // ← add this
+    /// Candidates closer than this to the player are rejected outright.
+    pub min_player_distance: f32,
+    /// Reject candidates inside the camera view; a visible one is only
+    /// used if no attempt this tick lands off-screen.
+    pub prefer_offscreen: bool,
 }
 
 impl Default for EnemySpawner {
     fn default() -> Self {
         Self {
+            enabled: true,
             timer: Timer::from_seconds(5.0, TimerMode::Repeating),
             attempts_per_tick: 8,
             ray_down: 2000.0,
             y_above_ground: 8.0,
             _patrol_span: 100.0,
             spawn_z: -100.1,
+            min_player_distance: 250.0,
+            prefer_offscreen: true,
         }
     }
 }
 
+/// Weighted roll deciding which enemy class each spawner spawn gets, by
+/// class id (resolved through `EnemyClassLibrary` on attach). Entries with
+/// no positive weight are never picked; an empty table spawns the default
+/// class.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct SpawnTable {
+    pub entries: Vec<(String, f32)>,
+}
+
+impl Default for SpawnTable {
+    fn default() -> Self {
+        Self {
+            entries: vec![("default_enemy".into(), 1.0)],
+        }
+    }
+}
+
+impl SpawnTable {
+    fn roll(&self, rng: &mut GameRng) -> Option<&str> {
+        let total: f32 = self.entries.iter().map(|(_, w)| w.max(0.0)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0.0..total);
+        for (id, weight) in self.entries.iter().filter(|(_, w)| *w > 0.0) {
+            if roll < *weight {
+                return Some(id.as_str());
+            }
+            roll -= weight;
+        }
+        // Float drift past the last entry
+        self.entries
+            .iter()
+            .rev()
+            .find(|(_, w)| *w > 0.0)
+            .map(|(id, _)| id.as_str())
+    }
+}
+
+/// Which enemy is removed first when the budget is exceeded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum EvictionPolicy {
+    #[default]
+    Oldest,
+    FarthestFromPlayer,
+}
+
+/// Hard cap on live enemies (spawner + level markers). Anything over the cap
+/// is despawned according to `policy`; classes tagged "boss" are never evicted.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct EntityBudget {
+    pub max_enemies: usize,
+    pub policy: EvictionPolicy,
+}
+
+impl Default for EntityBudget {
+    fn default() -> Self {
+        Self {
+            max_enemies: 40,
+            policy: EvictionPolicy::Oldest,
+        }
+    }
+}
+
+/// When the enemy entered the world (`Time::elapsed_secs_f64`).
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SpawnStamp(pub f64);
+
 /// Convert the tilemap components into a world-space AABB (bottom-left, top-right).
 /// Assumes no rotation/scaling on the tilemap transform (standard setup).
-fn tilemap_world_aabb(
+pub fn tilemap_world_aabb(
     size: &TilemapSize,
     grid: &TilemapGridSize,
     tile: &TilemapTileSize,
@@ -57,9 +141,37 @@ fn tilemap_world_aabb(
 }
 
 /// Pick a random X within the tilemap’s horizontal span.
-fn random_x_in_map(min: Vec2, max: Vec2) -> f32 {
-    let mut r = rng();
-    r.random_range(min.x..max.x)
+fn random_x_in_map(min: Vec2, max: Vec2, rng: &mut GameRng) -> f32 {
+    rng.random_range(min.x..max.x)
+}
+
+/// World-space rect the main camera currently shows (ortho scale aware).
+fn camera_view_rect(window: &Window, cam_tf: &GlobalTransform, projection: &Projection) -> Rect {
+    let scale = match projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+    let half = Vec2::new(window.width(), window.height()) * 0.5 * scale;
+    Rect::from_center_half_size(cam_tf.translation().truncate(), half)
+}
+
+/// Raycast straight down from `start` to the ground layer and return the
+/// point `y_above` over the hit, if any ground is within `ray_down`.
+pub fn ground_point_below(
+    spatial: &SpatialQuery,
+    start: Vec2,
+    ray_down: f32,
+    y_above: f32,
+) -> Option<Vec2> {
+    // Avian 2D: cast_ray(origin, direction: Dir2, max_distance, solid, filter)
+    let dir = Dir2::from_xy(0.0, -1.0).unwrap();
+
+    // Only consider ground/default layer as valid “floor”.
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
+    let hit = spatial.cast_ray(start, dir, ray_down, true, &filter)?;
+    // Reconstruct the hit point from distance along ray.
+    let hit_point = start + dir.as_vec2() * hit.distance;
+    Some(Vec2::new(start.x, hit_point.y + y_above))
 }
 
 /// Try to find a valid spawn point: pick a random X, raycast downward to ground,
@@ -70,34 +182,25 @@ fn try_pick_spawn_point(
     spatial: &SpatialQuery,
     y_above: f32,
     ray_down: f32,
+    rng: &mut GameRng,
 ) -> Option<(Vec2, f32, f32)> {
-    let x = random_x_in_map(min, max);
+    let x = random_x_in_map(min, max, rng);
 
     // Start well above the map’s top edge so we always cast through empty space first.
     let start = Vec2::new(x, max.y + 200.0);
-
-    // Avian 2D: cast_ray(origin, direction: Dir2, max_distance, solid, filter)
-    let dir = Dir2::from_xy(0.0, -1.0).unwrap();
-
-    // Only consider ground/default layer as valid “floor”.
-    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
-    if let Some(hit) = spatial.cast_ray(start, dir, ray_down, true, &filter) {
-        // Reconstruct the hit point from distance along ray.
-        let hit_point = start + dir.as_vec2() * hit.distance;
-        let spawn_pos = Vec2::new(x, hit_point.y + y_above);
-        // Small patrol around the spawn X
-        let patrol_left = x - 100.0;
-        let patrol_right = x + 100.0;
-        Some((spawn_pos, patrol_left, patrol_right))
-    } else {
-        None
-    }
+    let spawn_pos = ground_point_below(spatial, start, ray_down, y_above)?;
+    // Small patrol around the spawn X
+    let patrol_left = x - 100.0;
+    let patrol_right = x + 100.0;
+    Some((spawn_pos, patrol_left, patrol_right))
 }
 
 /// System: tick the spawn timer and spawn when it elapses.
 fn tick_enemy_spawner(
     time: Res<Time>,
     mut spawner: ResMut<EnemySpawner>,
+    table: Res<SpawnTable>,
+    mut rng: ResMut<GameRng>,
     // Grab *any* tile layer to derive map bounds (all layers share size/grid/anchor).
     map_q: Query<(
         &TilemapSize,
@@ -108,9 +211,12 @@ fn tick_enemy_spawner(
         &GlobalTransform,
     )>,
     spatial: SpatialQuery, // NOTE: this is a system parameter, NOT `Res<_>`
+    player_q: Query<&GlobalTransform, With<Player>>,
+    cam_q: Query<(&GlobalTransform, &Projection), With<MainCamera>>,
+    window_q: Query<&Window>,
     mut commands: Commands,
 ) {
-    if map_q.is_empty() {
+    if !spawner.enabled || map_q.is_empty() {
         return;
     }
 
@@ -127,16 +233,100 @@ fn tick_enemy_spawner(
     let anchor = maybe_anchor.copied().unwrap_or(TilemapAnchor::BottomLeft);
     let (min, max) = tilemap_world_aabb(size, grid, tile, ty, anchor, gt);
 
+    let player_pos = player_q.single().ok().map(|p| p.translation().truncate());
+    let view = match (cam_q.single(), window_q.single()) {
+        (Ok((cam_tf, projection)), Ok(window)) => {
+            Some(camera_view_rect(window, cam_tf, projection))
+        }
+        _ => None,
+    };
+
+    let mut picked = None;
+    let mut visible_fallback = None;
     for _ in 0..spawner.attempts_per_tick {
-        if let Some((pos, left, right)) =
-            try_pick_spawn_point(min, max, &spatial, spawner.y_above_ground, spawner.ray_down)
-        {
-            let e = spawn_enemy(&mut commands, pos, left, right);
-            commands
-                .entity(e)
-                .insert(Transform::from_xyz(pos.x, pos.y, spawner.spawn_z));
-            break;
+        let Some(candidate) = try_pick_spawn_point(
+            min,
+            max,
+            &spatial,
+            spawner.y_above_ground,
+            spawner.ray_down,
+            &mut rng,
+        ) else {
+            continue;
+        };
+        let pos = candidate.0;
+        if player_pos.is_some_and(|p| p.distance(pos) < spawner.min_player_distance) {
+            continue;
+        }
+        if spawner.prefer_offscreen && view.is_some_and(|v| v.contains(pos)) {
+            visible_fallback.get_or_insert(candidate);
+            continue;
         }
+        picked = Some(candidate);
+        break;
+    }
+
+    if let Some((pos, left, right)) = picked.or(visible_fallback) {
+        let e = spawn_enemy(&mut commands, pos, left, right);
+        commands
+            .entity(e)
+            .insert(Transform::from_xyz(pos.x, pos.y, spawner.spawn_z));
+        if let Some(id) = table.roll(&mut rng) {
+            commands.entity(e).insert(EnemyClassId(id.to_string()));
+        }
+    }
+}
+
+fn stamp_new_enemies(
+    time: Res<Time>,
+    mut commands: Commands,
+    q: Query<Entity, (Added<Enemy>, Without<SpawnStamp>)>,
+) {
+    for e in &q {
+        commands
+            .entity(e)
+            .insert(SpawnStamp(time.elapsed_secs_f64()));
+    }
+}
+
+/// System: despawn enemies over `EntityBudget::max_enemies`.
+fn enforce_entity_budget(
+    budget: Res<EntityBudget>,
+    q: Query<
+        (Entity, &SpawnStamp, &GlobalTransform, Option<&EnemyClass>),
+        (With<Enemy>, Without<Corpse>),
+    >,
+    dummies: Query<(), With<TrainingDummy>>,
+    player_q: Query<&GlobalTransform, With<Player>>,
+    mut commands: Commands,
+) {
+    let total = q.iter().count();
+    if total <= budget.max_enemies {
+        return;
+    }
+
+    let is_boss = |class: Option<&EnemyClass>| {
+        class.is_some_and(|c| c.0.tags.iter().any(|t| t.eq_ignore_ascii_case("boss")))
+    };
+    let player_pos = player_q.single().ok().map(|p| p.translation());
+    let mut candidates: Vec<(Entity, f64, f32)> = q
+        .iter()
+        .filter(|(e, .., class)| !is_boss(*class) && !dummies.contains(*e))
+        .map(|(e, stamp, gt, _)| {
+            let dist = player_pos
+                .map(|p| p.distance(gt.translation()))
+                .unwrap_or(0.0);
+            (e, stamp.0, dist)
+        })
+        .collect();
+
+    match budget.policy {
+        EvictionPolicy::Oldest => candidates.sort_by(|a, b| a.1.total_cmp(&b.1)),
+        EvictionPolicy::FarthestFromPlayer => candidates.sort_by(|a, b| b.2.total_cmp(&a.2)),
+    }
+
+    for (e, ..) in candidates.into_iter().take(total - budget.max_enemies) {
+        commands.entity(e).despawn();
     }
 }
 
@@ -146,6 +336,13 @@ pub struct EnemySpawnerPlugin;
 impl Plugin for EnemySpawnerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EnemySpawner>()
-            .add_systems(Update, tick_enemy_spawner);
+            .init_resource::<EntityBudget>()
+            .init_resource::<SpawnTable>()
+            .register_type::<EntityBudget>()
+            .register_type::<SpawnTable>()
+            .add_systems(
+                Update,
+                (tick_enemy_spawner, stamp_new_enemies, enforce_entity_budget).chain(),
+            );
     }
 }
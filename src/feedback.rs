@@ -0,0 +1,160 @@
+// feedback.rs
+use crate::camera::MainCamera;
+use crate::character::Player;
+use crate::filmic_post::FilmicOverrides;
+use crate::gameflow::GameState;
+use crate::hud::{DamagePlayer, PlayerStats};
+use crate::prelude::*;
+use crate::raycasts::SwingEnded;
+use crate::settings::JuiceConfig;
+
+// ───────── Low-health vignette ─────────
+const LOW_HEALTH_FRACTION: f32 = 0.25;
+const LOW_HEALTH_BEAT_HZ: f32 = 1.1;
+const LOW_HEALTH_VIGNETTE_BASE: f32 = 0.15;
+const LOW_HEALTH_VIGNETTE_PULSE: f32 = 0.25;
+const LOW_HEALTH_RECOVER_RATE: f32 = 4.0;
+
+/// Two quick bumps per period ("lub-dub"), in 0..=1.
+fn heartbeat(t: f32) -> f32 {
+    let phase = (t * LOW_HEALTH_BEAT_HZ).fract();
+    let bump = |center: f32, width: f32| (-((phase - center) / width).powi(2)).exp();
+    (bump(0.08, 0.05) + 0.6 * bump(0.28, 0.06)).min(1.0)
+}
+
+fn low_health_vignette_pulse(
+    time: Res<Time>,
+    stats: Res<PlayerStats>,
+    juice: Res<JuiceConfig>,
+    mut q: Query<&mut FilmicOverrides, With<MainCamera>>,
+) {
+    let Ok(mut o) = q.single_mut() else {
+        return;
+    };
+    let frac = if !juice.low_health_pulse {
+        1.0
+    } else if stats.max_health > 0.0 {
+        (stats.health / stats.max_health).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    if frac < LOW_HEALTH_FRACTION {
+        // Stronger the closer we are to zero
+        let severity = 1.0 - frac / LOW_HEALTH_FRACTION;
+        let beat = heartbeat(time.elapsed_secs());
+        o.vignette_boost = severity * (LOW_HEALTH_VIGNETTE_BASE + LOW_HEALTH_VIGNETTE_PULSE * beat);
+    } else if o.vignette_boost != 0.0 {
+        // Ease back to the FilmicControls baseline
+        let t = 1.0 - (-LOW_HEALTH_RECOVER_RATE * time.delta_secs()).exp();
+        o.vignette_boost *= 1.0 - t;
+        if o.vignette_boost.abs() < 0.001 {
+            o.vignette_boost = 0.0;
+        }
+    }
+}
+
+// ───────── Damage chromatic pulse ─────────
+const CA_PULSE_PX: f32 = 3.0;
+const CA_PULSE_SECS: f32 = 0.25;
+
+fn damage_chromatic_pulse(
+    time: Res<Time>,
+    juice: Res<JuiceConfig>,
+    mut hits: EventReader<DamagePlayer>,
+    mut q: Query<&mut FilmicOverrides, With<MainCamera>>,
+    mut pulse: Local<Option<Timer>>,
+) {
+    // Only real hits; damage-over-time ticks would keep it lit constantly
+    let player_hit = hits.read().any(|h| h.stagger);
+    if player_hit && juice.chromatic_pulse {
+        *pulse = Some(Timer::from_seconds(CA_PULSE_SECS, TimerMode::Once));
+    }
+
+    let Ok(mut o) = q.single_mut() else {
+        return;
+    };
+    o.ca_boost_px = match pulse.as_mut() {
+        Some(t) => {
+            t.tick(time.delta());
+            // Quadratic ease-out back to the baseline
+            CA_PULSE_PX * t.fraction_remaining().powi(2)
+        }
+        None => 0.0,
+    };
+    if pulse.as_ref().is_some_and(|t| t.finished()) {
+        *pulse = None;
+    }
+}
+
+// ───────── Swing outcome ─────────
+const SWING_HIT_SOUND: &str = "sfx/swing_hit.ogg";
+const SWING_WHIFF_SOUND: &str = "sfx/swing_whiff.ogg";
+// Stamina handed back for a swing that landed; 0 turns the reward off
+const SWING_HIT_STAMINA_REFUND: f32 = 4.0;
+
+/// Swing sounds, each loaded only if its file ships in `assets/`; a missing
+/// one just leaves that outcome silent.
+#[derive(Resource, Default)]
+struct SwingSounds {
+    hit: Option<Handle<AudioSource>>,
+    whiff: Option<Handle<AudioSource>>,
+}
+
+fn load_swing_sounds(mut commands: Commands, assets: Res<AssetServer>) {
+    let load = |path: &str| {
+        let shipped = std::path::Path::new("assets").join(path).is_file();
+        if !shipped {
+            info!("Feedback: {path} not found, that swing sound stays off");
+        }
+        shipped.then(|| assets.load(path))
+    };
+    commands.insert_resource(SwingSounds {
+        hit: load(SWING_HIT_SOUND),
+        whiff: load(SWING_WHIFF_SOUND),
+    });
+}
+
+/// Player swings that connected sound (and pay back) differently from whiffs.
+fn swing_outcome_feedback(
+    mut commands: Commands,
+    sounds: Res<SwingSounds>,
+    mut ended: EventReader<SwingEnded>,
+    mut stats: ResMut<PlayerStats>,
+    player: Query<(), With<Player>>,
+) {
+    for swing in ended.read() {
+        if !player.contains(swing.attacker) {
+            continue;
+        }
+        let sound = if swing.connected {
+            stats.stamina = (stats.stamina + SWING_HIT_STAMINA_REFUND).min(stats.max_stamina);
+            &sounds.hit
+        } else {
+            &sounds.whiff
+        };
+        if let Some(sound) = sound {
+            commands.spawn((AudioPlayer::new(sound.clone()), PlaybackSettings::DESPAWN));
+        }
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<FilmicOverrides>()
+            .init_resource::<SwingSounds>()
+            .add_systems(Startup, load_swing_sounds)
+            .add_systems(
+                Update,
+                (
+                    low_health_vignette_pulse,
+                    damage_chromatic_pulse,
+                    swing_outcome_feedback,
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
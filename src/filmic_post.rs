@@ -25,11 +25,13 @@ use bevy::{
 };
 use bevy_inspector_egui::InspectorOptions;
 use bevy_inspector_egui::prelude::ReflectInspectorOptions;
+use serde::{Deserialize, Serialize};
 
 const SHADER_ASSET_PATH: &str = "shaders/filmic_post.wgsl";
 
-#[derive(Component, Reflect, InspectorOptions)]
+#[derive(Component, Clone, Debug, Reflect, InspectorOptions, Serialize, Deserialize)]
 #[reflect(Component, InspectorOptions)]
+#[serde(default)]
 pub struct FilmicControls {
     #[inspector(min=-3.0, max=3.0, speed=0.02)]
     pub exposure_ev: f32,
@@ -71,6 +73,21 @@ impl Default for FilmicControls {
     }
 }
 
+impl FilmicControls {
+    /// Clamp every field into its inspector range (e.g. after loading JSON).
+    pub fn clamped(mut self) -> Self {
+        self.exposure_ev = self.exposure_ev.clamp(-3.0, 3.0);
+        self.vignette_strength = self.vignette_strength.clamp(0.0, 1.0);
+        self.shadow_crush = self.shadow_crush.clamp(0.0, 0.6);
+        self.split_tone_strength = self.split_tone_strength.clamp(0.0, 1.2);
+        self.ca_amount_px = self.ca_amount_px.clamp(0.0, 4.0);
+        self.ca_falloff = self.ca_falloff.clamp(0.2, 3.0);
+        self.curve_strength = self.curve_strength.clamp(0.0, 1.2);
+        self.stock_strength = self.stock_strength.clamp(0.0, 1.2);
+        self
+    }
+}
+
 #[derive(Component, Clone, Copy, Default, ExtractComponent, ShaderType, Reflect)]
 pub struct FilmicSettings {
     pub exposure_ev: f32,
@@ -98,6 +115,16 @@ impl FilmicSettings {
     }
 }
 
+/// Transient gameplay-driven offsets layered on top of `FilmicControls`.
+/// `sync_filmic_controls` treats the controls as the baseline and adds these,
+/// so feedback effects never fight the inspector values.
+#[derive(Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct FilmicOverrides {
+    pub vignette_boost: f32,
+    pub ca_boost_px: f32,
+}
+
 pub struct FilmicPostProcessPlugin;
 
 impl Plugin for FilmicPostProcessPlugin {
@@ -189,6 +216,9 @@ impl FromWorld for FilmicPipeline {
     }
 }
 
+/// Runs once per camera view. `ViewNodeRunner` skips any view whose entity
+/// doesn't match `ViewQuery`, so cameras without `FilmicSettings` (menu, HUD,
+/// a minimap or picture-in-picture view) pass through untouched.
 #[derive(Default)]
 struct FilmicNode;
 
@@ -238,6 +268,7 @@ impl ViewNode for FilmicNode {
         });
 
         pass.set_render_pipeline(gpu_pipeline);
+        // This view's slot in the shared uniform buffer
         pass.set_bind_group(0, &bind_group, &[dyn_index.index()]);
         pass.draw(0..3, 0..1);
 
@@ -245,13 +276,21 @@ impl ViewNode for FilmicNode {
     }
 }
 
-pub fn sync_filmic_controls(mut q: Query<(&FilmicControls, &mut FilmicSettings)>) {
-    for (ui, mut s) in &mut q {
+/// Per camera: every view with `FilmicControls` drives its own settings.
+pub fn sync_filmic_controls(
+    mut q: Query<(
+        &FilmicControls,
+        Option<&FilmicOverrides>,
+        &mut FilmicSettings,
+    )>,
+) {
+    for (ui, overrides, mut s) in &mut q {
+        let o = overrides.copied().unwrap_or_default();
         s.exposure_ev = ui.exposure_ev;
-        s.vignette_strength = ui.vignette_strength;
+        s.vignette_strength = (ui.vignette_strength + o.vignette_boost).clamp(0.0, 1.0);
         s.shadow_crush = ui.shadow_crush;
         s.split_tone_strength = ui.split_tone_strength;
-        s.ca_amount_px = ui.ca_amount_px;
+        s.ca_amount_px = (ui.ca_amount_px + o.ca_boost_px).max(0.0);
         s.ca_falloff = ui.ca_falloff;
         s.curve_strength = ui.curve_strength;
         s.stock_strength = ui.stock_strength;
@@ -0,0 +1,172 @@
+// focus.rs
+use crate::character::{Action, Dead, GameLayer, Player};
+use crate::class::PlayerClass;
+use crate::enemy::{Enemy, EnemyDead};
+use crate::gameflow::GameState;
+use crate::hud::DamagePlayer;
+use crate::pool::{EffectSprite, Pool, play_effect};
+use crate::prelude::*;
+use crate::raycasts::MeleeRaycastHit;
+use crate::settings::Palette;
+use avian2d::spatial_query::SpatialQueryFilter;
+
+const FOCUS_MAX: f32 = 100.0;
+const FOCUS_PER_HIT: f32 = 8.0;
+// Per point of damage the player takes
+const FOCUS_PER_DAMAGE: f32 = 0.5;
+// Each point of the class's focus attribute adds this much to fill and burst
+const FOCUS_SCALE_PER_POINT: f32 = 0.05;
+const FOCUS_BURST_RADIUS: f32 = 96.0;
+const FOCUS_BURST_DAMAGE: f32 = 20.0;
+const FOCUS_BURST_KNOCKBACK: f32 = 2.0;
+// The burst's own hits land for a couple of frames; don't let them refund it
+const FOCUS_REFILL_LOCK_SECS: f32 = 0.2;
+
+/// Meter built by landing melee hits and taking damage; when full,
+/// `Action::Special` spends it on a focus burst around the player.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Focus {
+    pub value: f32,
+    pub max: f32,
+    #[reflect(ignore)]
+    refill_lock: Timer,
+}
+
+impl Default for Focus {
+    fn default() -> Self {
+        let mut refill_lock = Timer::from_seconds(FOCUS_REFILL_LOCK_SECS, TimerMode::Once);
+        refill_lock.tick(refill_lock.duration());
+        Self {
+            value: 0.0,
+            max: FOCUS_MAX,
+            refill_lock,
+        }
+    }
+}
+
+impl Focus {
+    pub fn fraction(&self) -> f32 {
+        if self.max > 0.0 {
+            (self.value / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.max > 0.0 && self.value >= self.max
+    }
+}
+
+/// Fill and burst multiplier from the class's `focus` attribute.
+fn focus_scale(class: Option<&PlayerClass>) -> f32 {
+    let points = class.map(|c| c.0.attribute_start.focus).unwrap_or(0);
+    1.0 + points as f32 * FOCUS_SCALE_PER_POINT
+}
+
+fn build_focus(
+    time: Res<Time>,
+    mut focus: ResMut<Focus>,
+    mut hits: EventReader<MeleeRaycastHit>,
+    mut taken: EventReader<DamagePlayer>,
+    player: Query<(Entity, Option<&PlayerClass>), With<Player>>,
+) {
+    focus.refill_lock.tick(time.delta());
+    let Ok((player, class)) = player.single() else {
+        hits.clear();
+        taken.clear();
+        return;
+    };
+    let landed = hits.read().filter(|h| h.attacker == player).count() as f32;
+    let damage: f32 = taken.read().map(|d| d.amount.max(0.0)).sum();
+    if !focus.refill_lock.finished() {
+        return;
+    }
+    let gain = (landed * FOCUS_PER_HIT + damage * FOCUS_PER_DAMAGE) * focus_scale(class);
+    if gain > 0.0 {
+        focus.value = (focus.value + gain).min(focus.max);
+    }
+}
+
+/// Knocks back and damages every live enemy within `FOCUS_BURST_RADIUS`,
+/// through the same hit event as the player's swings.
+fn release_focus_burst(
+    mut commands: Commands,
+    mut focus: ResMut<Focus>,
+    mut fx_pool: ResMut<Pool<EffectSprite>>,
+    palette: Res<Palette>,
+    spatial: SpatialQuery,
+    player: Query<
+        (
+            Entity,
+            &ActionState<Action>,
+            &GlobalTransform,
+            Option<&PlayerClass>,
+        ),
+        (With<Player>, Without<Dead>),
+    >,
+    enemies: Query<&GlobalTransform, (With<Enemy>, Without<EnemyDead>)>,
+    mut hits: EventWriter<MeleeRaycastHit>,
+) {
+    let Ok((e, actions, gt, class)) = player.single() else {
+        return;
+    };
+    if !actions.just_pressed(&Action::Special) || !focus.is_full() {
+        return;
+    }
+    focus.value = 0.0;
+    focus.refill_lock.reset();
+
+    let scale = focus_scale(class);
+    let radius = FOCUS_BURST_RADIUS * scale;
+    let damage = (FOCUS_BURST_DAMAGE * scale).round() as i32;
+    let origin = gt.translation().truncate();
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Enemy));
+    for target in spatial.shape_intersections(&Collider::circle(radius), origin, 0.0, &filter) {
+        let Ok(target_gt) = enemies.get(target) else {
+            continue;
+        };
+        let to_target = target_gt.translation().truncate() - origin;
+        hits.write(MeleeRaycastHit {
+            attacker: e,
+            target,
+            distance: to_target.length(),
+            normal: -to_target.normalize_or_zero(),
+            damage,
+            knockback_mult: FOCUS_BURST_KNOCKBACK,
+            launch: 0.0,
+        });
+    }
+    play_effect(
+        &mut commands,
+        &mut fx_pool,
+        gt.translation() + Vec3::new(0.0, 0.0, 1.0),
+        palette.focus,
+        Vec2::splat(radius * 0.5),
+        4.0,
+        0.35,
+    );
+}
+
+fn reset_focus(mut focus: ResMut<Focus>) {
+    *focus = Focus::default();
+}
+
+// ───────── Plugin ─────────
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Focus>()
+            .register_type::<Focus>()
+            .add_systems(OnEnter(GameState::MainMenu), reset_focus)
+            .add_systems(OnEnter(GameState::GameOver), reset_focus)
+            .add_systems(
+                Update,
+                (build_focus, release_focus_burst)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,79 @@
+// fx.rs
+use crate::prelude::*;
+
+/// Ramps the entity's `Sprite` color (or UI `BackgroundColor`) alpha from 0
+/// up to the alpha it was spawned with over `timer`, then removes itself.
+/// Insert it at spawn.
+#[derive(Component, Debug, Clone)]
+pub struct FadeIn {
+    pub timer: Timer,
+    /// Alpha to end on; taken from the color on the first tick.
+    target: Option<f32>,
+}
+
+impl FadeIn {
+    pub fn new(secs: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(secs, TimerMode::Once),
+            target: None,
+        }
+    }
+
+    /// Alpha to show now, given the color's current alpha (remembered as
+    /// the target on the first call).
+    fn alpha(&mut self, current: f32) -> f32 {
+        let target = *self.target.get_or_insert(current);
+        target * self.timer.fraction()
+    }
+}
+
+fn tick_fade_in(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(
+        Entity,
+        &mut FadeIn,
+        Option<&mut Sprite>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (e, mut fade, sprite, background) in &mut q {
+        fade.timer.tick(time.delta());
+        if let Some(mut sprite) = sprite {
+            let alpha = fade.alpha(sprite.color.alpha());
+            sprite.color.set_alpha(alpha);
+        } else if let Some(mut bg) = background {
+            let alpha = fade.alpha(bg.0.alpha());
+            bg.0.set_alpha(alpha);
+        }
+        if fade.timer.finished() {
+            commands.entity(e).remove::<FadeIn>();
+        }
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct FxPlugin;
+
+impl Plugin for FxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, tick_fade_in);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FadeIn;
+    use std::time::Duration;
+
+    #[test]
+    fn fade_in_ramps_to_the_spawn_alpha() {
+        let mut fade = FadeIn::new(1.0);
+        assert_eq!(fade.alpha(0.8), 0.0);
+        fade.timer.tick(Duration::from_millis(500));
+        // Later calls see the faded alpha, but the target stays 0.8
+        assert!((fade.alpha(0.0) - 0.4).abs() < 1e-6);
+        fade.timer.tick(Duration::from_millis(500));
+        assert!((fade.alpha(0.4) - 0.8).abs() < 1e-6);
+    }
+}
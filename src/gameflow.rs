@@ -1,7 +1,18 @@
+use crate::character::Action;
+use crate::dev::dev_enabled;
+use crate::hud::HudWindowScale;
+use crate::pool::Pooled;
 use crate::prelude::*;
+use crate::progression::BeginNewGamePlus;
+use crate::settings::{InputConfig, SettingItem, SettingsParams};
+use crate::transition::{Transition, TransitionPlugin, not_transitioning};
 use bevy::app::AppExit;
+use bevy::input::gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::{MouseButtonInput, MouseWheel};
 use bevy::time::Virtual;
 use bevy::ui::GlobalZIndex;
+use bevy::window::{CursorMoved, PrimaryWindow, WindowResized};
 
 #[derive(States, Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
 pub enum GameState {
@@ -10,14 +21,88 @@ pub enum GameState {
     InGame,
     Paused,
     Settings,
+    Controls,
     GameOver,
 }
 
 #[derive(Event, Default)]
 pub struct PlayerDied;
 
+/// Menu that Settings/Controls "Back" returns to.
 #[derive(Resource, Clone, Copy, Default)]
-struct SettingsBackTarget(GameState);
+struct MenuBackTarget(GameState);
+
+// Slow-motion beat between the player's death and the GameOver fade
+const DEATH_SLOWMO_SPEED: f32 = 0.2;
+const DEATH_SLOWMO_SECS: f32 = 1.0; // real seconds
+
+// Dev: cycles the debug time scale
+const TIME_SCALE_CYCLE: KeyCode = KeyCode::F3;
+const TIME_SCALE_STEPS: [f32; 4] = [0.25, 0.5, 1.0, 2.0];
+const TIME_SCALE_MIN: f32 = 0.05;
+const TIME_SCALE_MAX: f32 = 4.0;
+
+const MENU_BG_PATH: &str = "ui/menu_bg.webp";
+// Gamepad sticks must move this far to count as input (drift stays idle)
+const ATTRACT_STICK_WAKE: f32 = 0.5;
+
+/// Main menu attract mode: after `idle_after` seconds without input the menu
+/// hides and the background switches to the `footage` loop; any input brings
+/// the menu back. 0 turns it off; so does `footage` missing from `assets/`.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct AttractMode {
+    pub idle_after: f32,
+    pub footage: String,
+}
+
+impl Default for AttractMode {
+    fn default() -> Self {
+        Self {
+            idle_after: 45.0,
+            footage: "ui/attract.webp".into(),
+        }
+    }
+}
+
+/// Real time (seconds since startup) of the last key, mouse or gamepad input.
+#[derive(Resource, Default)]
+pub struct LastInput {
+    pub at: f32,
+}
+
+/// Whether the attract loop is currently showing instead of the menu, and
+/// whether `AttractMode::footage` ships at all.
+#[derive(Resource, Default)]
+struct Attracting {
+    showing: bool,
+    footage_shipped: bool,
+}
+
+/// Virtual-time speed = `debug` (inspector, or F3 with dev tools on) ×
+/// `gameplay` (death slow-mo and similar beats). `apply_time_scale` is the
+/// only writer of the relative speed, so the two compose; pausing is separate.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct TimeScaleConfig {
+    pub debug: f32,
+    pub gameplay: f32,
+}
+
+impl Default for TimeScaleConfig {
+    fn default() -> Self {
+        Self {
+            debug: 1.0,
+            gameplay: 1.0,
+        }
+    }
+}
+
+/// Running while the death slow-mo plays; `GameOver` waits for it.
+#[derive(Resource, Default)]
+struct DeathSequence {
+    timer: Option<Timer>,
+}
 
 #[derive(Component)]
 pub struct GameplayRoot;
@@ -34,6 +119,9 @@ struct PauseMenuUI;
 #[derive(Component)]
 struct SettingsUI;
 
+#[derive(Component)]
+struct ControlsUI;
+
 #[derive(Component)]
 struct GameOverUI;
 
@@ -45,20 +133,26 @@ struct MenuBgLoop(Handle<vleue_kinetoscope::AnimatedImage>);
 #[derive(Component, Clone, Copy)]
 enum MainBtn {
     NewGame,
+    NewGamePlus,
     Settings,
+    Controls,
     Quit,
 }
 #[derive(Component, Clone, Copy)]
 enum PauseBtn {
     Resume,
     Settings,
+    Controls,
     MainMenu,
 }
 #[derive(Component, Clone, Copy)]
 enum SetBtn {
+    Item(SettingItem),
     Back,
 }
 #[derive(Component, Clone, Copy)]
+struct ControlsBackBtn;
+#[derive(Component, Clone, Copy)]
 enum OverBtn {
     TryAgain,
     MainMenu,
@@ -68,8 +162,16 @@ pub struct GameFlowPlugin;
 
 impl Plugin for GameFlowPlugin {
     fn build(&self, app: &mut App) {
-        app.init_state::<GameState>()
-            .init_resource::<SettingsBackTarget>()
+        app.add_plugins(TransitionPlugin)
+            .init_state::<GameState>()
+            .init_resource::<MenuBackTarget>()
+            .init_resource::<DeathSequence>()
+            .init_resource::<TimeScaleConfig>()
+            .register_type::<TimeScaleConfig>()
+            .init_resource::<AttractMode>()
+            .register_type::<AttractMode>()
+            .init_resource::<LastInput>()
+            .init_resource::<Attracting>()
             .add_event::<PlayerDied>()
             // Menus
             .add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
@@ -77,14 +179,17 @@ impl Plugin for GameFlowPlugin {
                 Update,
                 main_menu_buttons.run_if(in_state(GameState::MainMenu)),
             )
+            .add_systems(Update, respond_to_window_resize)
             .add_systems(
                 Update,
-                size_menu_bg_to_window.run_if(in_state(GameState::MainMenu)),
-            )
-            .add_systems(
-                Update,
-                size_menu_bg_to_window.run_if(in_state(GameState::Settings)),
+                (
+                    track_last_input,
+                    find_attract_footage.run_if(resource_changed::<AttractMode>),
+                    run_attract_mode.run_if(in_state(GameState::MainMenu)),
+                )
+                    .chain(),
             )
+            .add_systems(OnExit(GameState::MainMenu), stop_attract_mode)
             .add_systems(OnExit(GameState::MainMenu), despawn_ui::<MainMenuUI>)
             .add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
             .add_systems(OnEnter(GameState::InGame), despawn_menu_bg)
@@ -93,6 +198,12 @@ impl Plugin for GameFlowPlugin {
                 Update,
                 settings_buttons.run_if(in_state(GameState::Settings)),
             )
+            .add_systems(OnEnter(GameState::Controls), spawn_controls_menu)
+            .add_systems(OnExit(GameState::Controls), despawn_ui::<ControlsUI>)
+            .add_systems(
+                Update,
+                controls_buttons.run_if(in_state(GameState::Controls)),
+            )
             .add_systems(OnEnter(GameState::Paused), (spawn_pause_menu, pause_time))
             .add_systems(
                 OnExit(GameState::Paused),
@@ -102,17 +213,31 @@ impl Plugin for GameFlowPlugin {
                 Update,
                 pause_menu_buttons.run_if(in_state(GameState::Paused)),
             )
-            .add_systems(OnEnter(GameState::GameOver), spawn_game_over)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (spawn_game_over, end_death_sequence),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), end_death_sequence)
             .add_systems(OnExit(GameState::GameOver), despawn_ui::<GameOverUI>)
             .add_systems(
                 Update,
                 game_over_buttons.run_if(in_state(GameState::GameOver)),
             )
             // Pause toggles
-            .add_systems(Update, esc_to_pause.run_if(in_state(GameState::InGame)))
-            .add_systems(Update, esc_to_resume.run_if(in_state(GameState::Paused)))
+            .add_systems(
+                Update,
+                esc_to_pause.run_if(in_state(GameState::InGame).and(not_transitioning)),
+            )
+            .add_systems(
+                Update,
+                esc_to_resume.run_if(in_state(GameState::Paused).and(not_transitioning)),
+            )
             // Death -> GameOver
-            .add_systems(Update, to_game_over_on_death);
+            .add_systems(Update, to_game_over_on_death)
+            .add_systems(
+                Update,
+                (cycle_debug_time_scale.run_if(dev_enabled), apply_time_scale).chain(),
+            );
     }
 }
 
@@ -202,6 +327,43 @@ fn spawn_button<A: Component>(
     btn
 }
 
+/// Compact button for settings rows (label is refreshed when the value changes).
+fn spawn_setting_button(
+    commands: &mut Commands,
+    font: &Handle<Font>,
+    label: String,
+    item: SettingItem,
+) -> Entity {
+    let btn = commands
+        .spawn((
+            Button,
+            Node {
+                height: Val::Px(32.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.25)),
+            SetBtn::Item(item),
+        ))
+        .id();
+
+    let text = commands
+        .spawn((
+            Text::new(label),
+            TextFont {
+                font: font.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ))
+        .id();
+
+    commands.entity(btn).add_child(text);
+    btn
+}
+
 fn spawn_main_menu(
     mut commands: Commands,
     assets: Res<AssetServer>,
@@ -209,7 +371,7 @@ fn spawn_main_menu(
 ) {
     if q_bg.is_empty() {
         let stream_handle: Handle<vleue_kinetoscope::StreamingAnimatedImage> =
-            assets.load("ui/menu_bg.webp");
+            assets.load(MENU_BG_PATH);
         commands.spawn((
             MainMenuBg,
             vleue_kinetoscope::StreamingAnimatedImageController::play(stream_handle),
@@ -225,13 +387,22 @@ fn spawn_main_menu(
     commands.entity(root).add_child(panel);
 
     let b_new = spawn_button(&mut commands, &font, "New Game", MainBtn::NewGame);
+    let b_ngp = spawn_button(
+        &mut commands,
+        &font,
+        "Begin New Game+",
+        MainBtn::NewGamePlus,
+    );
     let b_set = spawn_button(&mut commands, &font, "Settings", MainBtn::Settings);
+    let b_ctl = spawn_button(&mut commands, &font, "Controls", MainBtn::Controls);
     let b_quit = spawn_button(&mut commands, &font, "Quit", MainBtn::Quit);
 
-    commands.entity(panel).add_children(&[b_new, b_set, b_quit]);
+    commands
+        .entity(panel)
+        .add_children(&[b_new, b_ngp, b_set, b_ctl, b_quit]);
 }
 
-fn spawn_settings_menu(mut commands: Commands, assets: Res<AssetServer>) {
+fn spawn_settings_menu(mut commands: Commands, assets: Res<AssetServer>, settings: SettingsParams) {
     let font = assets.load("fonts/GohuFont14NerdFontMono-Regular.ttf");
 
     let root = menu_root(&mut commands);
@@ -241,9 +412,14 @@ fn spawn_settings_menu(mut commands: Commands, assets: Res<AssetServer>) {
     commands.entity(root).add_child(panel);
 
     let title = menu_title(&mut commands, font.clone(), "SETTINGS");
-    let b_back = spawn_button(&mut commands, &font, "Back", SetBtn::Back);
-
     commands.entity(panel).add_child(title);
+
+    for item in SettingItem::ALL {
+        let b = spawn_setting_button(&mut commands, &font, settings.label(item), item);
+        commands.entity(panel).add_child(b);
+    }
+
+    let b_back = spawn_button(&mut commands, &font, "Back", SetBtn::Back);
     commands.entity(panel).add_child(b_back);
 }
 
@@ -259,10 +435,104 @@ fn spawn_pause_menu(mut commands: Commands, assets: Res<AssetServer>) {
     let title = menu_title(&mut commands, font.clone(), "PAUSED");
     let b_res = spawn_button(&mut commands, &font, "Resume", PauseBtn::Resume);
     let b_set = spawn_button(&mut commands, &font, "Settings", PauseBtn::Settings);
+    let b_ctl = spawn_button(&mut commands, &font, "Controls", PauseBtn::Controls);
     let b_menu = spawn_button(&mut commands, &font, "Main Menu", PauseBtn::MainMenu);
 
     commands.entity(panel).add_child(title);
-    commands.entity(panel).add_children(&[b_res, b_set, b_menu]);
+    commands
+        .entity(panel)
+        .add_children(&[b_res, b_set, b_ctl, b_menu]);
+}
+
+/// Read-only binding list, straight from the persisted `InputConfig` the
+/// player's `InputMap` is built from (so rebinds show up).
+fn spawn_controls_menu(mut commands: Commands, assets: Res<AssetServer>, input: Res<InputConfig>) {
+    let font = assets.load("fonts/GohuFont14NerdFontMono-Regular.ttf");
+
+    let root = menu_root(&mut commands);
+    let panel = menu_panel(&mut commands);
+
+    commands.entity(root).insert(ControlsUI);
+    commands.entity(root).add_child(panel);
+
+    let title = menu_title(&mut commands, font.clone(), "CONTROLS");
+    commands.entity(panel).add_child(title);
+
+    let header = controls_row(&mut commands, &font, ["Action", "Keyboard", "Gamepad"]);
+    commands.entity(panel).add_child(header);
+    for action in Action::ALL {
+        let (keys, pad) = binding_labels(&input, &action);
+        let row = controls_row(
+            &mut commands,
+            &font,
+            [&format!("{action:?}"), &keys.join(" / "), &pad.join(" / ")],
+        );
+        commands.entity(panel).add_child(row);
+    }
+
+    let b_back = spawn_button(&mut commands, &font, "Back", ControlsBackBtn);
+    commands.entity(panel).add_child(b_back);
+}
+
+fn controls_row(commands: &mut Commands, font: &Handle<Font>, cells: [&str; 3]) -> Entity {
+    let row = commands
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            ..default()
+        })
+        .id();
+    for cell in cells {
+        let text = commands
+            .spawn((
+                Node {
+                    width: Val::Percent(100.0 / 3.0),
+                    ..default()
+                },
+                Text::new(cell),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ))
+            .id();
+        commands.entity(row).add_child(text);
+    }
+    row
+}
+
+/// Keyboard and gamepad names bound to `action` (`KeyJ` -> `J`); the
+/// sticks aren't in `InputConfig`, so they're named here.
+fn binding_labels(input: &InputConfig, action: &Action) -> (Vec<String>, Vec<String>) {
+    let binding = match action {
+        Action::Move => {
+            let keys = format!(
+                "{}/{}",
+                key_label(&input.move_left),
+                key_label(&input.move_right)
+            );
+            return (vec![keys], vec!["Left Stick".into()]);
+        }
+        Action::Aim => return (Vec::new(), vec!["Right Stick".into()]),
+        Action::Jump => &input.jump,
+        Action::Attack => &input.attack,
+        Action::Sprint => &input.sprint,
+        Action::Drop => &input.drop,
+        Action::SwapWeapon => &input.swap_weapon,
+        Action::Up => &input.up,
+        Action::Special => &input.special,
+        Action::Dash => &input.dash,
+    };
+    let keys = binding.keys.iter().map(|k| key_label(k).to_string());
+    (keys.collect(), binding.gamepad.clone())
+}
+
+fn key_label(name: &str) -> &str {
+    match name.strip_prefix("Key") {
+        Some(letter) if !letter.is_empty() => letter,
+        _ => name,
+    }
 }
 
 fn spawn_game_over(mut commands: Commands, assets: Res<AssetServer>) {
@@ -290,20 +560,144 @@ fn set_btn_color(bg: &mut BackgroundColor, interaction: Interaction) {
     };
 }
 
-fn size_menu_bg_to_window(qwin: Query<&Window>, mut q: Query<&mut Sprite, With<MainMenuBg>>) {
-    let Ok(win) = qwin.single() else {
+/// Everything that depends on the window size, updated in one place when the
+/// primary window is created or resized: the menu background fills it and
+/// the HUD scales with its height. New backgrounds are sized as they appear.
+fn respond_to_window_resize(
+    mut resized: EventReader<WindowResized>,
+    qwin: Query<(Entity, Ref<Window>), With<PrimaryWindow>>,
+    mut hud_scale: ResMut<HudWindowScale>,
+    mut q_bg: Query<&mut Sprite, With<MainMenuBg>>,
+) {
+    let Ok((window_entity, win)) = qwin.single() else {
+        resized.clear();
         return;
     };
+    let window_changed = resized
+        .read()
+        .filter(|ev| ev.window == window_entity)
+        .count()
+        > 0
+        || win.is_added();
     let size = Vec2::new(win.width(), win.height());
-    for mut sprite in &mut q {
-        sprite.custom_size = Some(size);
+    if window_changed {
+        hud_scale.set_if_neq(HudWindowScale::from_height(size.y));
+    }
+    let fresh_bg = q_bg.iter_mut().any(|sprite| sprite.is_added());
+    if window_changed || fresh_bg {
+        for mut sprite in &mut q_bg {
+            sprite.custom_size = Some(size);
+        }
+    }
+}
+
+fn track_last_input(
+    time: Res<Time<Real>>,
+    mut last: ResMut<LastInput>,
+    mut keys: EventReader<KeyboardInput>,
+    mut clicks: EventReader<MouseButtonInput>,
+    mut cursor: EventReader<CursorMoved>,
+    mut wheel: EventReader<MouseWheel>,
+    mut pad_buttons: EventReader<GamepadButtonChangedEvent>,
+    mut pad_axes: EventReader<GamepadAxisChangedEvent>,
+) {
+    let mut any = keys.read().count() > 0;
+    any |= clicks.read().count() > 0;
+    any |= cursor.read().count() > 0;
+    any |= wheel.read().count() > 0;
+    any |= pad_buttons.read().count() > 0;
+    any |= pad_axes
+        .read()
+        .filter(|ev| ev.value.abs() >= ATTRACT_STICK_WAKE)
+        .count()
+        > 0;
+    if any {
+        last.at = time.elapsed_secs();
+    }
+}
+
+fn set_menu_bg(commands: &mut Commands, assets: &AssetServer, bg: Entity, path: &str) {
+    let stream: Handle<vleue_kinetoscope::StreamingAnimatedImage> = assets.load(path);
+    commands
+        .entity(bg)
+        .insert(vleue_kinetoscope::StreamingAnimatedImageController::play(
+            stream,
+        ));
+}
+
+/// Look for the footage on disk, so a missing file never hides the menu over
+/// an empty background.
+fn find_attract_footage(attract: Res<AttractMode>, mut attracting: ResMut<Attracting>) {
+    let shipped = std::path::Path::new("assets")
+        .join(&attract.footage)
+        .is_file();
+    if !shipped {
+        info!(
+            "Attract: {} not found, attract mode stays off",
+            attract.footage
+        );
+    }
+    attracting.footage_shipped = shipped;
+}
+
+/// Swap to the attract loop once idle long enough, and back on any input.
+fn run_attract_mode(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    time: Res<Time<Real>>,
+    attract: Res<AttractMode>,
+    last: Res<LastInput>,
+    mut attracting: ResMut<Attracting>,
+    q_bg: Query<Entity, With<MainMenuBg>>,
+    mut q_ui: Query<&mut Visibility, With<MainMenuUI>>,
+) {
+    let idle = attracting.footage_shipped
+        && attract.idle_after > 0.0
+        && time.elapsed_secs() - last.at >= attract.idle_after;
+    if idle == attracting.showing {
+        return;
+    }
+    attracting.showing = idle;
+    let path = if idle {
+        attract.footage.as_str()
+    } else {
+        MENU_BG_PATH
+    };
+    for bg in &q_bg {
+        set_menu_bg(&mut commands, &assets, bg, path);
+    }
+    for mut vis in &mut q_ui {
+        *vis = if idle {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+    }
+}
+
+/// Leaving the menu mid-loop puts the regular background back (Settings and
+/// Controls keep it).
+fn stop_attract_mode(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut attracting: ResMut<Attracting>,
+    q_bg: Query<Entity, With<MainMenuBg>>,
+) {
+    if !attracting.showing {
+        return;
+    }
+    attracting.showing = false;
+    for bg in &q_bg {
+        set_menu_bg(&mut commands, &assets, bg, MENU_BG_PATH);
     }
 }
 
 fn main_menu_buttons(
     mut next: ResMut<NextState<GameState>>,
+    mut transition: ResMut<Transition>,
     mut exit: EventWriter<AppExit>,
-    mut back_target: ResMut<SettingsBackTarget>,
+    mut begin_ng_plus: EventWriter<BeginNewGamePlus>,
+    mut back_target: ResMut<MenuBackTarget>,
     mut q: Query<
         (&Interaction, &mut BackgroundColor, &MainBtn),
         (Changed<Interaction>, With<Button>),
@@ -313,11 +707,19 @@ fn main_menu_buttons(
         set_btn_color(&mut bg, *i);
         if *i == Interaction::Pressed {
             match btn {
-                MainBtn::NewGame => next.set(GameState::InGame),
+                MainBtn::NewGame => transition.to(GameState::InGame),
+                MainBtn::NewGamePlus => {
+                    begin_ng_plus.write(BeginNewGamePlus);
+                    transition.to(GameState::InGame);
+                }
                 MainBtn::Settings => {
                     back_target.0 = GameState::MainMenu;
                     next.set(GameState::Settings);
                 }
+                MainBtn::Controls => {
+                    back_target.0 = GameState::MainMenu;
+                    next.set(GameState::Controls);
+                }
                 MainBtn::Quit => {
                     let _ = exit.write(AppExit::Success);
                 }
@@ -328,24 +730,37 @@ fn main_menu_buttons(
 
 fn settings_buttons(
     mut next: ResMut<NextState<GameState>>,
-    back_target: Res<SettingsBackTarget>,
+    back_target: Res<MenuBackTarget>,
+    mut settings: SettingsParams,
     mut q: Query<
-        (&Interaction, &mut BackgroundColor, &SetBtn),
+        (&Interaction, &mut BackgroundColor, &SetBtn, &Children),
         (Changed<Interaction>, With<Button>),
     >,
+    mut texts: Query<&mut Text>,
 ) {
-    for (i, mut bg, btn) in &mut q {
+    for (i, mut bg, btn, children) in &mut q {
         set_btn_color(&mut bg, *i);
-        if *i == Interaction::Pressed
-            && matches!(btn, SetBtn::Back) {
-                next.set(back_target.0);
+        if *i != Interaction::Pressed {
+            continue;
+        }
+        match btn {
+            SetBtn::Item(item) => {
+                settings.cycle(*item);
+                for &child in children {
+                    if let Ok(mut text) = texts.get_mut(child) {
+                        *text = Text::new(settings.label(*item));
+                    }
+                }
             }
+            SetBtn::Back => next.set(back_target.0),
+        }
     }
 }
 
 fn pause_menu_buttons(
     mut next: ResMut<NextState<GameState>>,
-    mut back_target: ResMut<SettingsBackTarget>,
+    mut transition: ResMut<Transition>,
+    mut back_target: ResMut<MenuBackTarget>,
     mut q: Query<
         (&Interaction, &mut BackgroundColor, &PauseBtn),
         (Changed<Interaction>, With<Button>),
@@ -360,14 +775,34 @@ fn pause_menu_buttons(
                     back_target.0 = GameState::Paused;
                     next.set(GameState::Settings);
                 }
-                PauseBtn::MainMenu => next.set(GameState::MainMenu),
+                PauseBtn::Controls => {
+                    back_target.0 = GameState::Paused;
+                    next.set(GameState::Controls);
+                }
+                PauseBtn::MainMenu => transition.to(GameState::MainMenu),
             }
         }
     }
 }
 
-fn game_over_buttons(
+fn controls_buttons(
     mut next: ResMut<NextState<GameState>>,
+    back_target: Res<MenuBackTarget>,
+    mut q: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ControlsBackBtn>),
+    >,
+) {
+    for (i, mut bg) in &mut q {
+        set_btn_color(&mut bg, *i);
+        if *i == Interaction::Pressed {
+            next.set(back_target.0);
+        }
+    }
+}
+
+fn game_over_buttons(
+    mut transition: ResMut<Transition>,
     mut q: Query<
         (&Interaction, &mut BackgroundColor, &OverBtn),
         (Changed<Interaction>, With<Button>),
@@ -377,8 +812,8 @@ fn game_over_buttons(
         set_btn_color(&mut bg, *i);
         if *i == Interaction::Pressed {
             match btn {
-                OverBtn::TryAgain => next.set(GameState::InGame),
-                OverBtn::MainMenu => next.set(GameState::MainMenu),
+                OverBtn::TryAgain => transition.to(GameState::InGame),
+                OverBtn::MainMenu => transition.to(GameState::MainMenu),
             }
         }
     }
@@ -401,10 +836,56 @@ fn resume_time(mut time: ResMut<Time<Virtual>>) {
     time.unpause();
 }
 
-fn to_game_over_on_death(mut ev: EventReader<PlayerDied>, mut next: ResMut<NextState<GameState>>) {
-    if ev.read().next().is_some() {
-        next.set(GameState::GameOver);
+/// First `PlayerDied` starts the slow-mo; the GameOver fade begins once it
+/// has run for `DEATH_SLOWMO_SECS` of real time.
+fn to_game_over_on_death(
+    mut ev: EventReader<PlayerDied>,
+    mut sequence: ResMut<DeathSequence>,
+    mut transition: ResMut<Transition>,
+    mut scale: ResMut<TimeScaleConfig>,
+    time: Res<Time<Virtual>>,
+    real: Res<Time<Real>>,
+) {
+    let died = ev.read().next().is_some();
+    let Some(timer) = sequence.timer.as_mut() else {
+        if died {
+            sequence.timer = Some(Timer::from_seconds(DEATH_SLOWMO_SECS, TimerMode::Once));
+            scale.gameplay = DEATH_SLOWMO_SPEED;
+        }
+        return;
+    };
+    // Paused mid-sequence: hold
+    if time.is_paused() {
+        return;
+    }
+    if timer.tick(real.delta()).finished() {
+        transition.to(GameState::GameOver);
+    }
+}
+
+fn end_death_sequence(mut sequence: ResMut<DeathSequence>, mut scale: ResMut<TimeScaleConfig>) {
+    sequence.timer = None;
+    scale.gameplay = 1.0;
+}
+
+fn cycle_debug_time_scale(keys: Res<ButtonInput<KeyCode>>, mut scale: ResMut<TimeScaleConfig>) {
+    if !keys.just_pressed(TIME_SCALE_CYCLE) {
+        return;
+    }
+    let next = TIME_SCALE_STEPS
+        .iter()
+        .position(|s| *s > scale.debug + f32::EPSILON)
+        .map_or(TIME_SCALE_STEPS[0], |i| TIME_SCALE_STEPS[i]);
+    scale.debug = next;
+    info!("Time scale: x{next}");
+}
+
+fn apply_time_scale(scale: Res<TimeScaleConfig>, mut time: ResMut<Time<Virtual>>) {
+    if !scale.is_changed() {
+        return;
     }
+    let debug = scale.debug.clamp(TIME_SCALE_MIN, TIME_SCALE_MAX);
+    time.set_relative_speed(debug * scale.gameplay.max(0.0));
 }
 
 fn despawn_ui<T: Component>(mut commands: Commands, q: Query<Entity, With<T>>) {
@@ -419,6 +900,39 @@ pub fn despawn_gameplay(mut commands: Commands, q: Query<Entity, With<GameplayRo
     }
 }
 
+/// Debug builds: run after `despawn_gameplay` and report world sprites and
+/// physics bodies that survived it, i.e. something spawned during the run
+/// without `GameplayRoot` (or a tagged parent). Menus, cameras, the menu
+/// background and pooled entities are expected to stay.
+pub fn audit_gameplay_leaks(
+    q: Query<
+        (Entity, Option<&Name>),
+        (
+            Or<(With<Sprite>, With<Collider>, With<RigidBody>)>,
+            Without<Camera>,
+            Without<Node>,
+            Without<MainMenuBg>,
+            Without<Pooled>,
+        ),
+    >,
+) {
+    if !cfg!(debug_assertions) || q.is_empty() {
+        return;
+    }
+    let leaked: Vec<String> = q
+        .iter()
+        .map(|(e, name)| match name {
+            Some(name) => format!("{name} ({e})"),
+            None => format!("{e}"),
+        })
+        .collect();
+    warn!(
+        "{} gameplay entities outlived teardown (missing GameplayRoot?): {}",
+        leaked.len(),
+        leaked.join(", ")
+    );
+}
+
 fn despawn_menu_bg(mut commands: Commands, q: Query<Entity, With<MainMenuBg>>) {
     for e in &q {
         commands.entity(e).despawn();
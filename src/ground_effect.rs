@@ -0,0 +1,195 @@
+// ground_effect.rs
+use crate::character::{Dead, GameLayer, Player};
+use crate::enemy::{DamageEnemy, Enemy, EnemyDead};
+use crate::enemy_spawner::ground_point_below;
+use crate::gameflow::{GameState, GameplayRoot};
+use crate::hud::DamagePlayer;
+use crate::prelude::*;
+use crate::settings::Palette;
+use avian2d::spatial_query::SpatialQueryFilter;
+use serde::Deserialize;
+
+// Patches fade out over their last this-many seconds (still hurting)
+const GROUND_EFFECT_FADE_SECS: f32 = 0.5;
+// Drawn as a flat strip this tall, centered on the spawn point
+const GROUND_EFFECT_DRAW_HEIGHT: f32 = 6.0;
+const GROUND_EFFECT_ALPHA: f32 = 0.7;
+const GROUND_EFFECT_Z: f32 = 1.0;
+// Trails only drop with ground this close below the dropper
+const GROUND_TRAIL_PROBE_DEPTH: f32 = 64.0;
+
+/// Temporary patch (fire, acid, ...) that damages everything on the
+/// `affects` layers standing inside `radius`, `dps` per second, for
+/// `lifetime` seconds. Players take it as `DamagePlayer::tick`, enemies as
+/// `DamageEnemy`.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct GroundEffect {
+    pub radius: f32,
+    pub dps: f32,
+    pub lifetime: f32,
+    pub affects: LayerMask,
+}
+
+#[derive(Component)]
+struct GroundEffectAge(Timer);
+
+/// Leaves a `GroundEffect` behind every `interval` seconds while moving
+/// (fire trails). Enabled per class via `ground_trail` in the enemy class
+/// JSON; the patches only hurt the player.
+#[derive(Component, Debug, Clone, Copy, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct GroundTrail {
+    pub radius: f32,
+    pub dps: f32,
+    pub lifetime: f32,
+    pub interval: f32,
+}
+
+#[derive(Component)]
+struct GroundTrailTimer(Timer);
+
+/// Spawn a patch at `pos` tinted `color`. The sensor sits on no layer, so
+/// nothing collides with or queries it; it only defines the area.
+pub fn spawn_ground_effect(
+    commands: &mut Commands,
+    pos: Vec2,
+    effect: GroundEffect,
+    color: Color,
+) -> Entity {
+    let radius = effect.radius.max(1.0);
+    let lifetime = effect.lifetime.max(0.0);
+    commands
+        .spawn((
+            Name::new("GroundEffect"),
+            GroundEffectAge(Timer::from_seconds(lifetime, TimerMode::Once)),
+            effect,
+            RigidBody::Static,
+            Collider::circle(radius),
+            Sensor,
+            CollisionLayers::new(LayerMask::NONE, LayerMask::NONE),
+            Sprite {
+                color: color.with_alpha(GROUND_EFFECT_ALPHA),
+                custom_size: Some(Vec2::new(radius * 2.0, GROUND_EFFECT_DRAW_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(GROUND_EFFECT_Z)),
+            GameplayRoot,
+        ))
+        .id()
+}
+
+fn apply_ground_effects(
+    time: Res<Time>,
+    spatial: SpatialQuery,
+    effects: Query<(&GroundEffect, &Collider, &GlobalTransform)>,
+    players: Query<(), (With<Player>, Without<Dead>)>,
+    enemies: Query<(), (With<Enemy>, Without<EnemyDead>)>,
+    mut damage_player: EventWriter<DamagePlayer>,
+    mut damage_enemy: EventWriter<DamageEnemy>,
+) {
+    let dt = time.delta_secs();
+    for (effect, collider, gt) in &effects {
+        let amount = effect.dps * dt;
+        if amount <= 0.0 {
+            continue;
+        }
+        let filter = SpatialQueryFilter::from_mask(effect.affects);
+        let pos = gt.translation().truncate();
+        for hit in spatial.shape_intersections(collider, pos, 0.0, &filter) {
+            if players.contains(hit) {
+                damage_player.write(DamagePlayer::tick(amount));
+            } else if enemies.contains(hit) {
+                damage_enemy.write(DamageEnemy {
+                    target: hit,
+                    amount,
+                });
+            }
+        }
+    }
+}
+
+fn age_ground_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut GroundEffectAge, &mut Sprite)>,
+) {
+    for (e, mut age, mut sprite) in &mut q {
+        age.0.tick(time.delta());
+        if age.0.finished() {
+            commands.entity(e).despawn();
+            continue;
+        }
+        let left = age.0.remaining_secs();
+        let fade = (left / GROUND_EFFECT_FADE_SECS).min(1.0);
+        sprite.color.set_alpha(GROUND_EFFECT_ALPHA * fade);
+    }
+}
+
+fn drop_ground_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    palette: Res<Palette>,
+    spatial: SpatialQuery,
+    mut q: Query<
+        (
+            Entity,
+            &GroundTrail,
+            &GlobalTransform,
+            &LinearVelocity,
+            Option<&mut GroundTrailTimer>,
+        ),
+        Without<EnemyDead>,
+    >,
+) {
+    for (e, trail, gt, vel, timer) in &mut q {
+        let Some(mut timer) = timer else {
+            commands
+                .entity(e)
+                .insert(GroundTrailTimer(Timer::from_seconds(
+                    trail.interval.max(0.05),
+                    TimerMode::Repeating,
+                )));
+            continue;
+        };
+        if !timer.0.tick(time.delta()).just_finished() || vel.x.abs() < 1.0 {
+            continue;
+        }
+        let start = gt.translation().truncate();
+        let Some(pos) = ground_point_below(
+            &spatial,
+            start,
+            GROUND_TRAIL_PROBE_DEPTH,
+            GROUND_EFFECT_DRAW_HEIGHT * 0.5,
+        ) else {
+            continue;
+        };
+        spawn_ground_effect(
+            &mut commands,
+            pos,
+            GroundEffect {
+                radius: trail.radius,
+                dps: trail.dps,
+                lifetime: trail.lifetime,
+                affects: LayerMask::from(GameLayer::Player),
+            },
+            palette.danger,
+        );
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct GroundEffectPlugin;
+
+impl Plugin for GroundEffectPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<GroundEffect>()
+            .register_type::<GroundTrail>()
+            .add_systems(
+                Update,
+                (drop_ground_trails, apply_ground_effects, age_ground_effects)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
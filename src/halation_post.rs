@@ -127,6 +127,9 @@ impl FromWorld for HalationPipeline {
     }
 }
 
+/// Runs once per camera view. `ViewNodeRunner` skips any view whose entity
+/// doesn't match `ViewQuery`, so cameras without `HalationSettings` (menu, HUD,
+/// a minimap or picture-in-picture view) pass through untouched.
 #[derive(Default)]
 struct HalationNode;
 
@@ -177,6 +180,7 @@ impl ViewNode for HalationNode {
         });
 
         pass.set_render_pipeline(gpu_pipeline);
+        // This view's slot in the shared uniform buffer
         pass.set_bind_group(0, &bind_group, &[dyn_index.index()]);
         pass.draw(0..3, 0..1);
 
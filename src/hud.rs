@@ -1,25 +1,59 @@
 use crate::class::{ClassAttachTarget, PlayerClass};
+use crate::focus::Focus;
 use crate::gameflow::GameState;
 use crate::gameflow::GameplayRoot;
+use crate::pickup::Wallet;
 use crate::prelude::*;
+use crate::settings::{HudAnchor, HudConfig, JuiceConfig, Palette};
+use crate::status::{StatusEffects, StatusKind};
+use crate::widgets::{Bar, spawn_bar};
 use bevy::ui::GlobalZIndex;
 
+// Gap between the HUD and the window edge it's anchored to (before scaling)
+const HUD_MARGIN_PX: f32 = 12.0;
+const HEAL_FLASH_SECS: f32 = 0.3;
+// Window height the HUD's pixel sizes were laid out for (the default 1280×720 window)
+const HUD_REFERENCE_HEIGHT: f32 = 720.0;
+const SHIELD_FLASH_SECS: f32 = 0.25;
+// No stamina regen for this long after the shield soaks a hit
+const SHIELD_REGEN_DELAY_SECS: f32 = 1.0;
+const FLOATING_TEXT_SECS: f32 = 0.8;
+const FLOATING_TEXT_RISE: f32 = 30.0; // units per second
+const FLOATING_TEXT_Y: f32 = 36.0; // above the player's center
+
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayerStats>()
+            .add_event::<DamagePlayer>()
+            .add_event::<HealPlayer>()
+            .add_event::<ShieldAbsorbed>()
             .init_resource::<HudClassSyncState>()
+            .init_resource::<HealFlash>()
+            .init_resource::<HudWindowScale>()
+            .init_resource::<StaminaRegen>()
             .add_systems(OnEnter(GameState::InGame), spawn_hud)
             .add_systems(OnExit(GameState::InGame), despawn_hud)
             .add_systems(
                 Update,
                 (
                     sync_player_stats_from_class,
+                    apply_heal_to_player,
+                    react_to_shield_absorb,
+                    regen_stamina,
                     update_health_bar,
                     update_stamina_bar,
+                    update_focus_bar,
                     update_health_text,
                     update_stamina_text,
+                    update_coin_text,
+                    update_status_icons,
+                    rebuild_hud_on_config_change,
+                    apply_palette_to_hud,
+                    flash_health_bar_on_heal,
+                    flash_stamina_bar_on_absorb,
+                    tick_floating_text,
                 )
                     .chain()
                     .run_if(in_state(GameState::InGame)),
@@ -48,6 +82,64 @@ impl Default for PlayerStats {
     }
 }
 
+/// Request to hurt the player. Every health loss goes through this event so
+/// defense, stagger and knockback are applied in one place.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DamagePlayer {
+    pub amount: f32,
+    /// Where the damage came from; drives knockback direction.
+    pub source_pos: Option<Vec2>,
+    /// Skip the class defense reduction (already applied by the sender).
+    pub ignore_defense: bool,
+    /// Interrupt the player with stun + knockback.
+    pub stagger: bool,
+}
+
+impl DamagePlayer {
+    /// A regular hit: reduced by defense, staggers the player.
+    pub fn hit(amount: f32, source_pos: Option<Vec2>) -> Self {
+        Self {
+            amount,
+            source_pos,
+            ignore_defense: false,
+            stagger: true,
+        }
+    }
+
+    /// Reduced by defense but never staggers (a grappler's hold).
+    pub fn held(amount: f32, source_pos: Option<Vec2>) -> Self {
+        Self {
+            amount,
+            source_pos,
+            ignore_defense: false,
+            stagger: false,
+        }
+    }
+
+    /// Pre-reduced damage with no stagger (damage-over-time ticks).
+    pub fn tick(amount: f32) -> Self {
+        Self {
+            amount,
+            source_pos: None,
+            ignore_defense: true,
+            stagger: false,
+        }
+    }
+}
+
+/// The stamina shield soaked `amount` of a hit (see `BaseStats::stamina_shield`).
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ShieldAbsorbed {
+    pub amount: f32,
+}
+
+/// Request to heal the player. Pickups, regen and abilities all go through
+/// this so clamping and feedback live in one place.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct HealPlayer {
+    pub amount: f32,
+}
+
 #[derive(Resource, Default)]
 struct HudClassSyncState {
     last_class_id: Option<String>,
@@ -58,9 +150,51 @@ struct HealthFill;
 #[derive(Component, Default)]
 struct StaminaFill;
 #[derive(Component, Default)]
+struct FocusFill;
+#[derive(Component, Default)]
 struct HealthText;
 #[derive(Component, Default)]
 struct StaminaText;
+#[derive(Component, Default)]
+struct CoinText;
+#[derive(Component)]
+struct StatusIcon(StatusKind);
+
+/// HUD size factor from the window height (1.0 at `HUD_REFERENCE_HEIGHT`),
+/// kept current by the window resize handler in gameflow.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct HudWindowScale(pub f32);
+
+impl Default for HudWindowScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl HudWindowScale {
+    pub fn from_height(height: f32) -> Self {
+        Self((height / HUD_REFERENCE_HEIGHT).clamp(0.25, 4.0))
+    }
+}
+
+/// Health bar highlight after a heal.
+#[derive(Resource, Default)]
+struct HealFlash(Option<Timer>);
+
+/// Stamina refills at the class rate unless a shielded hit paused it; the
+/// highlight shows the shield taking a hit.
+#[derive(Resource, Default)]
+struct StaminaRegen {
+    delay: Option<Timer>,
+    flash: Option<Timer>,
+}
+
+/// World-space number that drifts up and fades out.
+#[derive(Component)]
+struct FloatingText {
+    timer: Timer,
+    color: Color,
+}
 
 fn sync_player_stats_from_class(
     mut stats: ResMut<PlayerStats>,
@@ -84,17 +218,236 @@ fn sync_player_stats_from_class(
     }
 }
 
-fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
+/// Sum this frame's heals, clamp to max health and show the amount actually
+/// restored. Ignored once the player is dead.
+fn apply_heal_to_player(
+    mut commands: Commands,
+    mut events: EventReader<HealPlayer>,
+    mut stats: ResMut<PlayerStats>,
+    mut flash: ResMut<HealFlash>,
+    assets: Res<AssetServer>,
+    palette: Res<Palette>,
+    juice: Res<JuiceConfig>,
+    q_player: Query<&GlobalTransform, With<ClassAttachTarget>>,
+) {
+    let requested: f32 = events.read().map(|ev| ev.amount.max(0.0)).sum();
+    if requested <= 0.0 || stats.health <= 0.0 {
+        return;
+    }
+    let before = stats.health;
+    stats.health = (stats.health + requested).min(stats.max_health);
+    let healed = stats.health - before;
+    if healed <= 0.0 {
+        return;
+    }
+
+    flash.0 = Some(Timer::from_seconds(HEAL_FLASH_SECS, TimerMode::Once));
+    if !juice.damage_numbers {
+        return;
+    }
+    if let Ok(tf) = q_player.single() {
+        spawn_floating_text(
+            &mut commands,
+            &assets,
+            tf.translation(),
+            format!("+{:.0}", healed),
+            palette.heal,
+        );
+    }
+}
+
+fn react_to_shield_absorb(
+    mut events: EventReader<ShieldAbsorbed>,
+    mut regen: ResMut<StaminaRegen>,
+) {
+    let soaked: f32 = events.read().map(|ev| ev.amount).sum();
+    if soaked <= 0.0 {
+        return;
+    }
+    regen.delay = Some(Timer::from_seconds(
+        SHIELD_REGEN_DELAY_SECS,
+        TimerMode::Once,
+    ));
+    regen.flash = Some(Timer::from_seconds(SHIELD_FLASH_SECS, TimerMode::Once));
+}
+
+fn regen_stamina(
+    time: Res<Time>,
+    mut stats: ResMut<PlayerStats>,
+    mut regen: ResMut<StaminaRegen>,
+    q_class: Query<&PlayerClass, With<ClassAttachTarget>>,
+) {
+    if let Some(delay) = regen.delay.as_mut() {
+        if !delay.tick(time.delta()).finished() {
+            return;
+        }
+        regen.delay = None;
+    }
+    let Ok(pc) = q_class.single() else {
+        return;
+    };
+    if stats.health <= 0.0 || stats.stamina >= stats.max_stamina {
+        return;
+    }
+    let rate = pc.0.base_stats.stamina_regen_per_s.max(0.0);
+    stats.stamina = (stats.stamina + rate * time.delta_secs()).min(stats.max_stamina);
+}
+
+/// World-space label above `at` that rises and fades (heal numbers, level-ups).
+pub fn spawn_floating_text(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    at: Vec3,
+    text: impl Into<String>,
+    color: Color,
+) {
+    commands.spawn((
+        Text2d::new(text),
+        TextFont {
+            font: assets.load("fonts/GohuFont14NerdFontMono-Regular.ttf"),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(color),
+        Transform::from_translation(at + Vec3::new(0.0, FLOATING_TEXT_Y, 5.0)),
+        FloatingText {
+            timer: Timer::from_seconds(FLOATING_TEXT_SECS, TimerMode::Once),
+            color,
+        },
+        GameplayRoot,
+        Name::new("FloatingText"),
+    ));
+}
+
+fn tick_floating_text(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut FloatingText, &mut Transform, &mut TextColor)>,
+) {
+    for (e, mut ft, mut tf, mut color) in &mut q {
+        ft.timer.tick(time.delta());
+        if ft.timer.finished() {
+            commands.entity(e).despawn();
+            continue;
+        }
+        tf.translation.y += FLOATING_TEXT_RISE * time.delta_secs();
+        color.0 = ft.color.with_alpha(ft.timer.fraction_remaining());
+    }
+}
+
+fn flash_health_bar_on_heal(
+    time: Res<Time>,
+    palette: Res<Palette>,
+    juice: Res<JuiceConfig>,
+    mut flash: ResMut<HealFlash>,
+    mut q: Query<&mut BackgroundColor, With<HealthFill>>,
+) {
+    let Some(timer) = flash.0.as_mut() else {
+        return;
+    };
+    timer.tick(time.delta());
+    let color = if timer.finished() || !juice.flashes {
+        flash.0 = None;
+        palette.health
+    } else {
+        palette.heal.mix(&palette.health, timer.fraction())
+    };
+    for mut bg in &mut q {
+        bg.0 = color;
+    }
+}
+
+fn flash_stamina_bar_on_absorb(
+    time: Res<Time>,
+    palette: Res<Palette>,
+    juice: Res<JuiceConfig>,
+    mut regen: ResMut<StaminaRegen>,
+    mut q: Query<&mut BackgroundColor, With<StaminaFill>>,
+) {
+    let Some(timer) = regen.flash.as_mut() else {
+        return;
+    };
+    timer.tick(time.delta());
+    let color = if timer.finished() || !juice.flashes {
+        regen.flash = None;
+        palette.stamina
+    } else {
+        palette.hit_flash.mix(&palette.stamina, timer.fraction())
+    };
+    for mut bg in &mut q {
+        bg.0 = color;
+    }
+}
+
+fn spawn_hud(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    palette: Res<Palette>,
+    hud: Res<HudConfig>,
+    window: Res<HudWindowScale>,
+) {
+    build_hud(&mut commands, &assets, &palette, &hud, *window);
+}
+
+/// Rebuild the HUD when its layout settings or the window size change.
+fn rebuild_hud_on_config_change(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    palette: Res<Palette>,
+    hud: Res<HudConfig>,
+    window: Res<HudWindowScale>,
+    q: Query<Entity, With<HudRoot>>,
+) {
+    let changed = (hud.is_changed() && !hud.is_added()) || window.is_changed();
+    if !changed || q.is_empty() {
+        return;
+    }
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+    build_hud(&mut commands, &assets, &palette, &hud, *window);
+}
+
+/// Root placed per `HudConfig::anchor`; every size is multiplied by `scale`.
+fn build_hud(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    palette: &Palette,
+    hud: &HudConfig,
+    window: HudWindowScale,
+) {
+    let scale = if hud.scale_with_window {
+        hud.scale * window.0
+    } else {
+        hud.scale
+    };
+    let px = |v: f32| Val::Px(v * scale);
+    let margin = px(HUD_MARGIN_PX);
+    let mut root_node = Node {
+        position_type: PositionType::Absolute,
+        flex_direction: FlexDirection::Column,
+        row_gap: px(6.0),
+        ..default()
+    };
+    match hud.anchor {
+        HudAnchor::TopLeft => {
+            root_node.top = margin;
+            root_node.left = margin;
+        }
+        HudAnchor::TopRight => {
+            root_node.top = margin;
+            root_node.right = margin;
+            root_node.align_items = AlignItems::FlexEnd;
+        }
+        HudAnchor::BottomLeft => {
+            root_node.bottom = margin;
+            root_node.left = margin;
+        }
+    }
     let root = commands
         .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                top: Val::Px(12.0),
-                left: Val::Px(12.0),
-                flex_direction: FlexDirection::Column,
-                row_gap: Val::Px(6.0),
-                ..default()
-            },
+            root_node,
+            HudRoot,
             GameplayRoot,
             GlobalZIndex(1),
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.0)),
@@ -108,7 +461,7 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
             Node {
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
-                column_gap: Val::Px(8.0),
+                column_gap: px(8.0),
                 ..default()
             },
             BackgroundColor(Color::NONE),
@@ -120,37 +473,21 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
             Text::new("HP"),
             TextFont {
                 font: font.clone(),
-                font_size: 14.0,
+                font_size: 14.0 * scale,
                 ..default()
             },
             TextColor(Color::WHITE),
         ))
         .id();
 
-    let hp_container = commands
-        .spawn((
-            Node {
-                width: Val::Px(220.0),
-                height: Val::Px(18.0),
-                border: UiRect::all(Val::Px(2.0)),
-                ..default()
-            },
-            BorderColor(Color::srgb(0.1, 0.1, 0.1)),
-            BackgroundColor(Color::srgb(0.05, 0.05, 0.05)),
-        ))
-        .id();
-
-    let hp_fill = commands
-        .spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.85, 0.2, 0.2)),
-            HealthFill,
-        ))
-        .id();
+    let (hp_container, hp_fill) = spawn_bar(
+        commands,
+        220.0 * scale,
+        18.0 * scale,
+        palette.health,
+        palette.bar_bg,
+    );
+    commands.entity(hp_fill).insert(HealthFill);
 
     let hp_text_overlay = commands
         .spawn((
@@ -173,7 +510,7 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
             Text::new("100/100"),
             TextFont {
                 font: font.clone(),
-                font_size: 12.0,
+                font_size: 12.0 * scale,
                 ..default()
             },
             TextColor(Color::WHITE),
@@ -182,9 +519,7 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
         .id();
 
     commands.entity(hp_text_overlay).add_child(hp_text);
-    commands
-        .entity(hp_container)
-        .add_children(&[hp_fill, hp_text_overlay]);
+    commands.entity(hp_container).add_child(hp_text_overlay);
     commands
         .entity(hp_row)
         .add_children(&[hp_label, hp_container]);
@@ -194,7 +529,7 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
             Node {
                 flex_direction: FlexDirection::Row,
                 align_items: AlignItems::Center,
-                column_gap: Val::Px(8.0),
+                column_gap: px(8.0),
                 ..default()
             },
             BackgroundColor(Color::NONE),
@@ -206,37 +541,21 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
             Text::new("SP"),
             TextFont {
                 font: font.clone(),
-                font_size: 14.0,
+                font_size: 14.0 * scale,
                 ..default()
             },
             TextColor(Color::WHITE),
         ))
         .id();
 
-    let sp_container = commands
-        .spawn((
-            Node {
-                width: Val::Px(220.0),
-                height: Val::Px(18.0),
-                border: UiRect::all(Val::Px(2.0)),
-                ..default()
-            },
-            BorderColor(Color::srgb(0.1, 0.1, 0.1)),
-            BackgroundColor(Color::srgb(0.05, 0.05, 0.05)),
-        ))
-        .id();
-
-    let sp_fill = commands
-        .spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.72, 0.53, 0.04)),
-            StaminaFill,
-        ))
-        .id();
+    let (sp_container, sp_fill) = spawn_bar(
+        commands,
+        220.0 * scale,
+        18.0 * scale,
+        palette.stamina,
+        palette.bar_bg,
+    );
+    commands.entity(sp_fill).insert(StaminaFill);
 
     let sp_text_overlay = commands
         .spawn((
@@ -258,8 +577,8 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
         .spawn((
             Text::new("100/100"),
             TextFont {
-                font,
-                font_size: 11.0,
+                font: font.clone(),
+                font_size: 11.0 * scale,
                 ..default()
             },
             TextColor(Color::WHITE),
@@ -269,27 +588,121 @@ fn spawn_hud(mut commands: Commands, assets: Res<AssetServer>) {
 
     commands.entity(sp_text_overlay).add_child(sp_text);
 
-    commands
-        .entity(sp_container)
-        .add_children(&[sp_fill, sp_text_overlay]);
+    commands.entity(sp_container).add_child(sp_text_overlay);
     commands
         .entity(sp_row)
         .add_children(&[sp_label, sp_container]);
 
-    commands.entity(root).add_children(&[hp_row, sp_row]);
+    let status_row = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: px(4.0),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .id();
+
+    for kind in StatusKind::ALL {
+        let icon = commands
+            .spawn((
+                Node {
+                    display: Display::None,
+                    min_width: px(22.0),
+                    height: px(18.0),
+                    padding: UiRect::horizontal(px(3.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderColor(Color::srgb(0.1, 0.1, 0.1)),
+                BackgroundColor(kind.color(palette)),
+                StatusIcon(kind),
+            ))
+            .with_child((
+                Text::new(kind.glyph()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 12.0 * scale,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ))
+            .id();
+        commands.entity(status_row).add_child(icon);
+    }
+
+    let fp_row = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .id();
+
+    let fp_label = commands
+        .spawn((
+            Text::new("FP"),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0 * scale,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ))
+        .id();
+
+    let (fp_container, fp_fill) = spawn_bar(
+        commands,
+        220.0 * scale,
+        10.0 * scale,
+        palette.focus,
+        palette.bar_bg,
+    );
+    commands.entity(fp_fill).insert(FocusFill);
+    commands
+        .entity(fp_row)
+        .add_children(&[fp_label, fp_container]);
+
+    let coin_text = commands
+        .spawn((
+            Text::new("0"),
+            TextFont {
+                font: font.clone(),
+                font_size: 14.0 * scale,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.82, 0.2)),
+            CoinText,
+        ))
+        .id();
+
+    commands
+        .entity(root)
+        .add_children(&[hp_row, sp_row, fp_row, status_row, coin_text]);
 }
 
-fn update_health_bar(stats: Res<PlayerStats>, mut q: Query<&mut Node, With<HealthFill>>) {
-    if let Ok(mut node) = q.single_mut() {
-        let pct = (stats.health / stats.max_health).clamp(0.0, 1.0) * 100.0;
-        node.width = Val::Percent(pct);
+fn update_health_bar(stats: Res<PlayerStats>, mut q: Query<&mut Bar, With<HealthFill>>) {
+    if let Ok(mut bar) = q.single_mut() {
+        bar.fraction = stats.health / stats.max_health;
     }
 }
 
-fn update_stamina_bar(stats: Res<PlayerStats>, mut q: Query<&mut Node, With<StaminaFill>>) {
-    if let Ok(mut node) = q.single_mut() {
-        let pct = (stats.stamina / stats.max_stamina).clamp(0.0, 1.0) * 100.0;
-        node.width = Val::Percent(pct);
+fn update_stamina_bar(stats: Res<PlayerStats>, mut q: Query<&mut Bar, With<StaminaFill>>) {
+    if let Ok(mut bar) = q.single_mut() {
+        bar.fraction = stats.stamina / stats.max_stamina;
+    }
+}
+
+fn update_focus_bar(focus: Res<Focus>, mut q: Query<&mut Bar, With<FocusFill>>) {
+    if let Ok(mut bar) = q.single_mut() {
+        bar.fraction = focus.fraction();
     }
 }
 
@@ -307,6 +720,82 @@ fn update_stamina_text(stats: Res<PlayerStats>, mut q: Query<&mut Text, With<Sta
     }
 }
 
+fn update_coin_text(wallet: Res<Wallet>, mut q: Query<&mut Text, With<CoinText>>) {
+    if let Ok(mut text) = q.single_mut() {
+        let shown = format!("$ {}", wallet.coins);
+        if text.0 != shown {
+            text.0 = shown;
+        }
+    }
+}
+
+/// One icon per active status kind, with the stack count when above one.
+fn update_status_icons(
+    q_player: Query<&StatusEffects>,
+    mut q_icons: Query<(&StatusIcon, &mut Node, &Children)>,
+    mut q_text: Query<&mut Text>,
+) {
+    let effects = q_player.single().ok();
+    for (icon, mut node, children) in &mut q_icons {
+        let stacks = effects.map(|s| s.stacks(icon.0)).unwrap_or(0);
+        let display = if stacks > 0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        let label = if stacks > 1 {
+            format!("{}{}", icon.0.glyph(), stacks)
+        } else {
+            icon.0.glyph().to_string()
+        };
+        for &child in children {
+            let Ok(mut text) = q_text.get_mut(child) else {
+                continue;
+            };
+            if text.0 != label {
+                text.0 = label.clone();
+            }
+        }
+    }
+}
+
+/// Recolor the HUD when the palette preset changes.
+fn apply_palette_to_hud(
+    palette: Res<Palette>,
+    q_fills: Query<
+        (Entity, &ChildOf, Has<HealthFill>, Has<FocusFill>),
+        Or<(With<HealthFill>, With<StaminaFill>, With<FocusFill>)>,
+    >,
+    q_icons: Query<(Entity, &StatusIcon)>,
+    mut q_bg: Query<&mut BackgroundColor>,
+) {
+    if !palette.is_changed() {
+        return;
+    }
+    for (fill, child_of, is_health, is_focus) in &q_fills {
+        if let Ok(mut bg) = q_bg.get_mut(fill) {
+            bg.0 = if is_health {
+                palette.health
+            } else if is_focus {
+                palette.focus
+            } else {
+                palette.stamina
+            };
+        }
+        if let Ok(mut bg) = q_bg.get_mut(child_of.parent()) {
+            bg.0 = palette.bar_bg;
+        }
+    }
+    for (icon, kind) in &q_icons {
+        if let Ok(mut bg) = q_bg.get_mut(icon) {
+            bg.0 = kind.0.color(&palette);
+        }
+    }
+}
+
 fn despawn_hud(mut commands: Commands, q: Query<Entity, With<HudRoot>>) {
     for e in &q {
         commands.entity(e).despawn();
@@ -1,6 +1,13 @@
-use crate::character::Player;
+use crate::ambient::{AmbientKind, AmbientParticles};
+use crate::character::{Action, GameLayer, Player};
+use crate::enemy::{spawn_enemy, spawn_mimic, spawn_training_dummy};
+use crate::enemy_class::EnemyClassId;
+use crate::enemy_spawner::{EnemySpawner, tilemap_world_aabb};
 use crate::gameflow::GameplayRoot;
+use crate::mimic::MIMIC_DEFAULT_DELAY;
 use crate::prelude::*;
+use avian2d::collision::collider::{CollisionLayers, LayerMask};
+use bevy_ecs_tiled::prelude::tiled::PropertyValue;
 use bevy::ecs::{
     entity::hash_set::EntityHashSet,
     system::{SystemParam, lifetimeless::Read},
@@ -14,6 +21,38 @@ pub fn spawn_map(mut commands: Commands, asset_server: Res<AssetServer>) {
             GameplayRoot,
             TilemapAnchor::CenterLeft,
         ))
+        .observe(
+            |ev: Trigger<TiledEvent<MapCreated>>,
+             mut commands: Commands,
+             maps: Res<Assets<TiledMapAsset>>| {
+                let Some(map) = ev.event().get_map(&maps) else {
+                    return;
+                };
+                if let Some(PropertyValue::BoolValue(true)) = map.properties.get("bound_walls") {
+                    commands.entity(ev.event().origin).insert(PendingBoundWalls);
+                }
+
+                // Ambient particles: `ambient` = dust/leaves/embers/none, `ambient_density`
+                let mut ambient = AmbientParticles::default();
+                if let Some(PropertyValue::StringValue(name)) = map.properties.get("ambient") {
+                    match AmbientKind::from_name(name) {
+                        Some(kind) => ambient.kind = kind,
+                        None => {
+                            if !name.eq_ignore_ascii_case("none") {
+                                warn!("Unknown ambient particle kind '{name}'");
+                            }
+                            ambient.density = 0.0;
+                        }
+                    }
+                }
+                match map.properties.get("ambient_density") {
+                    Some(PropertyValue::FloatValue(d)) => ambient.density = *d,
+                    Some(PropertyValue::IntValue(d)) => ambient.density = *d as f32,
+                    _ => {}
+                }
+                commands.insert_resource(ambient);
+            },
+        )
         .observe(
             |ev: Trigger<TiledEvent<ColliderCreated>>, mut commands: Commands| {
                 commands
@@ -29,6 +68,42 @@ pub fn spawn_map(mut commands: Commands, asset_server: Res<AssetServer>) {
                     let is_streetlight = obj.user_type.eq_ignore_ascii_case("StreetLight")
                         || obj.name.eq_ignore_ascii_case("StreetLight");
 
+                    let is_enemy = obj.user_type.eq_ignore_ascii_case("Enemy")
+                        || obj.name.eq_ignore_ascii_case("Enemy");
+
+                    let is_dummy = obj.user_type.eq_ignore_ascii_case("TrainingDummy")
+                        || obj.name.eq_ignore_ascii_case("TrainingDummy");
+
+                    let is_mimic = obj.user_type.eq_ignore_ascii_case("Mimic")
+                        || obj.name.eq_ignore_ascii_case("Mimic");
+
+                    if is_enemy || is_dummy || is_mimic {
+                        let class = match obj.properties.get("class") {
+                            Some(PropertyValue::StringValue(s)) if !s.is_empty() => {
+                                Some(s.clone())
+                            }
+                            _ => None,
+                        };
+                        let patrol_width = match obj.properties.get("patrol_width") {
+                            Some(PropertyValue::FloatValue(w)) => *w,
+                            Some(PropertyValue::IntValue(w)) => *w as f32,
+                            _ => DEFAULT_PATROL_WIDTH,
+                        };
+                        let mimic_delay = is_mimic.then(|| match obj.properties.get("delay") {
+                            Some(PropertyValue::FloatValue(d)) => *d,
+                            Some(PropertyValue::IntValue(d)) => *d as f32,
+                            _ => MIMIC_DEFAULT_DELAY,
+                        });
+                        commands
+                            .entity(ev.event().origin)
+                            .insert(EnemySpawnMarker {
+                                class,
+                                patrol_width,
+                                dummy: is_dummy,
+                                mimic_delay,
+                            });
+                    }
+
                     if is_streetlight {
                         commands.entity(ev.event().origin).insert(SpotLight2d {
                             color: Srgba::hex("#FABD8A").unwrap().into(),
@@ -48,6 +123,213 @@ pub fn spawn_map(mut commands: Commands, asset_server: Res<AssetServer>) {
         );
 }
 
+// ───────── Level bounds ─────────
+const BOUND_WALL_THICKNESS: f32 = 32.0;
+
+/// Map asked for invisible walls around its bounds (bool map property
+/// `bound_walls`). Left open by default so pits can drop into a death plane.
+#[derive(Component)]
+pub struct PendingBoundWalls;
+
+/// Static walls on the left, right and top edges of the tilemap AABB. The
+/// bottom stays open so falling out of the level still works.
+pub fn spawn_level_bound_walls(
+    mut commands: Commands,
+    pending: Query<Entity, With<PendingBoundWalls>>,
+    layers: Query<(
+        &TilemapSize,
+        &TilemapGridSize,
+        &TilemapTileSize,
+        &TilemapType,
+        Option<&TilemapAnchor>,
+        &GlobalTransform,
+    )>,
+) {
+    let Ok(map) = pending.single() else {
+        return;
+    };
+    // Wait for the tile layers (all share size/grid/anchor)
+    let Some((size, grid, tile, ty, anchor, gt)) = layers.iter().next() else {
+        return;
+    };
+    let anchor = anchor.copied().unwrap_or(TilemapAnchor::BottomLeft);
+    let (min, max) = tilemap_world_aabb(size, grid, tile, ty, anchor, gt);
+    let (w, h) = (max.x - min.x, max.y - min.y);
+    let t = BOUND_WALL_THICKNESS;
+
+    let walls = [
+        (
+            "LevelWallLeft",
+            Vec2::new(min.x - t / 2.0, min.y + h / 2.0),
+            Vec2::new(t, h + 2.0 * t),
+        ),
+        (
+            "LevelWallRight",
+            Vec2::new(max.x + t / 2.0, min.y + h / 2.0),
+            Vec2::new(t, h + 2.0 * t),
+        ),
+        (
+            "LevelCeiling",
+            Vec2::new(min.x + w / 2.0, max.y + t / 2.0),
+            Vec2::new(w, t),
+        ),
+    ];
+    for (name, center, extents) in walls {
+        commands.spawn((
+            RigidBody::Static,
+            Collider::rectangle(extents.x, extents.y),
+            CollisionLayers::new(LayerMask::from(GameLayer::Default), LayerMask::ALL),
+            Friction::ZERO,
+            Transform::from_translation(center.extend(0.0)),
+            GameplayRoot,
+            Name::new(name),
+        ));
+    }
+    commands.entity(map).remove::<PendingBoundWalls>();
+}
+
+// ───────── Spawn safety ─────────
+// Free-spot search: rings of this step, up to this many
+const SPAWN_NUDGE_STEP: f32 = 8.0;
+const SPAWN_NUDGE_MAX_STEPS: u32 = 32;
+// Stop waiting for level colliders after this long
+const SPAWN_CHECK_TIMEOUT_SECS: f32 = 2.0;
+const SPAWN_GROUND_PROBE: f32 = 4000.0;
+
+/// Body whose spawn spot still has to be checked against level geometry.
+/// Once the level colliders exist it is moved to the nearest free spot
+/// (upward first, then sideways) and the component is removed.
+#[derive(Component)]
+pub struct SpawnCheck(Timer);
+
+impl Default for SpawnCheck {
+    fn default() -> Self {
+        Self(Timer::from_seconds(
+            SPAWN_CHECK_TIMEOUT_SECS,
+            TimerMode::Once,
+        ))
+    }
+}
+
+pub fn resolve_spawn_overlaps(
+    time: Res<Time>,
+    mut commands: Commands,
+    spatial: SpatialQuery,
+    mut q: Query<(
+        Entity,
+        &mut SpawnCheck,
+        &Collider,
+        &mut Transform,
+        Option<&mut LinearVelocity>,
+    )>,
+) {
+    let terrain = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Default));
+    for (e, mut check, collider, mut tf, vel) in &mut q {
+        let pos = tf.translation.truncate();
+        let blocked = |p: Vec2| {
+            !spatial
+                .shape_intersections(collider, p, 0.0, &terrain)
+                .is_empty()
+        };
+
+        // Nothing overlapping and no ground below yet: the level isn't built
+        let level_ready = blocked(pos)
+            || spatial
+                .cast_ray(pos, Dir2::NEG_Y, SPAWN_GROUND_PROBE, true, &terrain)
+                .is_some();
+        if !level_ready {
+            if check.0.tick(time.delta()).finished() {
+                warn!("SpawnCheck: no level geometry around {pos}; leaving spawn as is");
+                commands.entity(e).remove::<SpawnCheck>();
+            }
+            continue;
+        }
+
+        match nearest_free_spot(pos, blocked) {
+            Some(free) if free != pos => {
+                info!("SpawnCheck: {pos} is inside geometry; moved to {free}");
+                tf.translation.x = free.x;
+                tf.translation.y = free.y;
+                if let Some(mut vel) = vel {
+                    vel.0 = Vec2::ZERO;
+                }
+            }
+            Some(_) => {}
+            None => warn!("SpawnCheck: no free spot near {pos}"),
+        }
+        commands.entity(e).remove::<SpawnCheck>();
+    }
+}
+
+fn nearest_free_spot(pos: Vec2, blocked: impl Fn(Vec2) -> bool) -> Option<Vec2> {
+    (0..=SPAWN_NUDGE_MAX_STEPS).find_map(|step| {
+        let d = step as f32 * SPAWN_NUDGE_STEP;
+        [
+            Vec2::new(0.0, d),
+            Vec2::new(-d, 0.0),
+            Vec2::new(d, 0.0),
+            Vec2::new(-d, d),
+            Vec2::new(d, d),
+        ]
+        .into_iter()
+        .map(|offset| pos + offset)
+        .find(|p| !blocked(*p))
+    })
+}
+
+// ───────── Level-authored enemies ─────────
+const DEFAULT_PATROL_WIDTH: f32 = 200.0;
+// Where the dev-tools training dummy stands, relative to the player's spawn
+const DEV_DUMMY_OFFSET: Vec2 = Vec2::new(160.0, 0.0);
+
+/// Placed on Tiled objects of type `Enemy`, `TrainingDummy` or `Mimic`.
+/// Optional object properties: `class` (enemy class id, for the sprite),
+/// `patrol_width` (world units, ignored by dummies and mimics) and `delay`
+/// (mimics only, seconds behind the player).
+#[derive(Component, Clone, Debug)]
+pub struct EnemySpawnMarker {
+    pub class: Option<String>,
+    pub patrol_width: f32,
+    pub dummy: bool,
+    pub mimic_delay: Option<f32>,
+}
+
+/// Runs after transform propagation so the marker's world position is final.
+pub fn spawn_enemies_from_markers(
+    mut commands: Commands,
+    spawner: Res<EnemySpawner>,
+    markers: Query<(Entity, &EnemySpawnMarker, &GlobalTransform)>,
+) {
+    for (marker, m, gt) in &markers {
+        let pos = gt.translation().truncate();
+        let e = if let Some(delay) = m.mimic_delay {
+            spawn_mimic(&mut commands, pos, delay)
+        } else if m.dummy {
+            spawn_training_dummy(&mut commands, pos)
+        } else {
+            let half = m.patrol_width * 0.5;
+            spawn_enemy(&mut commands, pos, pos.x - half, pos.x + half)
+        };
+        commands
+            .entity(e)
+            .insert(Transform::from_xyz(pos.x, pos.y, spawner.spawn_z));
+        if let Some(id) = &m.class {
+            commands.entity(e).insert(EnemyClassId(id.clone()));
+        }
+        commands.entity(marker).remove::<EnemySpawnMarker>();
+    }
+}
+
+/// With dev tools on, every freshly loaded world gets a training dummy next
+/// to the player's spawn, so maps don't need one placed to tune hits.
+pub fn spawn_dev_training_dummy(mut commands: Commands, spawner: Res<EnemySpawner>) {
+    let pos = DEV_DUMMY_OFFSET;
+    let e = spawn_training_dummy(&mut commands, pos);
+    commands
+        .entity(e)
+        .insert(Transform::from_xyz(pos.x, pos.y, spawner.spawn_z));
+}
+
 #[derive(Clone, Eq, PartialEq, Debug, Default, Component)]
 #[require(ActiveCollisionHooks::MODIFY_CONTACTS)]
 pub struct OneWayPlatform(EntityHashSet);
@@ -60,16 +342,43 @@ pub enum PassThroughOneWayPlatform {
     Never,
 }
 
+/// How long a single `Action::Drop` press keeps one-way platforms passable.
+const DROP_THROUGH_WINDOW_S: f32 = 0.25;
+
+#[derive(Component)]
+pub struct DropThroughTimer(Timer);
+
 pub fn pass_through_one_way_platform(
     mut commands: Commands,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut players: Query<(Entity, &mut PassThroughOneWayPlatform), With<Player>>,
+    time: Res<Time>,
+    mut players: Query<
+        (
+            Entity,
+            &ActionState<Action>,
+            &mut PassThroughOneWayPlatform,
+            Option<&mut DropThroughTimer>,
+        ),
+        With<Player>,
+    >,
 ) {
-    for (entity, mut pass_through_one_way_platform) in &mut players {
-        if keyboard_input.pressed(KeyCode::ArrowDown) && keyboard_input.pressed(KeyCode::Space) {
+    for (entity, actions, mut pass_through_one_way_platform, timer) in &mut players {
+        if actions.just_pressed(&Action::Drop) {
+            commands.entity(entity).insert(DropThroughTimer(Timer::from_seconds(
+                DROP_THROUGH_WINDOW_S,
+                TimerMode::Once,
+            )));
             *pass_through_one_way_platform = PassThroughOneWayPlatform::Always;
             commands.queue(WakeUpBody(entity));
-        } else {
+            continue;
+        }
+
+        if let Some(mut t) = timer {
+            t.0.tick(time.delta());
+            if t.0.finished() {
+                commands.entity(entity).remove::<DropThroughTimer>();
+                *pass_through_one_way_platform = PassThroughOneWayPlatform::ByNormal;
+            }
+        } else if *pass_through_one_way_platform != PassThroughOneWayPlatform::ByNormal {
             *pass_through_one_way_platform = PassThroughOneWayPlatform::ByNormal;
         }
     }
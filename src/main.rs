@@ -1,38 +1,93 @@
+mod ai_debug;
+mod ambient;
 mod animations;
+mod assists;
 mod camera;
 mod character;
 mod class;
+mod collider_debug;
+mod combat_log;
+mod combo;
+mod danger;
+mod dev;
 mod enemy;
 mod enemy_class;
 mod enemy_spawner;
+mod feedback;
 mod filmic_post;
+mod focus;
+mod fx;
 mod gameflow;
+mod ground_effect;
 mod halation_post;
 mod hud;
 mod level;
+mod mimic;
+mod pickup;
+mod pool;
+mod postfx;
 mod prelude;
+mod progression;
 mod raycasts;
+mod replay;
+mod rng;
+mod save;
+mod settings;
+mod status;
+mod swing_step;
+mod transition;
+mod widgets;
+mod world_scale;
 
 use crate::MonitorSelection::*;
+use crate::ai_debug::AiDebugPlugin;
+use crate::ambient::AmbientPlugin;
 use crate::animations::AnimationsPlugin;
+use crate::assists::AssistsPlugin;
 use crate::camera::{
-    camera_follow, despawn_main_camera, despawn_menu_camera, spawn_follow_camera, spawn_menu_camera,
+    CameraFraming, FreeCam, apply_free_cam, camera_follow, despawn_main_camera,
+    despawn_menu_camera, free_cam_move, reset_free_cam, snap_camera_to_pixels, spawn_follow_camera,
+    spawn_menu_camera,
 };
 use crate::character::{Action, PlayerPlugin, spawn_main_character};
 use crate::class::ClassPlugin;
+use crate::collider_debug::ColliderDebugPlugin;
+use crate::combat_log::CombatLogPlugin;
+use crate::combo::ComboPlugin;
+use crate::danger::DangerPlugin;
+use crate::dev::{DevPlugin, dev_enabled, toggle_dev_tool};
 use crate::enemy::EnemyPlugin;
 use crate::enemy_class::EnemyClassPlugin;
+use crate::feedback::FeedbackPlugin;
 use crate::filmic_post::FilmicControls;
 use crate::filmic_post::FilmicPostProcessPlugin;
 use crate::filmic_post::FilmicSettings;
 use crate::filmic_post::sync_filmic_controls;
-use crate::gameflow::{GameFlowPlugin, GameState, despawn_gameplay};
+use crate::focus::FocusPlugin;
+use crate::fx::FxPlugin;
+use crate::gameflow::{GameFlowPlugin, GameState, audit_gameplay_leaks, despawn_gameplay};
+use crate::ground_effect::GroundEffectPlugin;
 use crate::halation_post::HalationPostProcessPlugin;
 use crate::hud::HudPlugin;
-use crate::level::{PlatformerCollisionHooks, pass_through_one_way_platform, spawn_map};
+use crate::level::{
+    PlatformerCollisionHooks, pass_through_one_way_platform, resolve_spawn_overlaps,
+    spawn_dev_training_dummy, spawn_enemies_from_markers, spawn_level_bound_walls, spawn_map,
+};
+use crate::mimic::MimicPlugin;
+use crate::pickup::PickupPlugin;
+use crate::pool::EffectsPoolPlugin;
+use crate::postfx::PostFxPlugin;
 use crate::prelude::*;
+use crate::progression::ProgressionPlugin;
+use crate::replay::ReplayPlugin;
+use crate::rng::reseed_game_rng;
+use crate::save::SavePlugin;
+use crate::settings::SettingsPlugin;
+use crate::status::StatusPlugin;
+use crate::swing_step::SwingStepPlugin;
+use crate::widgets::WidgetsPlugin;
+use crate::world_scale::{WorldScale, apply_world_scale};
 use bevy_egui::EguiPlugin;
-use bevy_inspector_egui::quick::WorldInspectorPlugin;
 use bevy_window::PresentMode;
 use bevy_window::WindowMode;
 use enemy_spawner::EnemySpawnerPlugin;
@@ -54,6 +109,7 @@ fn clear_world_loaded(mut commands: Commands) {
 }
 
 fn main() {
+    let world = WorldScale::default();
     App::new()
         .add_plugins((
             DefaultPlugins
@@ -75,18 +131,21 @@ fn main() {
             InputManagerPlugin::<Action>::default(),
             StateMachinePlugin::default(),
             PhysicsPlugins::default()
-                .with_length_unit(2.0)
+                .with_length_unit(world.physics_length_unit())
                 .with_collision_hooks::<PlatformerCollisionHooks>(),
             Light2dPlugin,
             //            PhysicsDebugPlugin::default(),
         ))
         .add_plugins(EguiPlugin::default())
-        .add_plugins(WorldInspectorPlugin::new())
         .add_plugins(SpritesheetAnimationPlugin)
         .add_plugins(AnimationsPlugin)
         .add_plugins(PlayerPlugin)
         .add_plugins(EnemyPlugin)
-        .add_plugins(ClassPlugin::new("assets/class_unknown.json").spawn_debug_holder(false))
+        .add_plugins(
+            ClassPlugin::new("assets/class_unknown.json")
+                .with_manifest("assets/classes.json")
+                .spawn_debug_holder(false),
+        )
         .add_plugins(EnemyClassPlugin::new("assets/default_enemy.json").spawn_debug_holder(false))
         .add_plugins(HudPlugin)
         .add_plugins(AnimatedImagePlugin)
@@ -96,16 +155,51 @@ fn main() {
         .add_plugins(HalationPostProcessPlugin)
         .add_plugins(FilmicPostProcessPlugin)
         .add_plugins(EnemySpawnerPlugin)
+        .add_plugins(EffectsPoolPlugin)
+        .add_plugins(FeedbackPlugin)
+        .add_plugins(SettingsPlugin)
+        .add_plugins(StatusPlugin)
+        .add_plugins(WidgetsPlugin)
+        .add_plugins(DevPlugin)
+        .add_plugins(AssistsPlugin)
+        .add_plugins(AiDebugPlugin)
+        .add_plugins(DangerPlugin)
+        .add_plugins(ReplayPlugin)
+        .add_plugins(ProgressionPlugin)
+        .add_plugins(PickupPlugin)
+        .add_plugins(SavePlugin)
+        .add_plugins(AmbientPlugin)
+        .add_plugins(ComboPlugin)
+        .add_plugins(FocusPlugin)
+        .add_plugins(FxPlugin)
+        .add_plugins(PostFxPlugin)
+        .add_plugins(SwingStepPlugin)
+        .add_plugins(CombatLogPlugin)
+        .add_plugins(ColliderDebugPlugin)
+        .add_plugins(GroundEffectPlugin)
+        .add_plugins(MimicPlugin)
+        .init_resource::<FreeCam>()
+        .init_resource::<CameraFraming>()
+        .register_type::<CameraFraming>()
         .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.1)))
         .register_type::<FilmicSettings>()
         .register_type::<FilmicControls>()
-        .insert_resource(Gravity(Vector::NEG_Y * 1000.0))
-        .add_systems(Startup, spawn_menu_camera)
+        .insert_resource(world)
+        .register_type::<WorldScale>()
+        .insert_resource(world.gravity())
+        .add_systems(Startup, (spawn_menu_camera, apply_world_scale))
         .add_systems(
             OnEnter(GameState::InGame),
             (
                 despawn_menu_camera,
-                (spawn_map, spawn_main_character, spawn_follow_camera).run_if(world_not_loaded),
+                (
+                    reseed_game_rng,
+                    spawn_map,
+                    spawn_main_character,
+                    spawn_follow_camera,
+                    spawn_dev_training_dummy.run_if(dev_enabled),
+                )
+                    .run_if(world_not_loaded),
                 mark_world_loaded.run_if(world_not_loaded),
             )
                 .chain(),
@@ -114,20 +208,45 @@ fn main() {
             OnEnter(GameState::MainMenu),
             (
                 despawn_gameplay,
+                audit_gameplay_leaks.after(despawn_gameplay),
                 despawn_main_camera,
                 clear_world_loaded,
                 spawn_menu_camera,
+                reset_free_cam,
             ),
         )
         .add_systems(
             OnEnter(GameState::GameOver),
-            (despawn_gameplay, clear_world_loaded),
+            (
+                despawn_gameplay,
+                audit_gameplay_leaks.after(despawn_gameplay),
+                clear_world_loaded,
+                reset_free_cam,
+            ),
+        )
+        .add_systems(
+            Update,
+            // Per frame so the view tracks the interpolated player, not the fixed step
+            (
+                toggle_dev_tool::<FreeCam>,
+                apply_free_cam.run_if(resource_changed::<FreeCam>),
+                free_cam_move,
+                camera_follow,
+                snap_camera_to_pixels,
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            PostUpdate,
+            (spawn_enemies_from_markers, spawn_level_bound_walls)
+                .after(TransformSystem::TransformPropagate),
         )
         .add_systems(
             FixedUpdate,
             (
                 pass_through_one_way_platform,
-                camera_follow,
+                resolve_spawn_overlaps,
                 sync_filmic_controls,
             )
                 .run_if(in_state(GameState::InGame)),
@@ -0,0 +1,213 @@
+// mimic.rs
+use crate::animations::{AnimHeld, AnimMap, AnimState, PlayerSpritesheet};
+use crate::character::Player;
+use crate::enemy::{EnemyDead, EnemyStunned};
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use crate::raycasts::MeleeAttackActive;
+use crate::world_scale::WorldScale;
+use bevy::sprite::Anchor;
+use bevy_spritesheet_animation::prelude::*;
+use std::collections::VecDeque;
+
+// Lag behind the player when the level object doesn't set `delay`
+pub const MIMIC_DEFAULT_DELAY: f32 = 0.6;
+
+/// One frame of the player as the mimic will replay it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MimicSample {
+    at: f32,
+    state: AnimState,
+    vx: f32,
+    flip_x: bool,
+}
+
+/// Shade enemy that plays the player's own clips and moves as the player
+/// did `delay` seconds ago, flipped left-to-right like a reflection.
+/// It has no `Thinker`; hits, stuns and death go through the usual enemy
+/// systems, and it swings whenever the replayed state is an attack.
+#[derive(Component, Debug)]
+pub struct Mimic {
+    pub delay: f32,
+    samples: VecDeque<MimicSample>,
+    current: Option<AnimationId>,
+}
+
+impl Mimic {
+    pub fn new(delay: f32) -> Self {
+        Self {
+            delay: delay.max(0.0),
+            samples: VecDeque::new(),
+            current: None,
+        }
+    }
+
+    /// The newest sample at least `delay` old, dropping the ones before it.
+    fn delayed(&mut self, now: f32) -> Option<MimicSample> {
+        let due = |s: &MimicSample| now - s.at >= self.delay;
+        while self.samples.get(1).is_some_and(due) {
+            self.samples.pop_front();
+        }
+        self.samples.front().copied().filter(due)
+    }
+}
+
+/// The player's high-level state as the mimic plays it; reactions (stun,
+/// death, ledge hangs) are the mimic's own, so they replay as idle.
+fn mimicked_state(state: AnimState) -> AnimState {
+    match state {
+        AnimState::Dead | AnimState::Stunned | AnimState::Hurt | AnimState::Hanging => {
+            AnimState::Idle
+        }
+        other => other,
+    }
+}
+
+fn is_attack(state: AnimState) -> bool {
+    matches!(
+        state,
+        AnimState::IdleAttack
+            | AnimState::WalkingAttack
+            | AnimState::RunningAttack
+            | AnimState::JumpingAttack
+            | AnimState::FallingAttack
+            | AnimState::ChargedAttack
+    )
+}
+
+/// Dress new mimics in the player's sheet and clip set (once a player exists
+/// to copy it from).
+fn attach_mimic_sprite(
+    mut commands: Commands,
+    sheet: Res<PlayerSpritesheet>,
+    world: Res<WorldScale>,
+    player: Query<&AnimMap, With<Player>>,
+    mimics: Query<Entity, (With<Mimic>, Without<AnimMap>)>,
+) {
+    if mimics.is_empty() {
+        return;
+    }
+    let Ok(clips) = player.single() else {
+        return;
+    };
+    for e in &mimics {
+        let mut sprite = Sprite::from_atlas_image(
+            sheet.image.clone(),
+            TextureAtlas {
+                layout: sheet.layout.clone(),
+                ..default()
+            },
+        );
+        sprite.anchor = Anchor::Custom(Vec2::new(0.0, -0.3));
+        let mut anim = SpritesheetAnimation::from_id(clips.clip(AnimState::Idle));
+        anim.playing = true;
+        commands
+            .entity(e)
+            .insert((sprite, anim, clips.clone(), world.body_collider()));
+    }
+}
+
+fn drive_mimics(
+    mut commands: Commands,
+    time: Res<Time>,
+    player: Query<(&AnimHeld, &LinearVelocity, &Sprite), With<Player>>,
+    mut mimics: Query<
+        (
+            Entity,
+            &mut Mimic,
+            &AnimMap,
+            &mut SpritesheetAnimation,
+            &mut LinearVelocity,
+            &mut Sprite,
+            Has<EnemyStunned>,
+            Has<EnemyDead>,
+            Has<MeleeAttackActive>,
+        ),
+        Without<Player>,
+    >,
+) {
+    let now = time.elapsed_secs();
+    let sample = player.single().ok().map(|(held, vel, sprite)| MimicSample {
+        at: now,
+        state: mimicked_state(held.state),
+        vx: -vel.x,
+        flip_x: !sprite.flip_x,
+    });
+    for (e, mut mimic, clips, mut anim, mut vel, mut sprite, stunned, dead, swinging) in &mut mimics
+    {
+        mimic.samples.extend(sample);
+        let replay = mimic.delayed(now);
+        // Stun knockback and death own the body until they end
+        let state = if dead {
+            AnimState::Dead
+        } else if stunned {
+            AnimState::Stunned
+        } else if let Some(s) = replay {
+            vel.x = s.vx;
+            sprite.flip_x = s.flip_x;
+            s.state
+        } else {
+            vel.x = 0.0;
+            AnimState::Idle
+        };
+
+        let attacking = !dead && !stunned && is_attack(state);
+        if attacking && !swinging {
+            commands.entity(e).insert(MeleeAttackActive);
+        } else if !attacking && swinging {
+            commands.entity(e).remove::<MeleeAttackActive>();
+        }
+
+        let id = clips.clip(state);
+        if mimic.current != Some(id) {
+            *anim = SpritesheetAnimation::from_id(id);
+            anim.playing = true;
+            mimic.current = Some(id);
+        }
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct MimicPlugin;
+
+impl Plugin for MimicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (attach_mimic_sprite, drive_mimics)
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mimic, MimicSample};
+    use crate::animations::AnimState;
+
+    fn sample(at: f32, state: AnimState) -> MimicSample {
+        MimicSample {
+            at,
+            state,
+            vx: 0.0,
+            flip_x: false,
+        }
+    }
+
+    #[test]
+    fn mimic_replays_the_sample_from_delay_ago() {
+        let mut mimic = Mimic::new(0.5);
+        mimic.samples.push_back(sample(0.0, AnimState::Idle));
+        mimic.samples.push_back(sample(0.3, AnimState::Walking));
+        mimic.samples.push_back(sample(0.6, AnimState::IdleAttack));
+        assert_eq!(mimic.delayed(0.2), None);
+        assert_eq!(mimic.delayed(0.7).map(|s| s.state), Some(AnimState::Idle));
+        assert_eq!(
+            mimic.delayed(0.9).map(|s| s.state),
+            Some(AnimState::Walking)
+        );
+        // Older samples are gone once passed
+        assert_eq!(mimic.samples.len(), 2);
+    }
+}
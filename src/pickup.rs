@@ -0,0 +1,154 @@
+// pickup.rs
+use crate::character::{Dead, GameLayer, Player};
+use crate::enemy::EnemyKilled;
+use crate::enemy_spawner::ground_point_below;
+use crate::fx::FadeIn;
+use crate::gameflow::{GameState, GameplayRoot};
+use crate::hud::spawn_floating_text;
+use crate::prelude::*;
+use crate::rng::GameRng;
+use crate::settings::JuiceConfig;
+use avian2d::spatial_query::SpatialQueryFilter;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+// Anything of the player's within this distance collects the pickup
+const PICKUP_RADIUS: f32 = 14.0;
+const COIN_SIZE: f32 = 6.0;
+const COIN_COLOR: Color = Color::srgb(1.0, 0.82, 0.2);
+const COIN_Z: f32 = 2.0;
+const PICKUP_FADE_IN_SECS: f32 = 0.25;
+// Coin value rolled per kill, inclusive
+const COINS_PER_KILL: (u32, u32) = (1, 3);
+// Drops settle on ground this close below the kill point
+const DROP_PROBE_DEPTH: f32 = 200.0;
+
+/// What a pickup gives when the player touches it.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum PickupKind {
+    Coin { value: u32 },
+}
+
+/// Collectible lying in the world; `collect_pickups` turns overlaps with the
+/// player into `PickupCollected`.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Pickup {
+    pub kind: PickupKind,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PickupCollected {
+    pub kind: PickupKind,
+    pub pos: Vec2,
+}
+
+/// Coins collected so far. Kept across runs like `Experience`, and persisted
+/// in the save file.
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct Wallet {
+    pub coins: u32,
+}
+
+pub fn spawn_pickup(commands: &mut Commands, pos: Vec2, kind: PickupKind) -> Entity {
+    let (name, color, size) = match kind {
+        PickupKind::Coin { .. } => ("Coin", COIN_COLOR, COIN_SIZE),
+    };
+    commands
+        .spawn((
+            Name::new(name),
+            Pickup { kind },
+            FadeIn::new(PICKUP_FADE_IN_SECS),
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(COIN_Z)),
+            GameplayRoot,
+        ))
+        .id()
+}
+
+/// Every kill leaves a coin on the ground below it.
+fn drop_coins_on_kill(
+    mut commands: Commands,
+    mut kills: EventReader<EnemyKilled>,
+    mut rng: ResMut<GameRng>,
+    spatial: SpatialQuery,
+) {
+    for kill in kills.read() {
+        let pos = ground_point_below(&spatial, kill.pos, DROP_PROBE_DEPTH, COIN_SIZE * 0.5)
+            .unwrap_or(kill.pos);
+        let value = rng.random_range(COINS_PER_KILL.0..=COINS_PER_KILL.1);
+        spawn_pickup(&mut commands, pos, PickupKind::Coin { value });
+    }
+}
+
+fn collect_pickups(
+    mut commands: Commands,
+    spatial: SpatialQuery,
+    pickups: Query<(Entity, &Pickup, &GlobalTransform)>,
+    players: Query<(), (With<Player>, Without<Dead>)>,
+    mut collected: EventWriter<PickupCollected>,
+) {
+    let shape = Collider::circle(PICKUP_RADIUS);
+    let filter = SpatialQueryFilter::from_mask(LayerMask::from(GameLayer::Player));
+    for (e, pickup, gt) in &pickups {
+        let pos = gt.translation().truncate();
+        let touched = spatial
+            .shape_intersections(&shape, pos, 0.0, &filter)
+            .into_iter()
+            .any(|hit| players.contains(hit));
+        if touched {
+            collected.write(PickupCollected {
+                kind: pickup.kind,
+                pos,
+            });
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+fn credit_coins(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    juice: Res<JuiceConfig>,
+    mut collected: EventReader<PickupCollected>,
+    mut wallet: ResMut<Wallet>,
+) {
+    for c in collected.read() {
+        let PickupKind::Coin { value } = c.kind;
+        wallet.coins = wallet.coins.saturating_add(value);
+        if !juice.damage_numbers {
+            continue;
+        }
+        spawn_floating_text(
+            &mut commands,
+            &assets,
+            c.pos.extend(0.0),
+            format!("+{value}"),
+            COIN_COLOR,
+        );
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PickupCollected>()
+            .init_resource::<Wallet>()
+            .register_type::<Wallet>()
+            .register_type::<Pickup>()
+            .add_systems(
+                Update,
+                (drop_coins_on_kill, collect_pickups, credit_coins)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
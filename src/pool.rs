@@ -0,0 +1,201 @@
+// pool.rs
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// Anything that lives in a `Pool<T>`: the marker component plus the bundle
+/// each pooled entity is pre-spawned with (kept hidden until acquired).
+pub trait Poolable: Component + Sized {
+    fn prototype() -> impl Bundle;
+}
+
+/// Fixed set of pre-spawned entities handed out instead of spawn/despawn.
+/// When every entity is in use, `acquire` recycles the oldest one.
+#[derive(Resource)]
+pub struct Pool<T: Poolable> {
+    free: Vec<Entity>,
+    /// Oldest acquisition first.
+    in_use: VecDeque<Entity>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Poolable> Default for Pool<T> {
+    fn default() -> Self {
+        Self {
+            free: Vec::new(),
+            in_use: VecDeque::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Poolable> Pool<T> {
+    pub fn acquire(&mut self, commands: &mut Commands) -> Option<Entity> {
+        let e = match self.free.pop() {
+            Some(e) => e,
+            None => self.in_use.pop_front()?,
+        };
+        self.in_use.push_back(e);
+        commands.entity(e).insert(Visibility::Visible);
+        Some(e)
+    }
+
+    pub fn release(&mut self, commands: &mut Commands, e: Entity) {
+        let Some(i) = self.in_use.iter().position(|&x| x == e) else {
+            return;
+        };
+        self.in_use.remove(i);
+        self.free.push(e);
+        commands
+            .entity(e)
+            .insert(Visibility::Hidden)
+            .remove::<PooledLifetime>();
+    }
+
+    pub fn release_all(&mut self, commands: &mut Commands) {
+        for e in std::mem::take(&mut self.in_use) {
+            self.free.push(e);
+            commands
+                .entity(e)
+                .insert(Visibility::Hidden)
+                .remove::<PooledLifetime>();
+        }
+    }
+}
+
+/// On every pre-spawned entity; pools outlive gameplay teardown on purpose.
+#[derive(Component)]
+pub struct Pooled;
+
+/// Optional auto-release: pooled entities carrying this go back to the pool
+/// once the timer finishes.
+#[derive(Component)]
+pub struct PooledLifetime(pub Timer);
+
+/// Registers `Pool<T>`, pre-spawns `capacity` entities at startup and
+/// reclaims everything when gameplay is torn down.
+pub struct PoolPlugin<T: Poolable> {
+    capacity: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Poolable> PoolPlugin<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct PoolCapacity<T: Poolable>(usize, PhantomData<T>);
+
+impl<T: Poolable> Plugin for PoolPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Pool<T>>()
+            .insert_resource(PoolCapacity::<T>(self.capacity, PhantomData))
+            .add_systems(Startup, prespawn_pool::<T>)
+            .add_systems(Update, release_expired::<T>)
+            .add_systems(OnEnter(GameState::MainMenu), release_pool::<T>)
+            .add_systems(OnEnter(GameState::GameOver), release_pool::<T>);
+    }
+}
+
+fn prespawn_pool<T: Poolable>(
+    mut commands: Commands,
+    cap: Res<PoolCapacity<T>>,
+    mut pool: ResMut<Pool<T>>,
+) {
+    for _ in 0..cap.0 {
+        let e = commands
+            .spawn((
+                T::prototype(),
+                Pooled,
+                Transform::default(),
+                Visibility::Hidden,
+                Name::new(format!(
+                    "Pooled<{}>",
+                    std::any::type_name::<T>().rsplit("::").next().unwrap_or("?")
+                )),
+            ))
+            .id();
+        pool.free.push(e);
+    }
+}
+
+fn release_expired<T: Poolable>(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pool: ResMut<Pool<T>>,
+    mut q: Query<(Entity, &mut PooledLifetime), With<T>>,
+) {
+    for (e, mut life) in &mut q {
+        life.0.tick(time.delta());
+        if life.0.finished() {
+            pool.release(&mut commands, e);
+        }
+    }
+}
+
+fn release_pool<T: Poolable>(mut commands: Commands, mut pool: ResMut<Pool<T>>) {
+    pool.release_all(&mut commands);
+}
+
+// ───────── Effect sprites ─────────
+
+/// Generic short-lived sprite effect (puffs, flashes, rings).
+#[derive(Component, Default)]
+pub struct EffectSprite {
+    pub color: Color,
+    pub grow: f32,
+    pub base_size: Vec2,
+}
+
+impl Poolable for EffectSprite {
+    fn prototype() -> impl Bundle {
+        (EffectSprite::default(), Sprite::default())
+    }
+}
+
+/// Grab a pooled sprite, place it at `pos` and let it fade out over `secs`.
+pub fn play_effect(
+    commands: &mut Commands,
+    pool: &mut Pool<EffectSprite>,
+    pos: Vec3,
+    color: Color,
+    size: Vec2,
+    grow: f32,
+    secs: f32,
+) -> Option<Entity> {
+    let e = pool.acquire(commands)?;
+    commands.entity(e).insert((
+        EffectSprite {
+            color,
+            grow,
+            base_size: size,
+        },
+        Sprite::from_color(color, size),
+        Transform::from_translation(pos),
+        PooledLifetime(Timer::from_seconds(secs, TimerMode::Once)),
+    ));
+    Some(e)
+}
+
+fn animate_effects(mut q: Query<(&EffectSprite, &PooledLifetime, &mut Sprite)>) {
+    for (fx, life, mut sprite) in &mut q {
+        let t = life.0.fraction();
+        sprite.color = fx.color.with_alpha(fx.color.alpha() * (1.0 - t));
+        sprite.custom_size = Some(fx.base_size * (1.0 + fx.grow * t));
+    }
+}
+
+pub struct EffectsPoolPlugin;
+
+impl Plugin for EffectsPoolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PoolPlugin::<EffectSprite>::new(64))
+            .add_systems(Update, animate_effects);
+    }
+}
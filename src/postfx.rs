@@ -0,0 +1,109 @@
+// postfx.rs
+use crate::filmic_post::FilmicControls;
+use crate::halation_post::HalationSettings;
+use crate::prelude::*;
+use bevy::core_pipeline::tonemapping::DebandDither;
+use serde::{Deserialize, Serialize};
+
+const POSTFX_PATH: &str = "postfx.json";
+
+/// Halation look in named fields; packed into `HalationSettings` for the shader.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct HalationDefaults {
+    pub strength: f32,
+    pub radius_px: f32,
+    pub threshold: f32,
+    pub knee: f32,
+    pub tint: [f32; 3],
+    pub red_boost: f32,
+    pub shadow_mul: f32,
+}
+
+impl Default for HalationDefaults {
+    fn default() -> Self {
+        Self {
+            strength: 0.6,
+            radius_px: 3.0,
+            threshold: 0.7,
+            knee: 0.08,
+            tint: [1.0, 0.35, 0.25],
+            red_boost: 1.25,
+            shadow_mul: 1.2,
+        }
+    }
+}
+
+impl HalationDefaults {
+    pub fn to_settings(&self) -> HalationSettings {
+        let [r, g, b] = self.tint.map(|c| c.max(0.0));
+        HalationSettings {
+            p0: Vec4::new(
+                self.strength.max(0.0),
+                self.radius_px.max(0.0),
+                self.threshold.clamp(0.0, 1.0),
+                self.knee.max(0.0),
+            ),
+            p1: Vec4::new(r, g, b, self.red_boost.max(0.0)),
+            p2: Vec4::new(self.shadow_mul.max(0.0), 0.0, 0.0, 0.0),
+        }
+    }
+}
+
+/// Starting post-process look for the `MainCamera`, read from `POSTFX_PATH`
+/// so a tuned look can ship without a rebuild. Missing fields keep the
+/// built-in values; out-of-range ones are clamped to the inspector limits.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PostFxDefaults {
+    pub filmic: FilmicControls,
+    pub halation: HalationDefaults,
+    /// Deband dithering on the main camera (hides gradient banding).
+    pub dither: bool,
+}
+
+impl Default for PostFxDefaults {
+    fn default() -> Self {
+        Self {
+            filmic: FilmicControls::default(),
+            halation: HalationDefaults::default(),
+            dither: true,
+        }
+    }
+}
+
+impl PostFxDefaults {
+    pub fn deband_dither(&self) -> DebandDither {
+        if self.dither {
+            DebandDither::Enabled
+        } else {
+            DebandDither::Disabled
+        }
+    }
+}
+
+fn load_postfx(mut commands: Commands) {
+    let file = match std::fs::read_to_string(POSTFX_PATH) {
+        Ok(text) => serde_json::from_str::<PostFxDefaults>(&text).unwrap_or_else(|e| {
+            warn!("PostFx: {POSTFX_PATH} is malformed ({e}); using built-in look");
+            PostFxDefaults::default()
+        }),
+        Err(_) => PostFxDefaults::default(),
+    };
+    commands.insert_resource(PostFxDefaults {
+        filmic: file.filmic.clamped(),
+        ..file
+    });
+}
+
+// ───────── Plugin ─────────
+pub struct PostFxPlugin;
+
+impl Plugin for PostFxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PostFxDefaults>()
+            .register_type::<PostFxDefaults>()
+            .add_systems(PreStartup, load_postfx);
+    }
+}
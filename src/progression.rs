@@ -0,0 +1,243 @@
+// progression.rs
+use crate::character::{PLAYER_MELEE_DAMAGE, Player, Weapons};
+use crate::class::{ClassFile, PlayerClass};
+use crate::enemy::EnemyKilled;
+use crate::gameflow::GameState;
+use crate::hud::{HealPlayer, PlayerStats, spawn_floating_text};
+use crate::prelude::*;
+use crate::raycasts::MeleeRaycastSpec;
+use crate::settings::{JuiceConfig, Palette};
+use serde::{Deserialize, Serialize};
+
+const XP_PER_KILL: u32 = 10;
+// XP to go from level n to n+1 = XP_CURVE_BASE * n^XP_CURVE_EXP
+const XP_CURVE_BASE: f32 = 50.0;
+const XP_CURVE_EXP: f32 = 1.5;
+// Share of max health restored on level-up
+const LEVEL_UP_HEAL_FRACTION: f32 = 0.25;
+
+// Derived stats per attribute point gained since level 1
+const HEALTH_PER_GRIT: u32 = 5;
+const DEFENSE_PER_GRIT: f32 = 0.005;
+const DAMAGE_PER_MIGHT: f32 = 0.04; // fraction of base melee damage
+const SPEED_PER_AGILITY: f32 = 0.01;
+const STAMINA_PER_FOCUS: f32 = 5.0;
+
+// Enemy scaling added per New Game+ cycle
+const NG_PLUS_HEALTH_PER_CYCLE: f32 = 0.5;
+const NG_PLUS_DAMAGE_PER_CYCLE: f32 = 0.25;
+
+/// Kill-driven progression. Persisted in the save file.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct Experience {
+    /// XP into the current level.
+    pub xp: u32,
+    pub level: u32,
+}
+
+impl Default for Experience {
+    fn default() -> Self {
+        Self { xp: 0, level: 1 }
+    }
+}
+
+/// Cleared runs: the cycle only advances on `BeginNewGamePlus`, never on a
+/// game over. Level and attributes already carry over through `Experience`;
+/// each cycle raises the enemy baseline. Persisted in the save.
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct NewGamePlus {
+    pub cycle: u32,
+}
+
+/// Player chose to start the next New Game+ cycle.
+#[derive(Event, Default)]
+pub struct BeginNewGamePlus;
+
+impl NewGamePlus {
+    /// Multiplier on enemy max health.
+    pub fn health_scale(&self) -> f32 {
+        1.0 + self.cycle as f32 * NG_PLUS_HEALTH_PER_CYCLE
+    }
+
+    /// Multiplier on enemy melee damage.
+    pub fn damage_scale(&self) -> f32 {
+        1.0 + self.cycle as f32 * NG_PLUS_DAMAGE_PER_CYCLE
+    }
+}
+
+/// XP needed to advance from `level` to the next one.
+pub fn xp_to_next(level: u32) -> u32 {
+    (XP_CURVE_BASE * (level.max(1) as f32).powf(XP_CURVE_EXP)).round() as u32
+}
+
+/// `base` after `level - 1` level-ups: +1 to every attribute per level, with
+/// the derived stats recomputed from the points gained.
+pub fn leveled_class(base: &ClassFile, level: u32) -> ClassFile {
+    let gained = level.saturating_sub(1);
+    let mut class = base.clone();
+
+    let a = &mut class.attribute_start;
+    a.might += gained;
+    a.agility += gained;
+    a.focus += gained;
+    a.grit += gained;
+
+    let b = &mut class.base_stats;
+    b.max_health += gained * HEALTH_PER_GRIT;
+    b.defense += gained as f32 * DEFENSE_PER_GRIT;
+    b.melee_power *= 1.0 + gained as f32 * DAMAGE_PER_MIGHT;
+    b.move_speed *= 1.0 + gained as f32 * SPEED_PER_AGILITY;
+    b.stamina_max += gained as f32 * STAMINA_PER_FOCUS;
+    class
+}
+
+fn grant_kill_xp(
+    mut commands: Commands,
+    mut kills: EventReader<EnemyKilled>,
+    mut experience: ResMut<Experience>,
+    mut heal: EventWriter<HealPlayer>,
+    stats: Res<PlayerStats>,
+    assets: Res<AssetServer>,
+    palette: Res<Palette>,
+    juice: Res<JuiceConfig>,
+    player: Query<&GlobalTransform, With<Player>>,
+) {
+    let gained = kills.read().count() as u32 * XP_PER_KILL;
+    if gained == 0 {
+        return;
+    }
+    let start_level = experience.level;
+    experience.xp += gained;
+    while experience.xp >= xp_to_next(experience.level) {
+        experience.xp -= xp_to_next(experience.level);
+        experience.level += 1;
+    }
+    if experience.level == start_level {
+        return;
+    }
+
+    info!("Progression: reached level {}", experience.level);
+    heal.write(HealPlayer {
+        amount: stats.max_health * LEVEL_UP_HEAL_FRACTION,
+    });
+    if !juice.damage_numbers {
+        return;
+    }
+    if let Ok(tf) = player.single() {
+        spawn_floating_text(
+            &mut commands,
+            &assets,
+            tf.translation() + Vec3::Y * 14.0,
+            format!("LEVEL {}", experience.level),
+            palette.heal,
+        );
+    }
+}
+
+/// Re-derive the player's class and stats from the base class whenever the
+/// level changes or a fresh player gets its class.
+fn apply_level_to_player(
+    experience: Res<Experience>,
+    base: Option<Res<ClassFile>>,
+    mut stats: ResMut<PlayerStats>,
+    mut q: Query<(&mut PlayerClass, &mut MeleeRaycastSpec, Option<&Weapons>), With<Player>>,
+) {
+    let Some(base) = base else {
+        return;
+    };
+    for (mut class, mut spec, weapons) in &mut q {
+        let fresh = class.is_added();
+        if !fresh && !experience.is_changed() {
+            continue;
+        }
+        let leveled = leveled_class(&base, experience.level);
+        let might_gained = leveled.attribute_start.might - base.attribute_start.might;
+        let base_damage = weapons.map_or(PLAYER_MELEE_DAMAGE, |w| w.active().spec.damage);
+        spec.damage =
+            (base_damage as f32 * (1.0 + might_gained as f32 * DAMAGE_PER_MIGHT)).round() as i32;
+
+        let max_health = leveled.base_stats.max_health as f32;
+        stats.health = if fresh {
+            max_health
+        } else {
+            // Keep the missing-health gap; the level-up heal comes on top
+            (stats.health + max_health - stats.max_health).clamp(0.0, max_health)
+        };
+        stats.max_health = max_health;
+        stats.max_stamina = leveled.base_stats.stamina_max;
+        stats.stamina = stats.stamina.min(stats.max_stamina);
+        class.0 = leveled;
+    }
+}
+
+fn advance_new_game_plus(
+    mut begin: EventReader<BeginNewGamePlus>,
+    mut new_game_plus: ResMut<NewGamePlus>,
+) {
+    for _ in begin.read() {
+        new_game_plus.cycle = new_game_plus.cycle.saturating_add(1);
+        info!("Progression: New Game+ cycle {}", new_game_plus.cycle);
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct ProgressionPlugin;
+
+impl Plugin for ProgressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Experience>()
+            .register_type::<Experience>()
+            .init_resource::<NewGamePlus>()
+            .register_type::<NewGamePlus>()
+            .add_event::<BeginNewGamePlus>()
+            .add_systems(Update, advance_new_game_plus)
+            .add_systems(
+                Update,
+                (grant_kill_xp, apply_level_to_player)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BeginNewGamePlus, NewGamePlus, ProgressionPlugin};
+    use crate::gameflow::GameState;
+    use bevy::prelude::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, ProgressionPlugin))
+            .init_state::<GameState>();
+        app
+    }
+
+    #[test]
+    fn game_over_leaves_cycle_unchanged() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<State<GameState>>().get(),
+            &GameState::GameOver
+        );
+        assert_eq!(app.world().resource::<NewGamePlus>().cycle, 0);
+    }
+
+    #[test]
+    fn begin_new_game_plus_advances_cycle() {
+        let mut app = app();
+        app.world_mut().send_event(BeginNewGamePlus);
+        app.update();
+        assert_eq!(app.world().resource::<NewGamePlus>().cycle, 1);
+    }
+}
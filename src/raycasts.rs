@@ -1,5 +1,3 @@
-use crate::class::{ClassAttachTarget, PlayerClass};
-use crate::hud::PlayerStats;
 use avian2d::spatial_query::{RayCaster, RayHits, SpatialQueryFilter};
 use bevy::prelude::*;
 use bevy::sprite::Sprite;
@@ -26,10 +24,62 @@ pub struct MeleeRaycastHit {
     pub distance: f32,
     pub normal: Vec2,
     pub damage: i32,
+    /// Scales the knockback the target receives (1.0 = normal).
+    pub knockback_mult: f32,
+    /// Upward launch speed (launcher attacks); 0.0 for regular hits.
+    pub launch: f32,
 }
 
+/// Per-swing tweaks layered on top of `MeleeRaycastSpec` (e.g. charged hits).
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SwingModifiers {
+    pub damage_mult: f32,
+    pub knockback_mult: f32,
+    pub launch: f32,
+}
+
+/// Whether the current swing has landed a hit yet; reset when an attack
+/// starts, read back through `SwingEnded`.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct SwingConnected(pub bool);
+
+/// An attacker's swing finished (`MeleeAttackActive` removed).
+#[derive(Event, Debug, Clone)]
+pub struct SwingEnded {
+    pub attacker: Entity,
+    pub connected: bool,
+}
+
+/// Where the attacker is aiming (unit length). Zero means "use facing";
+/// otherwise the melee ray points this way instead of straight ahead.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct AimDirection(pub Vec2);
+
+/// Child entity carrying an attacker's melee `RayCaster` while it swings.
 #[derive(Component)]
-struct AttackRay;
+pub struct AttackRay;
+
+/// Attacker whose ray counts against `ActiveRayBudget` (crowds of enemies).
+#[derive(Component, Default)]
+pub struct RayBudgeted;
+
+/// Entity the budget measures distance to (the player).
+#[derive(Component, Default)]
+pub struct RayBudgetFocus;
+
+/// Only the `max_rays` budgeted attackers closest to the focus cast while
+/// swinging; the rest keep animating without a ray until they get a slot.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ActiveRayBudget {
+    pub max_rays: usize,
+}
+
+impl Default for ActiveRayBudget {
+    fn default() -> Self {
+        Self { max_rays: 8 }
+    }
+}
 
 #[derive(Component, Default)]
 struct AlreadyHit(HashSet<Entity>);
@@ -45,6 +95,9 @@ pub struct RaycastMeleePlugin;
 impl Plugin for RaycastMeleePlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<MeleeRaycastHit>()
+            .add_event::<SwingEnded>()
+            .init_resource::<ActiveRayBudget>()
+            .register_type::<ActiveRayBudget>()
             .configure_sets(
                 Update,
                 (RaycastMeleeSet::Cast, RaycastMeleeSet::ApplyDamage).chain(),
@@ -52,16 +105,14 @@ impl Plugin for RaycastMeleePlugin {
             .add_systems(
                 Update,
                 (
+                    reset_swing_connected,
                     spawn_ray_on_attack_start,
+                    sync_budgeted_rays,
                     keep_ray_facing_correctly,
                     emit_hits_from_rays,
                 )
                     .in_set(RaycastMeleeSet::Cast),
             )
-            .add_systems(
-                Update,
-                apply_melee_damage_to_player_stats.in_set(RaycastMeleeSet::ApplyDamage),
-            )
             .add_systems(Update, despawn_ray_on_attack_end);
     }
 }
@@ -76,60 +127,139 @@ fn is_facing_right(sprite: Option<&Sprite>, gt: Option<&GlobalTransform>) -> boo
     true
 }
 
+/// Ray origin and direction: along the aim if there is one, else facing.
+fn ray_pose(
+    spec: &MeleeRaycastSpec,
+    facing_right: bool,
+    aim: Option<&AimDirection>,
+) -> (Vec2, Dir2) {
+    if let Some(dir) = aim.and_then(|a| Dir2::new(a.0).ok()) {
+        // Same reach in front of the body, just rotated toward the aim
+        return (
+            Vec2::new(0.0, spec.offset.y) + *dir * spec.offset.x.abs(),
+            dir,
+        );
+    }
+    if facing_right {
+        (spec.offset, Dir2::X)
+    } else {
+        (Vec2::new(-spec.offset.x, spec.offset.y), Dir2::NEG_X)
+    }
+}
+
+fn spawn_attack_ray(
+    commands: &mut Commands,
+    attacker: Entity,
+    spec: &MeleeRaycastSpec,
+    facing_right: bool,
+    aim: Option<&AimDirection>,
+) {
+    let (origin, direction) = ray_pose(spec, facing_right, aim);
+    commands.entity(attacker).with_children(|c| {
+        c.spawn((
+            AttackRay,
+            Transform::default(),
+            GlobalTransform::default(),
+            RayCaster::new(origin, direction)
+                .with_max_distance(spec.length)
+                .with_max_hits(spec.max_hits)
+                .with_ignore_self(true)
+                .with_solidness(spec.solid)
+                .with_query_filter(spec.filter.clone()),
+        ));
+    });
+}
+
 fn spawn_ray_on_attack_start(
     mut commands: Commands,
-    added: Query<(Entity, &MeleeRaycastSpec), Added<MeleeAttackActive>>,
+    added: Query<
+        (Entity, &MeleeRaycastSpec, Option<&AimDirection>),
+        (Added<MeleeAttackActive>, Without<RayBudgeted>),
+    >,
     sprites: Query<&Sprite>,
     globals: Query<&GlobalTransform>,
 ) {
-    for (attacker, spec) in &added {
+    for (attacker, spec, aim) in &added {
         commands.entity(attacker).insert(AlreadyHit::default());
 
         let sprite = sprites.get(attacker).ok();
         let gt = globals.get(attacker).ok();
         let facing_right = is_facing_right(sprite, gt);
+        spawn_attack_ray(&mut commands, attacker, spec, facing_right, aim);
+    }
+}
 
-        let origin = if facing_right {
-            spec.offset
-        } else {
-            Vec2::new(-spec.offset.x, spec.offset.y)
-        };
-        let direction = if facing_right { Dir2::X } else { Dir2::NEG_X };
-
-        commands.entity(attacker).with_children(|c| {
-            c.spawn((
-                AttackRay,
-                Transform::default(),
-                GlobalTransform::default(),
-                RayCaster::new(origin, direction)
-                    .with_max_distance(spec.length)
-                    .with_max_hits(spec.max_hits)
-                    .with_ignore_self(true)
-                    .with_solidness(spec.solid)
-                    .with_query_filter(spec.filter.clone()),
-            ));
-        });
+/// Budgeted attackers: the closest `max_rays` to the focus hold a ray,
+/// everyone else's is despawned (or never spawned) until they move up.
+fn sync_budgeted_rays(
+    mut commands: Commands,
+    budget: Res<ActiveRayBudget>,
+    focus: Query<&GlobalTransform, With<RayBudgetFocus>>,
+    attackers: Query<
+        (
+            Entity,
+            Ref<MeleeAttackActive>,
+            &MeleeRaycastSpec,
+            &GlobalTransform,
+            Option<&Sprite>,
+            Option<&AimDirection>,
+            Option<&Children>,
+        ),
+        With<RayBudgeted>,
+    >,
+    rays: Query<(), With<AttackRay>>,
+) {
+    let focus = focus.single().ok().map(|tf| tf.translation().truncate());
+    let mut by_distance: Vec<(Entity, f32)> = attackers
+        .iter()
+        .map(|(e, _, _, gt, ..)| {
+            let d = focus.map_or(0.0, |f| f.distance_squared(gt.translation().truncate()));
+            (e, d)
+        })
+        .collect();
+    by_distance.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let allowed: HashSet<Entity> = by_distance
+        .iter()
+        .take(budget.max_rays)
+        .map(|(e, _)| *e)
+        .collect();
+
+    for (attacker, active, spec, gt, sprite, aim, kids) in &attackers {
+        if active.is_added() {
+            commands.entity(attacker).insert(AlreadyHit::default());
+        }
+        let ray = kids.and_then(|k| k.iter().find(|c| rays.contains(*c)));
+        match (allowed.contains(&attacker), ray) {
+            (true, None) => {
+                let facing_right = is_facing_right(sprite, Some(gt));
+                spawn_attack_ray(&mut commands, attacker, spec, facing_right, aim);
+            }
+            (false, Some(ray)) => commands.entity(ray).despawn(),
+            _ => {}
+        }
     }
 }
 
 fn keep_ray_facing_correctly(
-    attackers: Query<(Entity, Option<&Sprite>, Option<&GlobalTransform>), With<MeleeAttackActive>>,
+    attackers: Query<
+        (
+            Entity,
+            Option<&Sprite>,
+            Option<&GlobalTransform>,
+            Option<&AimDirection>,
+        ),
+        With<MeleeAttackActive>,
+    >,
     children: Query<&Children>,
     mut rays: Query<&mut RayCaster, With<AttackRay>>,
     specs: Query<&MeleeRaycastSpec>,
 ) {
-    for (attacker, sprite, gt) in &attackers {
+    for (attacker, sprite, gt, aim) in &attackers {
         let facing_right = is_facing_right(sprite, gt);
         let Ok(spec) = specs.get(attacker) else {
             continue;
         };
-
-        let origin = if facing_right {
-            spec.offset
-        } else {
-            Vec2::new(-spec.offset.x, spec.offset.y)
-        };
-        let dir = if facing_right { Dir2::X } else { Dir2::NEG_X };
+        let (origin, dir) = ray_pose(spec, facing_right, aim);
 
         if let Ok(kids) = children.get(attacker) {
             for &child in kids {
@@ -142,25 +272,9 @@ fn keep_ray_facing_correctly(
     }
 }
 
-fn apply_melee_damage_to_player_stats(
-    mut events: EventReader<MeleeRaycastHit>,
-    mut stats: ResMut<PlayerStats>,
-    targets_with_player_tag: Query<Entity, With<ClassAttachTarget>>,
-    defenses: Query<&PlayerClass>,
-) {
-    for hit in events.read() {
-        if targets_with_player_tag.get(hit.target).is_ok() {
-            let defense = defenses
-                .get(hit.target)
-                .map(|pc| pc.0.base_stats.defense)
-                .unwrap_or(0.0)
-                .clamp(0.0, 0.95);
-
-            let reduced = (hit.damage as f32) * (1.0 - defense);
-            let dmg = reduced.max(0.0).ceil();
-
-            stats.health = (stats.health - dmg).max(0.0);
-        }
+fn reset_swing_connected(mut commands: Commands, added: Query<Entity, Added<MeleeAttackActive>>) {
+    for attacker in &added {
+        commands.entity(attacker).insert(SwingConnected(false));
     }
 }
 
@@ -168,13 +282,20 @@ fn emit_hits_from_rays(
     mut writer: EventWriter<MeleeRaycastHit>,
     rays: Query<(&ChildOf, &RayHits), With<AttackRay>>,
     specs: Query<&MeleeRaycastSpec>,
+    modifiers: Query<&SwingModifiers>,
     mut hit_sets: Query<&mut AlreadyHit>,
+    mut connected: Query<&mut SwingConnected>,
 ) {
     for (child_of, ray_hits) in &rays {
         let attacker = child_of.0; // parent entity
         let Ok(spec) = specs.get(attacker) else {
             continue;
         };
+        let (damage_mult, knockback_mult, launch) = modifiers
+            .get(attacker)
+            .map(|m| (m.damage_mult, m.knockback_mult, m.launch))
+            .unwrap_or((1.0, 1.0, 0.0));
+        let damage = (spec.damage as f32 * damage_mult).round() as i32;
 
         for hit in ray_hits.iter_sorted() {
             let target = hit.entity;
@@ -193,8 +314,13 @@ fn emit_hits_from_rays(
                 target,
                 distance: hit.distance,
                 normal: hit.normal,
-                damage: spec.damage,
+                damage,
+                knockback_mult,
+                launch,
             });
+            if let Ok(mut c) = connected.get_mut(attacker) {
+                c.0 = true;
+            }
         }
     }
 }
@@ -202,10 +328,18 @@ fn emit_hits_from_rays(
 fn despawn_ray_on_attack_end(
     mut commands: Commands,
     mut removed: RemovedComponents<MeleeAttackActive>,
+    mut ended: EventWriter<SwingEnded>,
+    connected: Query<&SwingConnected>,
     children: Query<&Children>,
     rays: Query<Entity, With<AttackRay>>,
 ) {
     for attacker in removed.read() {
+        if let Ok(c) = connected.get(attacker) {
+            ended.write(SwingEnded {
+                attacker,
+                connected: c.0,
+            });
+        }
         if let Ok(kids) = children.get(attacker) {
             for &child in kids {
                 if rays.get(child).is_ok() {
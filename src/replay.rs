@@ -0,0 +1,292 @@
+// replay.rs
+use crate::character::{Action, Player, shape_axis_input};
+use crate::dev::dev_enabled;
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use crate::rng::GameRng;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+
+const RECORD_TOGGLE: KeyCode = KeyCode::F5;
+const PLAYBACK_TOGGLE: KeyCode = KeyCode::F6;
+const RECORDING_PATH: &str = "recordings/last_inputs.json";
+
+/// Buttons stored as one bit each, in this order.
+const BUTTONS: [Action; 8] = [
+    Action::Jump,
+    Action::Attack,
+    Action::Sprint,
+    Action::Drop,
+    Action::SwapWeapon,
+    Action::Up,
+    Action::Special,
+    Action::Dash,
+];
+
+/// One fixed tick of player input: move axis + held-button bitmask + aim stick.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+struct InputFrame(f32, u8, [f32; 2]);
+
+impl InputFrame {
+    fn capture(actions: &ActionState<Action>) -> Self {
+        let held = BUTTONS
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| actions.pressed(a))
+            .fold(0u8, |bits, (i, _)| bits | 1 << i);
+        let aim = actions.axis_pair(&Action::Aim);
+        Self(actions.value(&Action::Move), held, aim.to_array())
+    }
+
+    fn apply(self, actions: &mut ActionState<Action>) {
+        actions.set_value(&Action::Move, self.0);
+        actions.set_axis_pair(&Action::Aim, Vec2::from_array(self.2));
+        for (i, a) in BUTTONS.iter().enumerate() {
+            if self.1 & (1 << i) != 0 {
+                actions.press(a);
+            } else {
+                actions.release(a);
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct InputRecording {
+    /// `GameRng` state when recording started; playback reseeds with it.
+    seed: u64,
+    frames: Vec<InputFrame>,
+}
+
+/// Dev tool: F5 starts/stops capturing the player's input every fixed tick
+/// (saved to `RECORDING_PATH`), so a bug report can ship with the exact inputs.
+#[derive(Resource, Default)]
+pub struct InputRecorder {
+    recording: Option<InputRecording>,
+    /// Input since the last tick; buttons accumulate so taps between ticks survive.
+    pending: Option<InputFrame>,
+}
+
+impl InputRecorder {
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn start_recording(&mut self, seed: u64) {
+        self.recording = Some(InputRecording {
+            seed,
+            frames: Vec::new(),
+        });
+        self.pending = None;
+        info!("Replay: recording (seed {seed})");
+    }
+
+    pub fn stop_recording(&mut self) {
+        let Some(recording) = self.recording.take() else {
+            return;
+        };
+        let result = std::path::Path::new(RECORDING_PATH)
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .map_err(|e| e.to_string())
+            .and_then(|_| serde_json::to_string(&recording).map_err(|e| e.to_string()))
+            .and_then(|text| std::fs::write(RECORDING_PATH, text).map_err(|e| e.to_string()));
+        match result {
+            Ok(()) => info!(
+                "Replay: saved {} ticks to {RECORDING_PATH}",
+                recording.frames.len()
+            ),
+            Err(e) => warn!("Replay: failed to save {RECORDING_PATH}: {e}"),
+        }
+    }
+}
+
+/// While active, recorded ticks replace the `InputMap`-driven action state
+/// (F6 loads `RECORDING_PATH` and plays it from the current position). Each
+/// frame is pinned to one fixed timestep so every tick gets its own input.
+#[derive(Resource, Default)]
+pub struct PlaybackInputs {
+    frames: Vec<InputFrame>,
+    cursor: usize,
+}
+
+impl PlaybackInputs {
+    pub fn is_active(&self) -> bool {
+        self.cursor < self.frames.len()
+    }
+
+    fn stop(&mut self, strategy: &mut TimeUpdateStrategy) {
+        self.frames.clear();
+        self.cursor = 0;
+        *strategy = TimeUpdateStrategy::Automatic;
+    }
+}
+
+fn toggle_replay(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut recorder: ResMut<InputRecorder>,
+    mut playback: ResMut<PlaybackInputs>,
+    mut rng: ResMut<GameRng>,
+    mut strategy: ResMut<TimeUpdateStrategy>,
+    fixed: Res<Time<Fixed>>,
+) {
+    if keys.just_pressed(RECORD_TOGGLE) && !playback.is_active() {
+        if recorder.is_recording() {
+            recorder.stop_recording();
+        } else {
+            // Rolls already made this run don't matter, only the ones to come
+            let seed = rng.fork();
+            recorder.start_recording(seed);
+        }
+    }
+    if keys.just_pressed(PLAYBACK_TOGGLE) && !recorder.is_recording() {
+        if playback.is_active() {
+            playback.stop(&mut strategy);
+            info!("Replay: playback stopped");
+            return;
+        }
+        let loaded = std::fs::read_to_string(RECORDING_PATH)
+            .map_err(|e| e.to_string())
+            .and_then(|text| {
+                serde_json::from_str::<InputRecording>(&text).map_err(|e| e.to_string())
+            });
+        match loaded {
+            Ok(recording) => {
+                // Same rolls as the recorded run from here on
+                rng.reseed_with(recording.seed);
+                info!("Replay: playing {} ticks", recording.frames.len());
+                playback.frames = recording.frames;
+                playback.cursor = 0;
+                *strategy = TimeUpdateStrategy::ManualDuration(fixed.timestep());
+            }
+            Err(e) => warn!("Replay: can't load {RECORDING_PATH}: {e}"),
+        }
+    }
+}
+
+/// Per frame: fold the shaped input into the tick about to be recorded.
+fn collect_inputs(
+    mut recorder: ResMut<InputRecorder>,
+    q: Query<&ActionState<Action>, With<Player>>,
+) {
+    if !recorder.is_recording() {
+        return;
+    }
+    if let Ok(actions) = q.single() {
+        let frame = InputFrame::capture(actions);
+        let held = recorder.pending.map_or(0, |p| p.1);
+        recorder.pending = Some(InputFrame(frame.0, frame.1 | held, frame.2));
+    }
+}
+
+/// Per fixed tick: store what was collected since the previous one.
+fn record_tick(mut recorder: ResMut<InputRecorder>) {
+    let InputRecorder {
+        recording: Some(recording),
+        pending,
+    } = &mut *recorder
+    else {
+        return;
+    };
+    if let Some(frame) = pending.take() {
+        recording.frames.push(frame);
+    } else if let Some(&last) = recording.frames.last() {
+        // Several ticks in one frame share its input
+        recording.frames.push(last);
+    } else {
+        recording.frames.push(InputFrame::default());
+    }
+}
+
+/// Frames are pinned to one timestep during playback, so one tick per frame.
+fn play_back_inputs(
+    mut playback: ResMut<PlaybackInputs>,
+    mut strategy: ResMut<TimeUpdateStrategy>,
+    mut q: Query<&mut ActionState<Action>, With<Player>>,
+) {
+    if !playback.is_active() {
+        return;
+    }
+    let Ok(mut actions) = q.single_mut() else {
+        return;
+    };
+    playback.frames[playback.cursor].apply(&mut actions);
+    playback.cursor += 1;
+    if !playback.is_active() {
+        playback.stop(&mut strategy);
+        info!("Replay: playback finished");
+    }
+}
+
+/// Leaving gameplay ends both modes (and saves a pending recording).
+fn stop_replay_tools(
+    mut recorder: ResMut<InputRecorder>,
+    mut playback: ResMut<PlaybackInputs>,
+    mut strategy: ResMut<TimeUpdateStrategy>,
+) {
+    recorder.stop_recording();
+    if playback.is_active() {
+        playback.stop(&mut strategy);
+    }
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputRecorder>()
+            .init_resource::<PlaybackInputs>()
+            .add_systems(
+                Update,
+                toggle_replay.run_if(in_state(GameState::InGame).and(dev_enabled)),
+            )
+            .add_systems(
+                PreUpdate,
+                // Recordings hold the shaped axis, so playback isn't shaped twice
+                (collect_inputs, play_back_inputs)
+                    .chain()
+                    .after(shape_axis_input)
+                    .in_set(InputManagerSystem::ManualControl)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                record_tick.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), stop_replay_tools)
+            .add_systems(OnEnter(GameState::GameOver), stop_replay_tools);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputFrame, InputRecording};
+    use crate::character::Action;
+    use bevy::math::Vec2;
+    use leafwing_input_manager::prelude::ActionState;
+
+    #[test]
+    fn recorded_frame_plays_back_move_buttons_and_aim() {
+        let mut live = ActionState::<Action>::default();
+        live.set_value(&Action::Move, -0.75);
+        live.set_axis_pair(&Action::Aim, Vec2::new(0.5, -1.0));
+        live.press(&Action::Attack);
+        live.press(&Action::Dash);
+
+        let recording = InputRecording {
+            seed: 7,
+            frames: vec![InputFrame::capture(&live)],
+        };
+        let text = serde_json::to_string(&recording).unwrap();
+        let loaded: InputRecording = serde_json::from_str(&text).unwrap();
+
+        let mut replayed = ActionState::<Action>::default();
+        replayed.press(&Action::Jump);
+        loaded.frames[0].apply(&mut replayed);
+        assert_eq!(replayed.value(&Action::Move), -0.75);
+        assert_eq!(replayed.axis_pair(&Action::Aim), Vec2::new(0.5, -1.0));
+        assert!(replayed.pressed(&Action::Attack));
+        assert!(replayed.pressed(&Action::Dash));
+        assert!(!replayed.pressed(&Action::Jump));
+    }
+}
@@ -0,0 +1,71 @@
+// rng.rs
+use crate::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Single source of gameplay randomness (spawn positions, patrol pauses,
+/// and later loot/crits). Reseeded at the start of every run, so a fixed
+/// `seed` in settings.json replays the same rolls.
+#[derive(Resource)]
+pub struct GameRng {
+    /// Seed pinned in settings; `None` rolls a fresh one per run.
+    pub fixed_seed: Option<u64>,
+    /// Seed of the current run (logged, so any run can be pinned afterwards).
+    pub seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(fixed_seed: Option<u64>) -> Self {
+        let seed = fixed_seed.unwrap_or_else(rand::random);
+        Self {
+            fixed_seed,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn reseed(&mut self) {
+        *self = Self::new(self.fixed_seed);
+    }
+
+    /// Restart from a specific seed (e.g. a recorded run) for this run only.
+    pub fn reseed_with(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Reseed from the live stream and return that seed, so every roll from
+    /// here on can be reproduced with `reseed_with`.
+    pub fn fork(&mut self) -> u64 {
+        let seed = self.rng.next_u64();
+        self.reseed_with(seed);
+        seed
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.fill_bytes(dst)
+    }
+}
+
+/// Start-of-run reseed.
+pub fn reseed_game_rng(mut rng: ResMut<GameRng>) {
+    rng.reseed();
+    info!("GameRng: run seed {}", rng.seed);
+}
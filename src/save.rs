@@ -0,0 +1,65 @@
+// save.rs
+use crate::pickup::Wallet;
+use crate::prelude::*;
+use crate::progression::{Experience, NewGamePlus};
+use serde::{Deserialize, Serialize};
+
+const SAVE_PATH: &str = "save.json";
+
+/// Progress that carries across runs (settings stay in settings.json).
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SaveFile {
+    experience: Experience,
+    new_game_plus: NewGamePlus,
+    wallet: Wallet,
+}
+
+fn load_save(mut commands: Commands) {
+    let file = match std::fs::read_to_string(SAVE_PATH) {
+        Ok(text) => serde_json::from_str::<SaveFile>(&text).unwrap_or_else(|e| {
+            warn!("Save: {SAVE_PATH} is malformed ({e}); starting fresh");
+            SaveFile::default()
+        }),
+        Err(_) => SaveFile::default(),
+    };
+    commands.insert_resource(file.experience);
+    commands.insert_resource(file.new_game_plus);
+    commands.insert_resource(file.wallet);
+}
+
+fn write_save_on_change(
+    experience: Res<Experience>,
+    new_game_plus: Res<NewGamePlus>,
+    wallet: Res<Wallet>,
+) {
+    // All are inserted together by `load_save`; skip that first write
+    if experience.is_added()
+        || !(experience.is_changed() || new_game_plus.is_changed() || wallet.is_changed())
+    {
+        return;
+    }
+    let file = SaveFile {
+        experience: experience.clone(),
+        new_game_plus: new_game_plus.clone(),
+        wallet: wallet.clone(),
+    };
+    match serde_json::to_string_pretty(&file) {
+        Ok(text) => {
+            if let Err(e) = std::fs::write(SAVE_PATH, text) {
+                warn!("Save: failed to write {SAVE_PATH}: {e}");
+            }
+        }
+        Err(e) => warn!("Save: failed to serialize: {e}"),
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, load_save)
+            .add_systems(Last, write_save_on_change);
+    }
+}
@@ -0,0 +1,959 @@
+// settings.rs
+use crate::prelude::*;
+use crate::rng::GameRng;
+use bevy::ecs::system::SystemParam;
+use bevy::reflect::{DynamicEnum, DynamicVariant};
+use bevy::render::render_resource::TextureFormat;
+use bevy::window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode};
+use bevy::winit::WinitWindows;
+use serde::{Deserialize, Serialize};
+
+const SETTINGS_PATH: &str = "settings.json";
+
+// ───────── Sections ─────────
+
+/// Feedback effects that can be turned off individually for comfort.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct JuiceConfig {
+    /// No-op until the camera has a shake to turn off.
+    pub screen_shake: bool,
+    /// No-op until hits freeze time.
+    pub hit_stop: bool,
+    pub flashes: bool,
+    /// Floating text popups: heal and coin numbers, level-ups.
+    pub damage_numbers: bool,
+    pub low_health_pulse: bool,
+    pub chromatic_pulse: bool,
+    /// Highlight projectiles heading at the player, with edge markers off-screen.
+    pub projectile_warnings: bool,
+}
+
+impl Default for JuiceConfig {
+    fn default() -> Self {
+        Self {
+            screen_shake: true,
+            hit_stop: true,
+            flashes: true,
+            damage_numbers: true,
+            low_health_pulse: true,
+            chromatic_pulse: true,
+            projectile_warnings: true,
+        }
+    }
+}
+
+/// Whether Sprint has to be held or latches on with a press.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum SprintMode {
+    #[default]
+    Hold,
+    Toggle,
+}
+
+/// How the right stick steers attacks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum AimMode {
+    /// Attacks follow the facing direction.
+    #[default]
+    Off,
+    /// Snap to the nearest cardinal/diagonal.
+    EightWay,
+    Free,
+}
+
+// Settings-menu steps for the move axis sensitivity and response curve
+const MOVE_SENSITIVITIES: [f32; 4] = [0.75, 1.0, 1.25, 1.5];
+const MOVE_CURVES: [f32; 3] = [1.0, 1.5, 2.0];
+
+/// Input preferences.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct ControlsConfig {
+    pub sprint_mode: SprintMode,
+    pub aim_mode: AimMode,
+    /// Turn toward the nearest enemy in reach when a swing starts.
+    pub aim_assist: bool,
+    /// Flip the move axis (stick and keys).
+    pub invert_move: bool,
+    /// Flip the aim stick's vertical axis.
+    pub invert_aim_y: bool,
+    /// Scales move deflection; full speed still caps at 1.0.
+    pub move_sensitivity: f32,
+    /// Move response exponent: 1.0 is linear, higher is finer near center.
+    pub move_curve: f32,
+}
+
+impl Default for ControlsConfig {
+    fn default() -> Self {
+        Self {
+            sprint_mode: SprintMode::default(),
+            aim_mode: AimMode::default(),
+            aim_assist: false,
+            invert_move: false,
+            invert_aim_y: false,
+            move_sensitivity: 1.0,
+            move_curve: 1.0,
+        }
+    }
+}
+
+impl ControlsConfig {
+    /// The move axis after the curve, sensitivity and invert preferences.
+    pub fn shape_move(&self, raw: f32) -> f32 {
+        let curved = raw.abs().powf(self.move_curve.max(0.1)).copysign(raw);
+        let scaled = (curved * self.move_sensitivity.max(0.0)).clamp(-1.0, 1.0);
+        if self.invert_move { -scaled } else { scaled }
+    }
+}
+
+/// Keys and gamepad buttons for one button action, by variant name
+/// (`KeyCode::KeyJ` is `"KeyJ"`, `GamepadButton::West` is `"West"`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct ButtonBinding {
+    pub keys: Vec<String>,
+    pub gamepad: Vec<String>,
+}
+
+impl ButtonBinding {
+    fn new(keys: &[KeyCode], gamepad: &[GamepadButton]) -> Self {
+        Self {
+            keys: keys.iter().map(|k| format!("{k:?}")).collect(),
+            gamepad: gamepad.iter().map(|b| format!("{b:?}")).collect(),
+        }
+    }
+}
+
+/// Persisted player bindings; `character::build_input_map` turns them into
+/// the `InputMap`. The sticks (move and aim) aren't rebindable.
+#[derive(Resource, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct InputConfig {
+    pub move_left: String,
+    pub move_right: String,
+    pub jump: ButtonBinding,
+    pub attack: ButtonBinding,
+    pub sprint: ButtonBinding,
+    pub drop: ButtonBinding,
+    pub up: ButtonBinding,
+    pub swap_weapon: ButtonBinding,
+    pub special: ButtonBinding,
+    pub dash: ButtonBinding,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            move_left: format!("{:?}", KeyCode::KeyA),
+            move_right: format!("{:?}", KeyCode::KeyD),
+            jump: ButtonBinding::new(&[KeyCode::Space], &[GamepadButton::South]),
+            attack: ButtonBinding::new(&[KeyCode::KeyJ], &[GamepadButton::West]),
+            sprint: ButtonBinding::new(&[KeyCode::ShiftLeft], &[GamepadButton::LeftTrigger]),
+            drop: ButtonBinding::new(
+                &[KeyCode::KeyS, KeyCode::ArrowDown],
+                &[GamepadButton::DPadDown],
+            ),
+            up: ButtonBinding::new(&[KeyCode::KeyW, KeyCode::ArrowUp], &[GamepadButton::DPadUp]),
+            swap_weapon: ButtonBinding::new(&[KeyCode::KeyQ], &[GamepadButton::North]),
+            special: ButtonBinding::new(&[KeyCode::KeyK], &[GamepadButton::East]),
+            dash: ButtonBinding::new(&[KeyCode::KeyL], &[GamepadButton::RightTrigger]),
+        }
+    }
+}
+
+/// Parse a unit enum variant by name through reflection (`"KeyA"` →
+/// `KeyCode::KeyA`); unknown names give `None`.
+pub fn variant_from_name<T: FromReflect>(name: &str) -> Option<T> {
+    T::from_reflect(&DynamicEnum::new(name, DynamicVariant::Unit))
+}
+
+/// Window corner the HUD is pinned to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum HudAnchor {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+}
+
+impl HudAnchor {
+    fn label(self) -> &'static str {
+        match self {
+            HudAnchor::TopLeft => "Top Left",
+            HudAnchor::TopRight => "Top Right",
+            HudAnchor::BottomLeft => "Bottom Left",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            HudAnchor::TopLeft => HudAnchor::TopRight,
+            HudAnchor::TopRight => HudAnchor::BottomLeft,
+            HudAnchor::BottomLeft => HudAnchor::TopLeft,
+        }
+    }
+}
+
+const HUD_SCALES: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// HUD placement and size; the HUD rebuilds itself when this changes.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct HudConfig {
+    /// Multiplier on every HUD size (bars, text, gaps).
+    pub scale: f32,
+    pub anchor: HudAnchor,
+    /// Also scale with the window height, so the HUD keeps its proportions
+    /// across resizes and displays.
+    pub scale_with_window: bool,
+}
+
+impl Default for HudConfig {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            anchor: HudAnchor::TopLeft,
+            scale_with_window: true,
+        }
+    }
+}
+
+/// Window present mode (vsync), applied to the primary window on change.
+/// Only modes every surface supports (or falls back from) are offered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum VsyncMode {
+    AutoVsync,
+    #[default]
+    // Older settings files may still ask for the removed `immediate`
+    #[serde(alias = "immediate")]
+    AutoNoVsync,
+    Fifo,
+}
+
+impl VsyncMode {
+    const ALL: [VsyncMode; 3] = [
+        VsyncMode::AutoVsync,
+        VsyncMode::AutoNoVsync,
+        VsyncMode::Fifo,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            VsyncMode::AutoVsync => "On",
+            VsyncMode::AutoNoVsync => "Off",
+            VsyncMode::Fifo => "Fifo",
+        }
+    }
+
+    fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn present_mode(self) -> PresentMode {
+        match self {
+            VsyncMode::AutoVsync => PresentMode::AutoVsync,
+            VsyncMode::AutoNoVsync => PresentMode::AutoNoVsync,
+            VsyncMode::Fifo => PresentMode::Fifo,
+        }
+    }
+}
+
+/// Display options.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct VideoConfig {
+    /// Round the camera to whole screen pixels (crisp pixel art) instead of
+    /// smooth sub-pixel motion.
+    pub pixel_snap: bool,
+    pub vsync: VsyncMode,
+    pub window_mode: WindowModeSetting,
+    pub title: String,
+    /// Window icon, relative to the assets folder; `None` keeps the OS default.
+    pub icon: Option<String>,
+}
+
+impl Default for VideoConfig {
+    fn default() -> Self {
+        Self {
+            pixel_snap: true,
+            vsync: VsyncMode::default(),
+            window_mode: WindowModeSetting::default(),
+            title: "redeemer".into(),
+            icon: None,
+        }
+    }
+}
+
+/// Difficulty presets; picking one resets both damage scales.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+}
+
+/// Multipliers on the damage math in both directions: `incoming` on
+/// everything the player takes (`DamagePlayer`), `outgoing` on the player's
+/// melee hits. The scales can be hand-tuned in settings.json.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct DifficultyConfig {
+    pub preset: Difficulty,
+    pub incoming_damage_scale: f32,
+    pub outgoing_damage_scale: f32,
+}
+
+impl Default for DifficultyConfig {
+    fn default() -> Self {
+        Self::from_preset(Difficulty::Normal)
+    }
+}
+
+impl DifficultyConfig {
+    pub fn from_preset(preset: Difficulty) -> Self {
+        let (incoming_damage_scale, outgoing_damage_scale) = match preset {
+            Difficulty::Easy => (0.5, 1.25),
+            Difficulty::Normal => (1.0, 1.0),
+            Difficulty::Hard => (1.5, 0.85),
+        };
+        Self {
+            preset,
+            incoming_damage_scale,
+            outgoing_damage_scale,
+        }
+    }
+}
+
+/// Color-vision presets for the semantic colors in `Palette`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum PalettePreset {
+    #[default]
+    Default,
+    Deuteranopia,
+    Protanopia,
+    HighContrast,
+}
+
+impl PalettePreset {
+    const ALL: [PalettePreset; 4] = [
+        PalettePreset::Default,
+        PalettePreset::Deuteranopia,
+        PalettePreset::Protanopia,
+        PalettePreset::HighContrast,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            PalettePreset::Default => "Default",
+            PalettePreset::Deuteranopia => "Deuteranopia",
+            PalettePreset::Protanopia => "Protanopia",
+            PalettePreset::HighContrast => "High Contrast",
+        }
+    }
+
+    fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+/// Semantic colors for the HUD and in-world highlights. Read these instead of
+/// hardcoding; the whole set is swapped when the preset changes.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Palette {
+    pub preset: PalettePreset,
+    pub health: Color,
+    pub stamina: Color,
+    /// Floating heal numbers and the health bar flash.
+    pub heal: Color,
+    pub bar_bg: Color,
+    pub hit_flash: Color,
+    pub charge: Color,
+    pub charge_ready: Color,
+    /// Telegraphs for incoming danger (e.g. bomber blast radius).
+    pub danger: Color,
+    pub bleed: Color,
+    pub poison: Color,
+    /// Focus meter fill and the focus burst.
+    pub focus: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::from_preset(PalettePreset::Default)
+    }
+}
+
+impl Palette {
+    pub fn from_preset(preset: PalettePreset) -> Self {
+        match preset {
+            PalettePreset::Default => Self {
+                preset,
+                health: Color::srgb(0.85, 0.2, 0.2),
+                stamina: Color::srgb(0.72, 0.53, 0.04),
+                heal: Color::srgb(0.4, 0.9, 0.4),
+                bar_bg: Color::srgb(0.05, 0.05, 0.05),
+                hit_flash: Color::srgb(1.0, 0.35, 0.35),
+                charge: Color::srgba(0.9, 0.9, 0.9, 0.7),
+                charge_ready: Color::srgb(1.0, 0.8, 0.2),
+                danger: Color::srgba(1.0, 0.3, 0.1, 0.6),
+                bleed: Color::srgb(0.8, 0.1, 0.12),
+                poison: Color::srgb(0.35, 0.75, 0.2),
+                focus: Color::srgb(0.45, 0.55, 1.0),
+            },
+            // Okabe-Ito hues: keep red/green pairs apart via blue/orange
+            PalettePreset::Deuteranopia => Self {
+                preset,
+                health: Color::srgb(0.84, 0.37, 0.0),
+                stamina: Color::srgb(0.34, 0.71, 0.91),
+                heal: Color::srgb(0.0, 0.62, 0.45),
+                bar_bg: Color::srgb(0.05, 0.05, 0.05),
+                hit_flash: Color::srgb(0.45, 0.75, 1.0),
+                charge: Color::srgba(0.9, 0.9, 0.9, 0.7),
+                charge_ready: Color::srgb(0.94, 0.89, 0.26),
+                danger: Color::srgba(0.8, 0.47, 0.65, 0.7),
+                bleed: Color::srgb(0.84, 0.37, 0.0),
+                poison: Color::srgb(0.0, 0.45, 0.7),
+                focus: Color::srgb(0.94, 0.89, 0.26),
+            },
+            // Reds read dark, so lean on bright orange/yellow vs blue
+            PalettePreset::Protanopia => Self {
+                preset,
+                health: Color::srgb(0.9, 0.6, 0.0),
+                stamina: Color::srgb(0.0, 0.45, 0.7),
+                heal: Color::srgb(0.0, 0.62, 0.45),
+                bar_bg: Color::srgb(0.05, 0.05, 0.05),
+                hit_flash: Color::srgb(1.0, 0.9, 0.3),
+                charge: Color::srgba(0.9, 0.9, 0.9, 0.7),
+                charge_ready: Color::srgb(0.34, 0.71, 0.91),
+                danger: Color::srgba(0.94, 0.89, 0.26, 0.7),
+                bleed: Color::srgb(0.9, 0.6, 0.0),
+                poison: Color::srgb(0.34, 0.71, 0.91),
+                focus: Color::srgb(0.8, 0.47, 0.65),
+            },
+            PalettePreset::HighContrast => Self {
+                preset,
+                health: Color::srgb(1.0, 0.25, 0.25),
+                stamina: Color::srgb(0.2, 0.9, 1.0),
+                heal: Color::srgb(0.2, 1.0, 0.2),
+                bar_bg: Color::BLACK,
+                hit_flash: Color::WHITE,
+                charge: Color::srgba(1.0, 1.0, 1.0, 0.9),
+                charge_ready: Color::srgb(1.0, 1.0, 0.0),
+                danger: Color::srgba(1.0, 0.0, 1.0, 0.85),
+                bleed: Color::srgb(1.0, 0.25, 0.25),
+                poison: Color::srgb(0.2, 1.0, 0.2),
+                focus: Color::srgb(0.55, 0.55, 1.0),
+            },
+        }
+    }
+}
+
+/// Primary window mode, applied on change (and at startup).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowModeSetting {
+    #[default]
+    Borderless,
+    Windowed,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    const ALL: [WindowModeSetting; 3] = [
+        WindowModeSetting::Borderless,
+        WindowModeSetting::Windowed,
+        WindowModeSetting::Fullscreen,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            WindowModeSetting::Borderless => "Borderless",
+            WindowModeSetting::Windowed => "Windowed",
+            WindowModeSetting::Fullscreen => "Fullscreen",
+        }
+    }
+
+    fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|m| *m == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn window_mode(self) -> WindowMode {
+        match self {
+            WindowModeSetting::Borderless => {
+                WindowMode::BorderlessFullscreen(MonitorSelection::Primary)
+            }
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current)
+            }
+        }
+    }
+}
+
+/// On-disk layout; every section falls back to its default when missing.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SettingsFile {
+    juice: JuiceConfig,
+    palette: PalettePreset,
+    controls: ControlsConfig,
+    hud: HudConfig,
+    video: VideoConfig,
+    input: InputConfig,
+    difficulty: DifficultyConfig,
+    /// Pin the gameplay RNG for reproducible runs (omit for random).
+    seed: Option<u64>,
+}
+
+// ───────── Menu items ─────────
+
+/// One adjustable row in the settings menu.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingItem {
+    Difficulty,
+    ScreenShake,
+    HitStop,
+    Flashes,
+    DamageNumbers,
+    LowHealthPulse,
+    ChromaticPulse,
+    ProjectileWarnings,
+    Palette,
+    HudScale,
+    HudAnchor,
+    SprintMode,
+    AimMode,
+    AimAssist,
+    InvertMove,
+    InvertAim,
+    MoveSensitivity,
+    MoveCurve,
+    PixelSnap,
+    Vsync,
+    WindowMode,
+}
+
+impl SettingItem {
+    pub const ALL: [SettingItem; 21] = [
+        SettingItem::Difficulty,
+        SettingItem::ScreenShake,
+        SettingItem::HitStop,
+        SettingItem::Flashes,
+        SettingItem::DamageNumbers,
+        SettingItem::LowHealthPulse,
+        SettingItem::ChromaticPulse,
+        SettingItem::ProjectileWarnings,
+        SettingItem::Palette,
+        SettingItem::HudScale,
+        SettingItem::HudAnchor,
+        SettingItem::SprintMode,
+        SettingItem::AimMode,
+        SettingItem::AimAssist,
+        SettingItem::InvertMove,
+        SettingItem::InvertAim,
+        SettingItem::MoveSensitivity,
+        SettingItem::MoveCurve,
+        SettingItem::PixelSnap,
+        SettingItem::Vsync,
+        SettingItem::WindowMode,
+    ];
+}
+
+fn on_off(v: bool) -> &'static str {
+    if v { "On" } else { "Off" }
+}
+
+/// Every settings section, so menus and persistence touch one place.
+#[derive(SystemParam)]
+pub struct SettingsParams<'w> {
+    juice: ResMut<'w, JuiceConfig>,
+    palette: ResMut<'w, Palette>,
+    controls: ResMut<'w, ControlsConfig>,
+    hud: ResMut<'w, HudConfig>,
+    video: ResMut<'w, VideoConfig>,
+    input: Res<'w, InputConfig>,
+    difficulty: ResMut<'w, DifficultyConfig>,
+    rng: Res<'w, GameRng>,
+}
+
+impl SettingsParams<'_> {
+    pub fn label(&self, item: SettingItem) -> String {
+        match item {
+            SettingItem::Difficulty => format!("Difficulty: {}", self.difficulty.preset.label()),
+            SettingItem::ScreenShake => {
+                format!("Screen Shake: {}", on_off(self.juice.screen_shake))
+            }
+            SettingItem::HitStop => format!("Hit-Stop: {}", on_off(self.juice.hit_stop)),
+            SettingItem::Flashes => format!("Hit Flashes: {}", on_off(self.juice.flashes)),
+            SettingItem::DamageNumbers => {
+                format!("Popup Numbers: {}", on_off(self.juice.damage_numbers))
+            }
+            SettingItem::LowHealthPulse => {
+                format!("Low-Health Pulse: {}", on_off(self.juice.low_health_pulse))
+            }
+            SettingItem::ChromaticPulse => {
+                format!("Damage Aberration: {}", on_off(self.juice.chromatic_pulse))
+            }
+            SettingItem::ProjectileWarnings => format!(
+                "Projectile Warnings: {}",
+                on_off(self.juice.projectile_warnings)
+            ),
+            SettingItem::Palette => format!("Palette: {}", self.palette.preset.label()),
+            SettingItem::HudScale => format!("HUD Scale: {}%", (self.hud.scale * 100.0).round()),
+            SettingItem::HudAnchor => format!("HUD Position: {}", self.hud.anchor.label()),
+            SettingItem::SprintMode => match self.controls.sprint_mode {
+                SprintMode::Hold => "Sprint: Hold".to_string(),
+                SprintMode::Toggle => "Sprint: Toggle".to_string(),
+            },
+            SettingItem::AimMode => match self.controls.aim_mode {
+                AimMode::Off => "Stick Aim: Off".to_string(),
+                AimMode::EightWay => "Stick Aim: 8-Way".to_string(),
+                AimMode::Free => "Stick Aim: Free".to_string(),
+            },
+            SettingItem::AimAssist => format!("Aim Assist: {}", on_off(self.controls.aim_assist)),
+            SettingItem::InvertMove => {
+                format!("Invert Move: {}", on_off(self.controls.invert_move))
+            }
+            SettingItem::InvertAim => {
+                format!("Invert Aim Y: {}", on_off(self.controls.invert_aim_y))
+            }
+            SettingItem::MoveSensitivity => format!(
+                "Move Sensitivity: {}%",
+                (self.controls.move_sensitivity * 100.0).round()
+            ),
+            SettingItem::MoveCurve => format!("Move Response: x{:.1}", self.controls.move_curve),
+            SettingItem::PixelSnap => format!("Pixel Snap: {}", on_off(self.video.pixel_snap)),
+            SettingItem::Vsync => format!("VSync: {}", self.video.vsync.label()),
+            SettingItem::WindowMode => format!("Window: {}", self.video.window_mode.label()),
+        }
+    }
+
+    /// Advance the item to its next value.
+    pub fn cycle(&mut self, item: SettingItem) {
+        match item {
+            SettingItem::Difficulty => {
+                *self.difficulty = DifficultyConfig::from_preset(self.difficulty.preset.next())
+            }
+            SettingItem::ScreenShake => self.juice.screen_shake = !self.juice.screen_shake,
+            SettingItem::HitStop => self.juice.hit_stop = !self.juice.hit_stop,
+            SettingItem::Flashes => self.juice.flashes = !self.juice.flashes,
+            SettingItem::DamageNumbers => self.juice.damage_numbers = !self.juice.damage_numbers,
+            SettingItem::LowHealthPulse => {
+                self.juice.low_health_pulse = !self.juice.low_health_pulse
+            }
+            SettingItem::ChromaticPulse => self.juice.chromatic_pulse = !self.juice.chromatic_pulse,
+            SettingItem::ProjectileWarnings => {
+                self.juice.projectile_warnings = !self.juice.projectile_warnings
+            }
+            SettingItem::Palette => {
+                *self.palette = Palette::from_preset(self.palette.preset.next())
+            }
+            SettingItem::HudScale => {
+                // Next step up, wrapping; off-list values snap to the first larger one
+                let next = HUD_SCALES.iter().find(|s| **s > self.hud.scale + 0.01);
+                self.hud.scale = *next.unwrap_or(&HUD_SCALES[0]);
+            }
+            SettingItem::HudAnchor => self.hud.anchor = self.hud.anchor.next(),
+            SettingItem::SprintMode => {
+                self.controls.sprint_mode = match self.controls.sprint_mode {
+                    SprintMode::Hold => SprintMode::Toggle,
+                    SprintMode::Toggle => SprintMode::Hold,
+                }
+            }
+            SettingItem::AimMode => {
+                self.controls.aim_mode = match self.controls.aim_mode {
+                    AimMode::Off => AimMode::EightWay,
+                    AimMode::EightWay => AimMode::Free,
+                    AimMode::Free => AimMode::Off,
+                }
+            }
+            SettingItem::AimAssist => self.controls.aim_assist = !self.controls.aim_assist,
+            SettingItem::InvertMove => self.controls.invert_move = !self.controls.invert_move,
+            SettingItem::InvertAim => self.controls.invert_aim_y = !self.controls.invert_aim_y,
+            SettingItem::MoveSensitivity => {
+                let next = MOVE_SENSITIVITIES
+                    .iter()
+                    .find(|s| **s > self.controls.move_sensitivity + 0.01);
+                self.controls.move_sensitivity = *next.unwrap_or(&MOVE_SENSITIVITIES[0]);
+            }
+            SettingItem::MoveCurve => {
+                let next = MOVE_CURVES
+                    .iter()
+                    .find(|c| **c > self.controls.move_curve + 0.01);
+                self.controls.move_curve = *next.unwrap_or(&MOVE_CURVES[0]);
+            }
+            SettingItem::PixelSnap => self.video.pixel_snap = !self.video.pixel_snap,
+            SettingItem::Vsync => self.video.vsync = self.video.vsync.next(),
+            SettingItem::WindowMode => self.video.window_mode = self.video.window_mode.next(),
+        }
+    }
+
+    fn changed(&self) -> bool {
+        (self.juice.is_changed() && !self.juice.is_added())
+            || (self.palette.is_changed() && !self.palette.is_added())
+            || (self.controls.is_changed() && !self.controls.is_added())
+            || (self.hud.is_changed() && !self.hud.is_added())
+            || (self.video.is_changed() && !self.video.is_added())
+            || (self.input.is_changed() && !self.input.is_added())
+            || (self.difficulty.is_changed() && !self.difficulty.is_added())
+    }
+
+    fn to_file(&self) -> SettingsFile {
+        SettingsFile {
+            juice: self.juice.clone(),
+            palette: self.palette.preset,
+            controls: self.controls.clone(),
+            hud: self.hud.clone(),
+            video: self.video.clone(),
+            input: self.input.clone(),
+            difficulty: self.difficulty.clone(),
+            seed: self.rng.fixed_seed,
+        }
+    }
+}
+
+// ───────── Persistence ─────────
+
+fn load_settings(mut commands: Commands) {
+    let file = match std::fs::read_to_string(SETTINGS_PATH) {
+        Ok(text) => serde_json::from_str::<SettingsFile>(&text).unwrap_or_else(|e| {
+            warn!("Settings: {SETTINGS_PATH} is malformed ({e}); using defaults");
+            SettingsFile::default()
+        }),
+        Err(_) => SettingsFile::default(),
+    };
+    commands.insert_resource(file.juice);
+    commands.insert_resource(Palette::from_preset(file.palette));
+    commands.insert_resource(file.controls);
+    commands.insert_resource(HudConfig {
+        scale: file.hud.scale.clamp(0.5, 3.0),
+        ..file.hud
+    });
+    commands.insert_resource(file.video);
+    commands.insert_resource(file.input);
+    commands.insert_resource(file.difficulty);
+    commands.insert_resource(GameRng::new(file.seed));
+}
+
+fn save_settings_on_change(settings: SettingsParams) {
+    if !settings.changed() {
+        return;
+    }
+    match serde_json::to_string_pretty(&settings.to_file()) {
+        Ok(text) => {
+            if let Err(e) = std::fs::write(SETTINGS_PATH, text) {
+                warn!("Settings: failed to write {SETTINGS_PATH}: {e}");
+            }
+        }
+        Err(e) => warn!("Settings: failed to serialize: {e}"),
+    }
+}
+
+// ───────── Window ─────────
+
+/// Push the vsync choice to the primary window; Bevy reconfigures the
+/// surface on the next frame, no restart needed.
+fn apply_present_mode(
+    video: Res<VideoConfig>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !video.is_changed() {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let mode = video.vsync.present_mode();
+    if window.present_mode != mode {
+        window.present_mode = mode;
+    }
+}
+
+/// Window mode and title from `VideoConfig`. The settings are inserted
+/// before the first `Update`, so this also covers startup.
+fn apply_window_mode(
+    video: Res<VideoConfig>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !video.is_changed() {
+        return;
+    }
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+    let mode = video.window_mode.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    if window.title != video.title {
+        window.title.clone_from(&video.title);
+    }
+}
+
+/// Icon image requested by `load_window_icon`; cleared once it's been
+/// handed to the OS window.
+#[derive(Resource, Default)]
+struct WindowIcon(Option<Handle<Image>>);
+
+fn load_window_icon(
+    video: Res<VideoConfig>,
+    assets: Res<AssetServer>,
+    mut icon: ResMut<WindowIcon>,
+) {
+    icon.0 = video.icon.as_ref().map(|path| assets.load(path.as_str()));
+}
+
+/// Bevy has no icon field on `Window`; set it on the winit window once the
+/// image has loaded.
+fn set_window_icon(
+    mut icon: ResMut<WindowIcon>,
+    images: Res<Assets<Image>>,
+    windows: NonSend<WinitWindows>,
+    primary: Query<Entity, With<PrimaryWindow>>,
+) {
+    let Some(handle) = &icon.0 else {
+        return;
+    };
+    let Some(image) = images.get(handle) else {
+        return;
+    };
+    let Some(window) = primary.single().ok().and_then(|e| windows.get_window(e)) else {
+        return;
+    };
+    let (width, height) = (image.width(), image.height());
+    let rgba = image
+        .convert(TextureFormat::Rgba8UnormSrgb)
+        .and_then(|img| img.data);
+    match rgba.map(|data| winit::window::Icon::from_rgba(data, width, height)) {
+        Some(Ok(winit_icon)) => window.set_window_icon(Some(winit_icon)),
+        Some(Err(e)) => warn!("Settings: unusable window icon: {e}"),
+        None => warn!("Settings: window icon isn't convertible to RGBA8"),
+    }
+    icon.0 = None;
+}
+
+// ───────── Plugin ─────────
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<JuiceConfig>()
+            .register_type::<JuiceConfig>()
+            .init_resource::<Palette>()
+            .register_type::<Palette>()
+            .init_resource::<ControlsConfig>()
+            .register_type::<ControlsConfig>()
+            .init_resource::<HudConfig>()
+            .register_type::<HudConfig>()
+            .init_resource::<VideoConfig>()
+            .register_type::<VideoConfig>()
+            .init_resource::<InputConfig>()
+            .register_type::<InputConfig>()
+            .init_resource::<DifficultyConfig>()
+            .register_type::<DifficultyConfig>()
+            .init_resource::<GameRng>()
+            .init_resource::<WindowIcon>()
+            .add_systems(PreStartup, load_settings)
+            .add_systems(Startup, load_window_icon)
+            .add_systems(
+                Update,
+                (
+                    apply_present_mode,
+                    apply_window_mode,
+                    set_window_icon.run_if(|icon: Res<WindowIcon>| icon.0.is_some()),
+                ),
+            )
+            .add_systems(Last, save_settings_on_change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ControlsConfig, InputConfig, variant_from_name};
+    use bevy::prelude::{GamepadButton, KeyCode};
+
+    #[test]
+    fn input_config_round_trips_through_json() {
+        let mut config = InputConfig::default();
+        config.attack.keys.push("KeyK".into());
+        let text = serde_json::to_string(&config).unwrap();
+        let back: InputConfig = serde_json::from_str(&text).unwrap();
+        assert_eq!(back, config);
+    }
+
+    #[test]
+    fn missing_fields_keep_default_bindings() {
+        let back: InputConfig = serde_json::from_str(r#"{"move_left":"ArrowLeft"}"#).unwrap();
+        assert_eq!(back.move_left, "ArrowLeft");
+        assert_eq!(back.jump, InputConfig::default().jump);
+    }
+
+    #[test]
+    fn binding_names_parse_by_variant() {
+        assert_eq!(variant_from_name::<KeyCode>("KeyJ"), Some(KeyCode::KeyJ));
+        assert_eq!(
+            variant_from_name::<GamepadButton>("West"),
+            Some(GamepadButton::West)
+        );
+        assert_eq!(variant_from_name::<KeyCode>("NotAKey"), None);
+        // Default names are exactly what the parser expects
+        let config = InputConfig::default();
+        assert!(variant_from_name::<KeyCode>(&config.move_right).is_some());
+        assert!(
+            config
+                .drop
+                .gamepad
+                .iter()
+                .all(|n| variant_from_name::<GamepadButton>(n).is_some())
+        );
+    }
+
+    #[test]
+    fn move_axis_shaping() {
+        let mut controls = ControlsConfig::default();
+        assert_eq!(controls.shape_move(0.5), 0.5);
+        controls.move_curve = 2.0;
+        assert_eq!(controls.shape_move(-0.5), -0.25);
+        controls.move_sensitivity = 1.5;
+        controls.invert_move = true;
+        // Sensitivity can't push past full deflection
+        assert_eq!(controls.shape_move(1.0), -1.0);
+        assert_eq!(controls.shape_move(0.0), 0.0);
+    }
+}
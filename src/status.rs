@@ -0,0 +1,168 @@
+// status.rs
+use crate::character::{Dead, Player};
+use crate::class::PlayerClass;
+use crate::enemy_class::EnemyClass;
+use crate::gameflow::GameState;
+use crate::hud::DamagePlayer;
+use crate::prelude::*;
+use crate::raycasts::{MeleeRaycastHit, RaycastMeleeSet};
+use crate::settings::Palette;
+use serde::Deserialize;
+
+/// Most simultaneous applications kept per kind; a new one replaces the oldest.
+const MAX_STACKS_PER_KIND: usize = 5;
+
+// ───────── Data ─────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Reflect)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusKind {
+    Bleed,
+    Poison,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 2] = [StatusKind::Bleed, StatusKind::Poison];
+
+    pub fn color(self, palette: &Palette) -> Color {
+        match self {
+            StatusKind::Bleed => palette.bleed,
+            StatusKind::Poison => palette.poison,
+        }
+    }
+
+    pub fn glyph(self) -> &'static str {
+        match self {
+            StatusKind::Bleed => "B",
+            StatusKind::Poison => "P",
+        }
+    }
+}
+
+/// Enemy class flag: melee hits from this class also apply a status.
+/// `dps` is before the target's defense.
+#[derive(Debug, Clone, Deserialize, Reflect)]
+pub struct StatusOnHit {
+    pub kind: StatusKind,
+    pub dps: f32,
+    pub duration: f32,
+}
+
+/// One application. `dps` already has defense applied, so ticks bypass it.
+#[derive(Debug, Clone)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub dps: f32,
+    pub remaining: Timer,
+}
+
+/// Active statuses on the player; every application stacks independently.
+#[derive(Component, Default, Debug)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    pub fn stacks(&self, kind: StatusKind) -> usize {
+        self.0.iter().filter(|s| s.kind == kind).count()
+    }
+
+    fn push(&mut self, effect: StatusEffect) {
+        if self.stacks(effect.kind) >= MAX_STACKS_PER_KIND {
+            // Oldest applications sit first
+            if let Some(i) = self.0.iter().position(|s| s.kind == effect.kind) {
+                self.0.remove(i);
+            }
+        }
+        self.0.push(effect);
+    }
+}
+
+// ───────── Systems ─────────
+
+fn apply_status_on_hit(
+    mut commands: Commands,
+    mut hits: EventReader<MeleeRaycastHit>,
+    attackers: Query<&EnemyClass>,
+    mut players: Query<
+        (Option<&PlayerClass>, Option<&mut StatusEffects>),
+        (With<Player>, Without<Dead>),
+    >,
+) {
+    for hit in hits.read() {
+        let Ok(class) = attackers.get(hit.attacker) else {
+            continue;
+        };
+        let Some(on_hit) = class.0.base_stats.on_hit_status.as_ref() else {
+            continue;
+        };
+        let Ok((player_class, effects)) = players.get_mut(hit.target) else {
+            continue;
+        };
+
+        // Defense is applied once here, not on every tick
+        let defense = player_class
+            .map(|c| c.0.base_stats.defense)
+            .unwrap_or(0.0)
+            .clamp(0.0, 0.95);
+        let effect = StatusEffect {
+            kind: on_hit.kind,
+            dps: (on_hit.dps * (1.0 - defense)).max(0.0),
+            remaining: Timer::from_seconds(on_hit.duration, TimerMode::Once),
+        };
+
+        match effects {
+            Some(mut effects) => effects.push(effect),
+            None => {
+                let mut effects = StatusEffects::default();
+                effects.push(effect);
+                commands.entity(hit.target).insert(effects);
+            }
+        }
+    }
+}
+
+fn tick_status_effects(
+    time: Res<Time>,
+    mut damage: EventWriter<DamagePlayer>,
+    mut q: Query<(&mut StatusEffects, Option<&Dead>), With<Player>>,
+) {
+    for (mut effects, dead) in &mut q {
+        if dead.is_some() {
+            effects.0.clear();
+            continue;
+        }
+        if effects.0.is_empty() {
+            continue;
+        }
+
+        let mut total = 0.0;
+        for s in &mut effects.0 {
+            let before = s.remaining.elapsed_secs();
+            s.remaining.tick(time.delta());
+            // Only the time actually left on the effect deals damage
+            total += s.dps * (s.remaining.elapsed_secs() - before);
+        }
+        effects.0.retain(|s| !s.remaining.finished());
+
+        if total > 0.0 {
+            damage.write(DamagePlayer::tick(total));
+        }
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<StatusKind>()
+            .register_type::<StatusOnHit>()
+            .add_systems(
+                Update,
+                (apply_status_on_hit, tick_status_effects)
+                    .chain()
+                    .before(RaycastMeleeSet::ApplyDamage)
+                    .after(RaycastMeleeSet::Cast)
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
@@ -0,0 +1,130 @@
+// swing_step.rs
+use crate::character::{Action, Player};
+use crate::dev::dev_enabled;
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use crate::raycasts::AttackRay;
+use bevy::time::TimeUpdateStrategy;
+
+const SWING_STEP_TOGGLE: KeyCode = KeyCode::F7;
+const SWING_STEP_ADVANCE: KeyCode = KeyCode::F8;
+const RAY_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+const HIT_COLOR: Color = Color::srgb(1.0, 0.25, 0.2);
+const HIT_MARK_RADIUS: f32 = 3.0;
+
+/// Dev tool for checking hit windows: F7 makes the player swing and freezes
+/// virtual time right after; each F8 then advances exactly one fixed tick.
+/// F7 again resumes. Attack rays and their hits are drawn while it's on.
+#[derive(Resource, Default)]
+pub struct SwingStep {
+    active: bool,
+    /// Attack was pressed last frame; release it (and freeze) this one.
+    releasing: bool,
+    /// A one-tick step is running this frame; re-freeze afterwards.
+    stepping: bool,
+}
+
+impl SwingStep {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// Runs with the input manager's manual-control set so the state machine
+/// sees the tap this same frame: a press, then a release the next frame,
+/// since ground attacks start on release and air attacks on press.
+fn start_swing_step(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut step: ResMut<SwingStep>,
+    mut time: ResMut<Time<Virtual>>,
+    mut q: Query<&mut ActionState<Action>, With<Player>>,
+) {
+    if step.releasing {
+        step.releasing = false;
+        if let Ok(mut actions) = q.single_mut() {
+            actions.release(&Action::Attack);
+        }
+        // Takes effect next frame, so the swing's first frame still plays
+        step.active = true;
+        time.pause();
+        info!("Swing step: frozen (F8 steps one tick, F7 resumes)");
+        return;
+    }
+    if !keys.just_pressed(SWING_STEP_TOGGLE) {
+        return;
+    }
+    if step.active {
+        step.active = false;
+        time.unpause();
+        info!("Swing step: resumed");
+        return;
+    }
+    if let Ok(mut actions) = q.single_mut() {
+        actions.press(&Action::Attack);
+    }
+    step.releasing = true;
+}
+
+/// F8 unpauses for one frame whose real delta is forced to one fixed
+/// timestep, so `FixedUpdate` (physics, rays) runs exactly once.
+fn advance_swing_step(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut step: ResMut<SwingStep>,
+    mut time: ResMut<Time<Virtual>>,
+    fixed: Res<Time<Fixed>>,
+    mut strategy: ResMut<TimeUpdateStrategy>,
+) {
+    if step.stepping {
+        step.stepping = false;
+        *strategy = TimeUpdateStrategy::Automatic;
+        time.pause();
+        return;
+    }
+    if !step.active || !keys.just_pressed(SWING_STEP_ADVANCE) {
+        return;
+    }
+    step.stepping = true;
+    *strategy = TimeUpdateStrategy::ManualDuration(fixed.timestep());
+    time.unpause();
+}
+
+fn draw_attack_rays(rays: Query<(&RayCaster, &RayHits), With<AttackRay>>, mut gizmos: Gizmos) {
+    for (ray, hits) in &rays {
+        let origin = ray.global_origin();
+        let dir = *ray.global_direction();
+        gizmos.line_2d(origin, origin + dir * ray.max_distance, RAY_COLOR);
+        for hit in hits.iter() {
+            gizmos.circle_2d(origin + dir * hit.distance, HIT_MARK_RADIUS, HIT_COLOR);
+        }
+    }
+}
+
+/// Leaving gameplay (pause menu included) drops the tool; the menus manage
+/// pausing themselves.
+fn stop_swing_step(mut step: ResMut<SwingStep>, mut strategy: ResMut<TimeUpdateStrategy>) {
+    *step = SwingStep::default();
+    *strategy = TimeUpdateStrategy::Automatic;
+}
+
+pub struct SwingStepPlugin;
+
+impl Plugin for SwingStepPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SwingStep>()
+            .add_systems(
+                PreUpdate,
+                start_swing_step
+                    .in_set(InputManagerSystem::ManualControl)
+                    .run_if(in_state(GameState::InGame).and(dev_enabled)),
+            )
+            .add_systems(
+                Update,
+                (
+                    advance_swing_step,
+                    draw_attack_rays.run_if(|step: Res<SwingStep>| step.is_active()),
+                )
+                    .run_if(in_state(GameState::InGame).and(dev_enabled)),
+            )
+            .add_systems(OnExit(GameState::InGame), stop_swing_step);
+    }
+}
@@ -0,0 +1,165 @@
+// transition.rs
+use crate::character::Action;
+use crate::gameflow::GameState;
+use crate::prelude::*;
+use bevy::ui::{FocusPolicy, GlobalZIndex};
+
+const DEFAULT_FADE_SECS: f32 = 0.35;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FadeDir {
+    Idle,
+    /// Darkening towards black; the state switches when it completes.
+    Out,
+    /// Revealing the new state.
+    In,
+}
+
+/// Fade-to-black state change. Use `Transition::to` instead of
+/// `NextState::set` where the cut should be smoothed over.
+/// Runs on real time so it also works out of the (time-paused) pause menu.
+#[derive(Resource)]
+pub struct Transition {
+    pub timer: Timer,
+    pub dir: FadeDir,
+    /// Length of each half (out and in), in seconds.
+    pub fade_secs: f32,
+    target: Option<GameState>,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(DEFAULT_FADE_SECS, TimerMode::Once),
+            dir: FadeDir::Idle,
+            fade_secs: DEFAULT_FADE_SECS,
+            target: None,
+        }
+    }
+}
+
+impl Transition {
+    /// Ignored while a fade is already running (repeated requests such as
+    /// `PlayerDied` firing every frame must not restart it).
+    pub fn to(&mut self, target: GameState) {
+        if self.is_active() {
+            return;
+        }
+        self.target = Some(target);
+        self.start(FadeDir::Out);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.dir != FadeDir::Idle
+    }
+
+    fn start(&mut self, dir: FadeDir) {
+        self.dir = dir;
+        self.timer = Timer::from_seconds(self.fade_secs, TimerMode::Once);
+    }
+
+    fn alpha(&self) -> f32 {
+        match self.dir {
+            FadeDir::Idle => 0.0,
+            FadeDir::Out => self.timer.fraction(),
+            FadeDir::In => self.timer.fraction_remaining(),
+        }
+    }
+}
+
+/// Run condition: no fade in progress.
+pub fn not_transitioning(transition: Res<Transition>) -> bool {
+    !transition.is_active()
+}
+
+#[derive(Component)]
+struct FadeOverlay;
+
+fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(10_000),
+        // Swallows clicks so menus can't be used mid-fade
+        FocusPolicy::Block,
+        Visibility::Hidden,
+        FadeOverlay,
+        Name::new("FadeOverlay"),
+    ));
+}
+
+fn tick_transition(
+    time: Res<Time<Real>>,
+    mut transition: ResMut<Transition>,
+    mut next: ResMut<NextState<GameState>>,
+) {
+    if !transition.is_active() {
+        return;
+    }
+    transition.timer.tick(time.delta());
+    if !transition.timer.finished() {
+        return;
+    }
+    match transition.dir {
+        FadeDir::Out => {
+            // Swap while fully black; the fade-in starts as the new state enters
+            if let Some(target) = transition.target.take() {
+                next.set(target);
+            }
+            transition.start(FadeDir::In);
+        }
+        FadeDir::In => transition.dir = FadeDir::Idle,
+        FadeDir::Idle => {}
+    }
+}
+
+fn draw_fade_overlay(
+    transition: Res<Transition>,
+    mut q: Query<(&mut BackgroundColor, &mut Visibility), With<FadeOverlay>>,
+) {
+    for (mut bg, mut vis) in &mut q {
+        bg.0 = Color::BLACK.with_alpha(transition.alpha());
+        *vis = if transition.is_active() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// Gameplay input is ignored for the whole fade.
+fn block_input_during_fade(
+    transition: Res<Transition>,
+    mut q: Query<&mut ActionState<Action>>,
+    mut was_active: Local<bool>,
+) {
+    let active = transition.is_active();
+    if active {
+        for mut actions in &mut q {
+            actions.disable_all();
+        }
+    } else if *was_active {
+        for mut actions in &mut q {
+            actions.enable_all();
+        }
+    }
+    *was_active = active;
+}
+
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Transition>()
+            .add_systems(Startup, spawn_fade_overlay)
+            .add_systems(
+                Update,
+                (tick_transition, draw_fade_overlay, block_input_during_fade).chain(),
+            );
+    }
+}
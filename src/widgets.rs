@@ -0,0 +1,64 @@
+// widgets.rs
+use crate::prelude::*;
+use bevy::ui::UiSystem;
+
+// ───────── Bars ─────────
+
+/// Fill of a horizontal bar; set `fraction` (0..=1) and the width follows.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct Bar {
+    pub fraction: f32,
+}
+
+/// Spawn a bordered bar. Returns `(container, fill)`; add extra children
+/// (e.g. a text overlay) to the container and drive `Bar` on the fill.
+pub fn spawn_bar(
+    commands: &mut Commands,
+    width: f32,
+    height: f32,
+    fg_color: Color,
+    bg_color: Color,
+) -> (Entity, Entity) {
+    let container = commands
+        .spawn((
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(height),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.1, 0.1, 0.1)),
+            BackgroundColor(bg_color),
+        ))
+        .id();
+
+    let fill = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(fg_color),
+            Bar { fraction: 1.0 },
+        ))
+        .id();
+
+    commands.entity(container).add_child(fill);
+    (container, fill)
+}
+
+fn update_bars(mut q: Query<(&Bar, &mut Node), Changed<Bar>>) {
+    for (bar, mut node) in &mut q {
+        node.width = Val::Percent(bar.fraction.clamp(0.0, 1.0) * 100.0);
+    }
+}
+
+// ───────── Plugin ─────────
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_bars.before(UiSystem::Layout));
+    }
+}
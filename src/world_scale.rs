@@ -0,0 +1,71 @@
+// world_scale.rs
+use crate::enemy::EnemyTuning;
+use crate::prelude::*;
+
+// Authored values at `WorldScale::unit == 1.0`; they were tuned together,
+// so scale them through `WorldScale` rather than editing one by hand.
+
+// Avian's length unit (roughly the size of a typical dynamic body)
+const BASE_LENGTH_UNIT: f32 = 2.0;
+const BASE_GRAVITY: f32 = 1000.0;
+// Player and enemy body capsule
+const BODY_RADIUS: f32 = 8.0;
+const BODY_LENGTH: f32 = 26.0;
+
+/// One knob for the core movement numbers: gravity, the physics length unit,
+/// the body capsule, player run/jump/knockback speeds, enemy knockback and
+/// juggle pops, and the `EnemyTuning` distances and speeds all scale linearly
+/// with `unit`, so jumps keep their timing and reach the same height relative
+/// to the bodies. Not covered yet (still authored at 1.0): melee ray lengths,
+/// `AttackMomentum`, enemy dash probes and `DashAttack` speeds, and the
+/// wall-splat speed threshold. Set it before startup; the physics length
+/// unit can't change afterwards.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct WorldScale {
+    pub unit: f32,
+}
+
+impl Default for WorldScale {
+    fn default() -> Self {
+        Self { unit: 1.0 }
+    }
+}
+
+impl WorldScale {
+    /// An authored length, speed or acceleration in the scaled world.
+    pub fn len(&self, authored: f32) -> f32 {
+        authored * self.unit
+    }
+
+    pub fn physics_length_unit(&self) -> f32 {
+        self.len(BASE_LENGTH_UNIT)
+    }
+
+    pub fn gravity(&self) -> Gravity {
+        Gravity(Vector::NEG_Y * self.len(BASE_GRAVITY))
+    }
+
+    /// Capsule shared by the player and enemies.
+    pub fn body_collider(&self) -> Collider {
+        Collider::capsule(self.len(BODY_RADIUS), self.len(BODY_LENGTH))
+    }
+}
+
+/// Rescale the tuning resources authored in world units.
+pub fn apply_world_scale(world: Res<WorldScale>, mut enemy: ResMut<EnemyTuning>) {
+    if world.unit == 1.0 {
+        return;
+    }
+    let t = &mut *enemy;
+    for v in [
+        &mut t.walk,
+        &mut t.run,
+        &mut t.aggro,
+        &mut t.range,
+        &mut t.attack_band_x,
+        &mut t.return_speed,
+    ] {
+        *v = world.len(*v);
+    }
+}